@@ -0,0 +1,62 @@
+//! Benchmarks for formatting ENIDs.
+//!
+//! To compare the `safe` feature against the default build, save a baseline
+//! and then run again with the feature:
+//!
+//! ```text
+//! cargo bench --bench format -- --save-baseline default
+//! cargo bench --bench format --features safe -- --baseline default
+//! ```
+
+// `criterion_group!` defines an undocumented public function.
+#![allow(missing_docs)]
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use enid::{Enid40, Enid80};
+use std::fmt::Write;
+use std::hint::black_box;
+
+fn format(c: &mut Criterion) {
+    let enid40 = Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    let enid80 = Enid80::from_bytes([0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+    let string = enid80.to_enid_string();
+    let mut s = String::with_capacity(32);
+    let mut buf = [0; 17];
+
+    c.bench_function("display_40", |b| {
+        b.iter(|| {
+            s.clear();
+            write!(s, "{}", black_box(enid40))
+        })
+    });
+    c.bench_function("display_80", |b| {
+        b.iter(|| {
+            s.clear();
+            write!(s, "{}", black_box(enid80))
+        })
+    });
+    c.bench_function("format_into_40", |b| {
+        b.iter(|| {
+            s.clear();
+            black_box(enid40).format_into(&mut s)
+        })
+    });
+    c.bench_function("format_into_80", |b| {
+        b.iter(|| {
+            s.clear();
+            black_box(enid80).format_into(&mut s)
+        })
+    });
+    c.bench_function("encode_to_slice_40", |b| {
+        b.iter(|| black_box(black_box(enid40).encode_to_slice(&mut buf)).is_ok())
+    });
+    c.bench_function("encode_to_slice_80", |b| {
+        b.iter(|| black_box(black_box(enid80).encode_to_slice(&mut buf)).is_ok())
+    });
+    c.bench_function("enid_string_as_str_80", |b| {
+        b.iter(|| black_box(black_box(&string).as_str()))
+    });
+}
+
+criterion_group!(benches, format);
+criterion_main!(benches);
@@ -0,0 +1,178 @@
+//! Derive macros for the [`enid`](https://docs.rs/enid) crate.
+//!
+//! These are re-exported by `enid` with its `derive` feature, and should be
+//! used from there rather than by depending on this crate directly.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Fields, LitStr, Member, Type, parse_macro_input};
+
+/// Derives conversions, formatting, and parsing for a struct wrapping an ENID.
+///
+/// The struct must have a single field, which is one of the ENID types. The
+/// derive implements `From` in both directions, `AsRef`, `Display`, and
+/// `FromStr`, and with `enid`'s `serde` feature, `Serialize` and
+/// `Deserialize`. Traits that can be derived by the standard library, such as
+/// `Debug`, `Clone`, and `Hash`, are left to other derives.
+///
+/// Database traits, such as sqlx's `Type`, `Encode`, and `Decode` or diesel's
+/// `ToSql` and `FromSql`, are not derived, because `enid` does not implement
+/// them for the ENID types, so there is nothing to forward to. Implement them
+/// on the struct by converting to a column type, such as with
+/// `Enid40::to_i64`.
+///
+/// An optional `#[enid(prefix = "...")]` attribute adds a type prefix to the
+/// string form, as with `enid::prefixed::Prefixed`, which is checked when
+/// parsing.
+///
+/// # Examples
+///
+/// ```
+/// use enid::{EnidId, Enid80, enid80};
+///
+/// #[derive(EnidId, Debug, Copy, Clone, PartialEq, Eq, Hash)]
+/// #[enid(prefix = "usr")]
+/// struct UserId(Enid80);
+///
+/// #[derive(EnidId, Debug, PartialEq)]
+/// struct OrderId {
+///     enid: Enid80,
+/// }
+///
+/// let user = UserId::from(enid80!("y3gx5gxm-mpb8ey39"));
+///
+/// assert_eq!(user.to_string(), "usr_y3gx5gxm-mpb8ey39");
+/// assert_eq!("usr_y3gx5gxm-mpb8ey39".parse(), Ok(user));
+/// assert_eq!(serde_json::to_string(&user)?, r#""usr_y3gx5gxm-mpb8ey39""#);
+///
+/// let order: OrderId = "y3gx5gxm-mpb8ey39".parse()?;
+///
+/// assert_eq!(Enid80::from(order), enid80!("y3gx5gxm-mpb8ey39"));
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[proc_macro_derive(EnidId, attributes(enid))]
+pub fn derive_enid_id(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`EnidId` cannot be derived for generic types",
+        ));
+    }
+
+    let (member, ty) = single_field(input)?;
+    let prefix = prefix(input)?;
+    let name = &input.ident;
+
+    let common = quote! {
+        impl ::core::convert::From<#ty> for #name {
+            #[inline]
+            fn from(enid: #ty) -> Self {
+                Self { #member: enid }
+            }
+        }
+
+        impl ::core::convert::From<#name> for #ty {
+            #[inline]
+            fn from(id: #name) -> Self {
+                id.#member
+            }
+        }
+
+        impl ::core::convert::AsRef<#ty> for #name {
+            #[inline]
+            fn as_ref(&self) -> &#ty {
+                &self.#member
+            }
+        }
+    };
+
+    let format = match &prefix {
+        None => quote! {
+            impl ::core::fmt::Display for #name {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.#member, f)
+                }
+            }
+
+            impl ::core::str::FromStr for #name {
+                type Err = <#ty as ::core::str::FromStr>::Err;
+
+                #[inline]
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    <#ty as ::core::str::FromStr>::from_str(s).map(Self::from)
+                }
+            }
+
+            ::enid::__define_enid_type_serde!(#name, #ty);
+        },
+        Some(prefix) => quote! {
+            ::enid::__define_enid_type_prefixed!(#name, #ty, #prefix);
+            ::enid::__define_enid_type_serde!(#name, prefixed);
+        },
+    };
+
+    Ok(quote! {
+        #common
+        #format
+    })
+}
+
+/// Returns the struct's only field.
+fn single_field(input: &DeriveInput) -> syn::Result<(Member, &Type)> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            input,
+            "`EnidId` can only be derived for structs",
+        ));
+    };
+
+    let field = match &data.fields {
+        Fields::Named(fields) if fields.named.len() == 1 => &fields.named[0],
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => &fields.unnamed[0],
+        _ => {
+            return Err(Error::new_spanned(
+                &data.fields,
+                "`EnidId` can only be derived for structs with a single field",
+            ));
+        }
+    };
+
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+
+    Ok((member, &field.ty))
+}
+
+/// Returns the prefix given by the `#[enid(prefix = "...")]` attribute.
+fn prefix(input: &DeriveInput) -> syn::Result<Option<LitStr>> {
+    let mut prefix = None;
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("enid"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = Some(meta.value()?.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unknown `enid` attribute"))
+            }
+        })?;
+    }
+
+    Ok(prefix)
+}
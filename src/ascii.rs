@@ -0,0 +1,40 @@
+use core::str;
+
+// TODO: Use `ascii::Char` once it's stable.
+// https://github.com/rust-lang/rust/issues/110998
+
+/// Converts a buffer of ASCII characters to a string.
+///
+/// Callers must only pass ASCII characters. With the `safe` feature enabled, the
+/// buffer is validated instead of trusted, which costs about as much as encoding
+/// an ENID, so this is only used where a string is needed, and at most once for
+/// each formatted ENID.
+#[inline]
+pub(crate) const fn to_str(buf: &[u8]) -> &str {
+    #[cfg(not(feature = "safe"))]
+    let s = {
+        debug_assert!(buf.is_ascii());
+
+        // SAFETY: Only ASCII characters are passed by callers.
+        unsafe { str::from_utf8_unchecked(buf) }
+    };
+    #[cfg(feature = "safe")]
+    let s = match str::from_utf8(buf) {
+        Ok(s) => s,
+        Err(_) => panic!("buffer contains non-ASCII characters"),
+    };
+
+    s
+}
+
+/// Returns the value of a hexadecimal digit of either case, or `None` if the
+/// character is not a hexadecimal digit.
+#[inline]
+pub(crate) const fn hex_value(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        b'A'..=b'F' => Some(c - b'A' + 10),
+        _ => None,
+    }
+}
@@ -0,0 +1,247 @@
+use crate::enid::Enid40;
+use core::fmt::{self, Debug, Formatter};
+use core::sync::atomic::{AtomicU64, Ordering};
+
+const MASK: u64 = (1 << 40) - 1;
+
+/// Returns the ordering for the load in `fetch_update`, which cannot be a
+/// release.
+const fn load_ordering(order: Ordering) -> Ordering {
+    match order {
+        Ordering::Release => Ordering::Relaxed,
+        Ordering::AcqRel => Ordering::Acquire,
+        order => order,
+    }
+}
+
+/// Converts a stored value, which always fits in 40 bits, to an ENID.
+const fn to_enid(value: u64) -> Enid40 {
+    match Enid40::from_u64(value) {
+        Some(enid) => enid,
+        None => unreachable!(),
+    }
+}
+
+/// An [`Enid40`] that can be shared between threads, backed by an
+/// [`AtomicU64`].
+///
+/// This allows sequential IDs to be allocated, or a "current" ID to be
+/// published, without a mutex. The ENID is treated as a 40-bit big-endian
+/// integer, and the memory orderings have the same meaning as for
+/// [`AtomicU64`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, AtomicEnid40};
+/// # use core::sync::atomic::Ordering;
+/// static NEXT_ID: AtomicEnid40 = AtomicEnid40::new(enid40!("00000000"));
+///
+/// assert_eq!(NEXT_ID.fetch_increment(Ordering::Relaxed), Some(enid40!("00000000")));
+/// assert_eq!(NEXT_ID.fetch_increment(Ordering::Relaxed), Some(enid40!("00000001")));
+/// assert_eq!(NEXT_ID.load(Ordering::Relaxed), enid40!("00000002"));
+/// ```
+#[repr(transparent)]
+pub struct AtomicEnid40 {
+    value: AtomicU64,
+}
+
+impl AtomicEnid40 {
+    /// Creates an atomic ENID with the given initial value.
+    #[must_use]
+    #[inline]
+    pub const fn new(enid: Enid40) -> Self {
+        Self {
+            value: AtomicU64::new(enid.to_u64()),
+        }
+    }
+
+    /// Loads the current value.
+    #[must_use]
+    #[inline]
+    pub fn load(&self, order: Ordering) -> Enid40 {
+        to_enid(self.value.load(order))
+    }
+
+    /// Stores a new value.
+    #[inline]
+    pub fn store(&self, enid: Enid40, order: Ordering) {
+        self.value.store(enid.to_u64(), order);
+    }
+
+    /// Stores a new value, returning the previous value.
+    #[inline]
+    pub fn swap(&self, enid: Enid40, order: Ordering) -> Enid40 {
+        to_enid(self.value.swap(enid.to_u64(), order))
+    }
+
+    /// Stores a new value if the current value is the same as `current`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current value if it was not the same as `current`.
+    #[inline]
+    pub fn compare_exchange(
+        &self,
+        current: Enid40,
+        new: Enid40,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Enid40, Enid40> {
+        self.value
+            .compare_exchange(current.to_u64(), new.to_u64(), success, failure)
+            .map(to_enid)
+            .map_err(to_enid)
+    }
+
+    /// Stores a new value if the current value is the same as `current`,
+    /// allowing spurious failures.
+    ///
+    /// # Errors
+    ///
+    /// Returns the current value if it was not the same as `current`, or if
+    /// the comparison failed spuriously.
+    #[inline]
+    pub fn compare_exchange_weak(
+        &self,
+        current: Enid40,
+        new: Enid40,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Enid40, Enid40> {
+        self.value
+            .compare_exchange_weak(current.to_u64(), new.to_u64(), success, failure)
+            .map(to_enid)
+            .map_err(to_enid)
+    }
+
+    /// Increments the value by one, returning the previous value.
+    ///
+    /// Returns `None` and leaves the value unchanged if it is already the
+    /// largest ENID, `zzzzzzzz`, so that no ID is issued twice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, AtomicEnid40};
+    /// # use core::sync::atomic::Ordering;
+    /// let enid = AtomicEnid40::new(enid40!("zzzzzzzy"));
+    ///
+    /// assert_eq!(enid.fetch_increment(Ordering::Relaxed), Some(enid40!("zzzzzzzy")));
+    /// assert_eq!(enid.fetch_increment(Ordering::Relaxed), None);
+    /// assert_eq!(enid.load(Ordering::Relaxed), enid40!("zzzzzzzz"));
+    /// ```
+    #[inline]
+    pub fn fetch_increment(&self, order: Ordering) -> Option<Enid40> {
+        self.value
+            .fetch_update(order, load_ordering(order), |value| {
+                (value < MASK).then_some(value + 1)
+            })
+            .ok()
+            .map(to_enid)
+    }
+
+    /// Increments the value by one, returning the previous value.
+    ///
+    /// The largest ENID, `zzzzzzzz`, wraps around to `00000000`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, AtomicEnid40};
+    /// # use core::sync::atomic::Ordering;
+    /// let enid = AtomicEnid40::new(enid40!("zzzzzzzz"));
+    ///
+    /// assert_eq!(enid.fetch_increment_wrapping(Ordering::Relaxed), enid40!("zzzzzzzz"));
+    /// assert_eq!(enid.load(Ordering::Relaxed), enid40!("00000000"));
+    /// ```
+    #[inline]
+    pub fn fetch_increment_wrapping(&self, order: Ordering) -> Enid40 {
+        let previous = self
+            .value
+            .fetch_update(order, load_ordering(order), |value| {
+                Some((value + 1) & MASK)
+            });
+
+        match previous {
+            Ok(value) | Err(value) => to_enid(value),
+        }
+    }
+
+    /// Consumes the atomic ENID, returning its value.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> Enid40 {
+        to_enid(self.value.into_inner())
+    }
+}
+
+impl Default for AtomicEnid40 {
+    #[inline]
+    fn default() -> Self {
+        Self::new(Enid40::ZERO)
+    }
+}
+
+impl From<Enid40> for AtomicEnid40 {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        Self::new(enid)
+    }
+}
+
+impl Debug for AtomicEnid40 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use std::vec::Vec;
+
+    #[test]
+    fn concurrent_increments_are_unique() {
+        let next = Arc::new(AtomicEnid40::default());
+
+        let threads: Vec<_> = (0..4)
+            .map(|_| {
+                let next = Arc::clone(&next);
+                thread::spawn(move || {
+                    (0..1000)
+                        .map(|_| next.fetch_increment(Ordering::Relaxed).unwrap())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        let mut issued: Vec<_> = threads
+            .into_iter()
+            .flat_map(|thread| thread.join().unwrap())
+            .collect();
+        issued.sort_unstable();
+        issued.dedup();
+
+        assert_eq!(issued.len(), 4000);
+        assert_eq!(next.load(Ordering::Relaxed), to_enid(4000));
+    }
+
+    #[test]
+    fn increment_stops_at_max() {
+        let next = AtomicEnid40::new(Enid40::MAX);
+
+        assert_eq!(next.fetch_increment(Ordering::SeqCst), None);
+        assert_eq!(next.fetch_increment(Ordering::AcqRel), None);
+        assert_eq!(next.load(Ordering::SeqCst), Enid40::MAX);
+
+        assert_eq!(
+            next.fetch_increment_wrapping(Ordering::Release),
+            Enid40::MAX
+        );
+        assert_eq!(next.load(Ordering::SeqCst), Enid40::ZERO);
+        assert_eq!(next.fetch_increment(Ordering::Release), Some(Enid40::ZERO));
+    }
+}
@@ -0,0 +1,763 @@
+//! The Base32 alphabet used by ENID strings.
+//!
+//! ENIDs use a variant of Crockford's Base32 that excludes the letters `i`,
+//! `l`, `o`, and `u`. Only lowercase characters are valid. Other Base32
+//! variants can be used with a [`Codec`].
+
+use crate::ascii;
+use crate::enid::{EnidN, EnidParseError};
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Debug, Formatter};
+
+#[cfg(feature = "alloc")]
+mod simd;
+
+/// The 32 characters used to encode ENIDs, in order of value.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32::ALPHABET;
+/// assert_eq!(&ALPHABET, b"0123456789abcdefghjkmnpqrstvwxyz");
+/// ```
+pub const ALPHABET: [u8; 32] = *b"0123456789abcdefghjkmnpqrstvwxyz";
+
+/// The character separating each group of 8 characters in an ENID.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32::SEPARATOR;
+/// assert_eq!(SEPARATOR, b'-');
+/// ```
+pub const SEPARATOR: u8 = b'-';
+
+const VALUES: [u8; 256] = {
+    let mut values = [0xff; 256];
+    let mut i = 0;
+
+    while i < ALPHABET.len() {
+        let idx = ALPHABET[i] as usize;
+
+        assert!(values[idx] == 0xff);
+        values[idx] = i as u8;
+
+        i += 1;
+    }
+
+    values
+};
+
+/// Like `VALUES`, but also accepts uppercase characters, and maps the
+/// characters that Crockford's Base32 treats as aliases: `o` to `0`, and `i`
+/// and `l` to `1`.
+const CROCKFORD_VALUES: [u8; 256] = {
+    let mut values = VALUES;
+    let mut i = 0;
+
+    while i < ALPHABET.len() {
+        let c = ALPHABET[i];
+        values[c.to_ascii_uppercase() as usize] = values[c as usize];
+        i += 1;
+    }
+
+    let aliases = [(b'o', b'0'), (b'i', b'1'), (b'l', b'1')];
+    let mut i = 0;
+
+    while i < aliases.len() {
+        let (c, target) = aliases[i];
+        values[c as usize] = VALUES[target as usize];
+        values[c.to_ascii_uppercase() as usize] = VALUES[target as usize];
+        i += 1;
+    }
+
+    values
+};
+
+/// Like `CROCKFORD_VALUES`, but also maps `u`, which is easily confused with
+/// `v`.
+const LENIENT_VALUES: [u8; 256] = {
+    let mut values = CROCKFORD_VALUES;
+    values[b'u' as usize] = VALUES[b'v' as usize];
+    values[b'U' as usize] = VALUES[b'v' as usize];
+
+    values
+};
+
+/// Like `VALUES`, but for the uppercase form of the alphabet.
+const UPPERCASE_VALUES: [u8; 256] = {
+    let mut values = [0xff; 256];
+    let mut i = 0;
+
+    while i < ALPHABET.len() {
+        values[ALPHABET[i].to_ascii_uppercase() as usize] = i as u8;
+        i += 1;
+    }
+
+    values
+};
+
+/// Returns `true` if the given character is in the ENID alphabet.
+///
+/// The [`SEPARATOR`] is not part of the alphabet.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32::is_enid_char;
+/// assert_eq!(is_enid_char(b'm'), true);
+/// assert_eq!(is_enid_char(b'i'), false);
+/// assert_eq!(is_enid_char(b'M'), false);
+/// assert_eq!(is_enid_char(b'-'), false);
+/// ```
+#[must_use]
+#[inline]
+pub const fn is_enid_char(c: u8) -> bool {
+    VALUES[c as usize] != 0xff
+}
+
+/// Returns the 5-bit value of the given character, or `None` if it is not in
+/// the ENID alphabet.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32::char_value;
+/// assert_eq!(char_value(b'0'), Some(0));
+/// assert_eq!(char_value(b'z'), Some(31));
+/// assert_eq!(char_value(b'u'), None);
+/// ```
+#[must_use]
+#[inline]
+pub const fn char_value(c: u8) -> Option<u8> {
+    match VALUES[c as usize] {
+        0xff => None,
+        value => Some(value),
+    }
+}
+
+/// Returns the character representing the given 5-bit value, or `None` if the
+/// value is 32 or greater.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32::char_for;
+/// assert_eq!(char_for(0), Some(b'0'));
+/// assert_eq!(char_for(31), Some(b'z'));
+/// assert_eq!(char_for(32), None);
+/// ```
+#[must_use]
+#[inline]
+pub const fn char_for(value: u8) -> Option<u8> {
+    if value < 32 {
+        Some(ALPHABET[value as usize])
+    } else {
+        None
+    }
+}
+
+/// Encodes a group of 5 bytes with the ENID alphabet, working on all 8
+/// characters at once in a `u64` rather than looking each one up.
+pub(crate) const fn encode(bytes: [u8; 5]) -> [u8; 8] {
+    const fn splat(b: u8) -> u64 {
+        u64::from_ne_bytes([b; 8])
+    }
+
+    // Returns 1 in each byte whose value is greater than `n`, and 0 otherwise.
+    // Every value is less than 32, so adding never carries between bytes.
+    const fn gt(values: u64, n: u8) -> u64 {
+        ((values + splat(0x7f - n)) & splat(0x80)) >> 7
+    }
+
+    let [a, b, c, d, e] = bytes;
+    let x = u64::from_be_bytes([0, 0, 0, a, b, c, d, e]);
+
+    // Spread the 40 bits into 8 bytes of 5 bits each, halving the width of
+    // each lane at every step.
+    let x = ((x & 0x00ff_fff0_0000) << 12) | (x & 0x000f_ffff);
+    let x = ((x & 0x000f_fc00_000f_fc00) << 6) | (x & 0x0000_03ff_0000_03ff);
+    let x = ((x & 0x03e0_03e0_03e0_03e0) << 3) | (x & 0x001f_001f_001f_001f);
+
+    // Offset each value to its character, skipping `i`, `l`, `o`, and `u`.
+    let chars = x
+        + splat(b'0')
+        + gt(x, 9) * (b'a' - b'9' - 1) as u64
+        + gt(x, 17)
+        + gt(x, 19)
+        + gt(x, 21)
+        + gt(x, 26);
+
+    chars.to_be_bytes()
+}
+
+const fn encode_with(bytes: [u8; 5], alphabet: &[u8; 32]) -> [u8; 8] {
+    let bytes = [bytes[0], bytes[1], bytes[2], bytes[3], bytes[4], 0, 0, 0];
+
+    let mut bits = u64::from_be_bytes(bytes);
+    let mut chars = [0; 8];
+
+    let mut i = 0;
+    while i < 8 {
+        chars[i] = alphabet[(bits >> 59) as usize];
+        bits <<= 5;
+
+        i += 1;
+    }
+
+    chars
+}
+
+/// The characters accepted when decoding.
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub(crate) enum Decoding {
+    /// Only the lowercase alphabet.
+    Strict,
+    /// Only the uppercase form of the alphabet.
+    Uppercase,
+    /// Either case, with `o` read as `0`, and `i` and `l` as `1`.
+    Crockford,
+    /// Like `Crockford`, but also with `u` read as `v`.
+    Lenient,
+}
+
+const fn values(decoding: Decoding) -> &'static [u8; 256] {
+    match decoding {
+        Decoding::Strict => &VALUES,
+        Decoding::Uppercase => &UPPERCASE_VALUES,
+        Decoding::Crockford => &CROCKFORD_VALUES,
+        Decoding::Lenient => &LENIENT_VALUES,
+    }
+}
+
+pub(crate) const fn decode(chars: [u8; 8], decoding: Decoding) -> Result<[u8; 5], EnidParseError> {
+    decode_with(chars, values(decoding))
+}
+
+/// Returns whether `c` is accepted as a character when decoding.
+pub(crate) const fn is_valid(c: u8, decoding: Decoding) -> bool {
+    values(decoding)[c as usize] != 0xff
+}
+
+const fn decode_with(chars: [u8; 8], values: &[u8; 256]) -> Result<[u8; 5], EnidParseError> {
+    let mut bits: u64 = 0;
+
+    let mut i = 0;
+    while i < 8 {
+        let b = values[chars[i] as usize];
+
+        if b == 0xff {
+            return Err(EnidParseError);
+        }
+
+        bits <<= 5;
+        bits |= b as u64;
+        i += 1;
+    }
+
+    let bytes = bits.to_be_bytes();
+
+    Ok([bytes[3], bytes[4], bytes[5], bytes[6], bytes[7]])
+}
+
+/// An encoding of ENIDs with a configurable alphabet and separator, for
+/// exchanging ENIDs with systems that use another Base32 variant.
+///
+/// Each group of 5 bytes is encoded as 8 characters, in the same way as the
+/// ENID encoding, so only the characters differ.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32::Codec;
+/// # use enid::enid80;
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let enid = enid80!("y3gx5gxm-mpb8ey39");
+/// let mut buf = [0; 17];
+/// let encoded = Codec::RFC_4648.encode(&enid, &mut buf);
+///
+/// assert_eq!(encoded, "6DQ5FQ5UUWLIO6DJ");
+/// assert_eq!(Codec::RFC_4648.decode::<10>(encoded)?, enid);
+///
+/// let codec = Codec::new(*b"ybndrfg8ejkmcpqxot1uwisza345h769").with_separator(Some(b'.'));
+///
+/// assert_eq!(codec.encode(&enid, &mut buf), "6do7fo7w.wsmeq6dj");
+/// assert_eq!(codec.decode::<10>("6do7fo7w.wsmeq6dj")?, enid);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq)]
+pub struct Codec {
+    alphabet: [u8; 32],
+    values: [u8; 256],
+    separator: Option<u8>,
+}
+
+impl Codec {
+    /// The ENID encoding, such as `"y3gx5gxm-mpb8ey39"`.
+    pub const ENID: Self = Self::new(ALPHABET).with_separator(Some(SEPARATOR));
+
+    /// The Base32 encoding from RFC 4648, without a separator or padding, such
+    /// as `"6DQ5FQ5UUWLIO6DJ"`.
+    pub const RFC_4648: Self = Self::new(*b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567");
+
+    /// The Base32 encoding with the extended hex alphabet from RFC 4648,
+    /// without a separator or padding, such as `"U3GT5GTKKMB8EU39"`.
+    pub const RFC_4648_HEX: Self = Self::new(*b"0123456789ABCDEFGHIJKLMNOPQRSTUV");
+
+    /// The z-base-32 encoding, without a separator, such as
+    /// `"6do7fo7wwsmeq6dj"`.
+    pub const Z_BASE_32: Self = Self::new(*b"ybndrfg8ejkmcpqxot1uwisza345h769");
+
+    /// Creates a codec with the given alphabet and no separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the alphabet contains a non-ASCII character, or the same
+    /// character more than once.
+    #[must_use]
+    #[track_caller]
+    pub const fn new(alphabet: [u8; 32]) -> Self {
+        let mut values = [0xff; 256];
+        let mut i = 0;
+
+        while i < alphabet.len() {
+            let idx = alphabet[i] as usize;
+
+            assert!(
+                alphabet[i].is_ascii(),
+                "alphabet contains a non-ASCII character"
+            );
+            assert!(
+                values[idx] == 0xff,
+                "alphabet contains a duplicate character"
+            );
+            values[idx] = i as u8;
+
+            i += 1;
+        }
+
+        Self {
+            alphabet,
+            values,
+            separator: None,
+        }
+    }
+
+    /// Returns the codec with the given character separating each group of 8
+    /// characters, or with no separator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the separator is not ASCII, or is in the alphabet.
+    #[must_use]
+    #[track_caller]
+    pub const fn with_separator(mut self, separator: Option<u8>) -> Self {
+        if let Some(c) = separator {
+            assert!(c.is_ascii(), "separator is not ASCII");
+            assert!(
+                self.values[c as usize] == 0xff,
+                "separator is in the alphabet"
+            );
+        }
+
+        self.separator = separator;
+        self
+    }
+
+    /// Returns the alphabet.
+    #[must_use]
+    #[inline]
+    pub const fn alphabet(&self) -> &[u8; 32] {
+        &self.alphabet
+    }
+
+    /// Returns the separator, if there is one.
+    #[must_use]
+    #[inline]
+    pub const fn separator(&self) -> Option<u8> {
+        self.separator
+    }
+
+    /// Returns the length of the string form of an `N`-byte ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::base32::Codec;
+    /// assert_eq!(Codec::ENID.str_len::<10>(), 17);
+    /// assert_eq!(Codec::RFC_4648.str_len::<10>(), 16);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn str_len<const N: usize>(&self) -> usize {
+        let groups = N / 5;
+
+        match self.separator {
+            Some(_) => groups * 9 - 1,
+            None => groups * 8,
+        }
+    }
+
+    /// Writes the string form of the ENID to the start of the buffer, and
+    /// returns it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is shorter than [`str_len`](Self::str_len).
+    #[track_caller]
+    pub const fn encode<'a, const N: usize>(&self, enid: &EnidN<N>, buf: &'a mut [u8]) -> &'a str {
+        assert!(buf.len() >= self.str_len::<N>(), "buffer is too short");
+
+        let (buf, _) = buf.split_at_mut(self.str_len::<N>());
+        let stride = if self.separator.is_some() { 9 } else { 8 };
+        let mut group = 0;
+
+        while group < N / 5 {
+            let (_, bytes) = enid.as_bytes().split_at(group * 5);
+            let chars = encode_with(*bytes.first_chunk().unwrap(), &self.alphabet);
+
+            let mut i = 0;
+            while i < 8 {
+                buf[group * stride + i] = chars[i];
+                i += 1;
+            }
+
+            if let (Some(c), true) = (self.separator, group > 0) {
+                buf[group * 9 - 1] = c;
+            }
+
+            group += 1;
+        }
+
+        ascii::to_str(buf)
+    }
+
+    /// Attempts to create an `N`-byte ENID from its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID in this
+    /// encoding.
+    pub const fn decode<const N: usize>(&self, s: &str) -> Result<EnidN<N>, EnidParseError> {
+        let s = s.as_bytes();
+
+        if s.len() != self.str_len::<N>() {
+            return Err(EnidParseError);
+        }
+
+        let stride = if self.separator.is_some() { 9 } else { 8 };
+        let mut bytes = [0; N];
+        let mut group = 0;
+
+        while group < N / 5 {
+            if let (Some(c), true) = (self.separator, group > 0) {
+                if s[group * 9 - 1] != c {
+                    return Err(EnidParseError);
+                }
+            }
+
+            let (_, chars) = s.split_at(group * stride);
+
+            match decode_with(*chars.first_chunk().unwrap(), &self.values) {
+                Ok(chunk) => {
+                    let mut i = 0;
+                    while i < 5 {
+                        bytes[group * 5 + i] = chunk[i];
+                        i += 1;
+                    }
+                }
+                Err(e) => return Err(e),
+            }
+
+            group += 1;
+        }
+
+        Ok(EnidN::from_bytes(bytes))
+    }
+}
+
+impl Default for Codec {
+    #[inline]
+    fn default() -> Self {
+        Self::ENID
+    }
+}
+
+impl Debug for Codec {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Codec")
+            .field("alphabet", &ascii::to_str(&self.alphabet))
+            .field("separator", &self.separator.map(char::from))
+            .finish()
+    }
+}
+
+/// The number of 5-byte groups encoded or decoded at once by the batch
+/// functions.
+#[cfg(feature = "alloc")]
+const BATCH_GROUPS: usize = 32;
+
+/// Appends the string forms of ENIDs to a string, each followed by a
+/// newline.
+///
+/// This is faster than formatting each ENID separately, because groups from
+/// many ENIDs are encoded together and copied to the string in large chunks.
+/// On x86 and x86-64 with the `std` feature, the groups are encoded with AVX2
+/// or SSSE3 instructions when the CPU supports them, and on AArch64 with NEON.
+/// Otherwise, or with the `safe` feature, they are encoded one at a time.
+///
+/// This requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32;
+/// # use enid::enid40;
+/// let mut s = String::new();
+/// base32::encode_batch(&[enid40!("m6sc7n75"), enid40!("zzzzzzzz")], &mut s);
+///
+/// assert_eq!(s, "m6sc7n75\nzzzzzzzz\n");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn encode_batch<const N: usize>(enids: &[EnidN<N>], out: &mut String) {
+    // The bytes are padded so that vector loads never read past the end.
+    let mut bytes = [0; BATCH_GROUPS * 5 + 16];
+    // Each group of 8 characters is followed by a separator or a newline.
+    let mut buf = [0; BATCH_GROUPS * 9];
+    let mut len = 0;
+
+    out.reserve(enids.len() * (EnidN::<N>::STR_LEN + 1));
+
+    let groups = enids
+        .iter()
+        .flat_map(|enid| enid.as_bytes().chunks_exact(5));
+
+    for (i, group) in groups.enumerate() {
+        bytes[len * 5..len * 5 + 5].copy_from_slice(group);
+        buf[len * 9 + 8] = if (i + 1) % EnidN::<N>::GROUPS == 0 {
+            b'\n'
+        } else {
+            SEPARATOR
+        };
+        len += 1;
+
+        if len == BATCH_GROUPS {
+            encode_groups(&bytes, &mut buf, len);
+            out.push_str(ascii::to_str(&buf));
+            len = 0;
+        }
+    }
+
+    encode_groups(&bytes, &mut buf, len);
+    out.push_str(ascii::to_str(&buf[..len * 9]));
+}
+
+/// Encodes the first `groups` groups of 5 bytes into the first 8 of every 9
+/// bytes of `buf`.
+#[cfg(feature = "alloc")]
+fn encode_groups(bytes: &[u8], buf: &mut [u8], groups: usize) {
+    for i in simd::encode_groups(bytes, buf, groups)..groups {
+        let chars = encode(*bytes[i * 5..].first_chunk().unwrap());
+        buf[i * 9..i * 9 + 8].copy_from_slice(&chars);
+    }
+}
+
+/// Appends the ENIDs in a string written by [`encode_batch`] to a vector.
+///
+/// Each ENID must be followed by a single `\n`, except that the newline may
+/// be omitted after the last one. Because every line has the same length, the
+/// lines are found without searching for newlines, and groups from many lines
+/// are decoded together, with the same instructions as [`encode_batch`]. For
+/// input in any other form, such as with blank lines or `\r\n` line endings,
+/// use [`io::parse_lines`](crate::io::parse_lines) instead.
+///
+/// This requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::base32;
+/// # use enid::{enid40, EnidParseError};
+/// let mut enids = Vec::new();
+///
+/// assert_eq!(base32::decode_batch("m6sc7n75\nzzzzzzzz", &mut enids), Ok(()));
+/// assert_eq!(enids, [enid40!("m6sc7n75"), enid40!("zzzzzzzz")]);
+///
+/// enids.clear();
+///
+/// assert_eq!(
+///     base32::decode_batch::<5>("m6sc7n75\nzzzzzzz\n", &mut enids),
+///     Err((1, EnidParseError)),
+/// );
+/// assert_eq!(enids, [enid40!("m6sc7n75")]);
+/// ```
+///
+/// # Errors
+///
+/// Returns the index of the first line that is not a valid ENID, along with
+/// an [`EnidParseError`]. The ENIDs before that line will have been appended
+/// to the vector.
+#[cfg(feature = "alloc")]
+pub fn decode_batch<const N: usize>(
+    s: &str,
+    out: &mut Vec<EnidN<N>>,
+) -> Result<(), (usize, EnidParseError)> {
+    let stride = EnidN::<N>::STR_LEN + 1;
+    // ENIDs too long to fit in a batch are decoded one at a time.
+    let lines_per_batch = (BATCH_GROUPS / EnidN::<N>::GROUPS).max(1);
+
+    out.reserve(s.len().div_ceil(stride));
+
+    for (i, batch) in s.as_bytes().chunks(stride * lines_per_batch).enumerate() {
+        if decode_lines(batch, out) {
+            continue;
+        }
+
+        // Find the line that failed to decode, after pushing the ones before
+        // it.
+        for (j, line) in batch.chunks(stride).enumerate() {
+            let (chars, newline) = line.split_at(line.len().min(stride - 1));
+
+            if !matches!(newline, [] | [b'\n']) {
+                return Err((i * lines_per_batch + j, EnidParseError));
+            }
+
+            match EnidN::parse_str_ascii(chars) {
+                Ok(enid) => out.push(enid),
+                Err(e) => return Err((i * lines_per_batch + j, e)),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Decodes whole lines written by [`encode_batch`], appending them to the
+/// vector and returning `true` only if every line is valid.
+#[cfg(feature = "alloc")]
+fn decode_lines<const N: usize>(lines: &[u8], out: &mut Vec<EnidN<N>>) -> bool {
+    let groups = lines.len().div_ceil(9);
+    let mut bytes = [0; BATCH_GROUPS * 5];
+
+    // The newline after the last line may be omitted.
+    if groups > BATCH_GROUPS || groups % EnidN::<N>::GROUPS != 0 || lines.len() + 1 < groups * 9 {
+        return false;
+    }
+
+    for i in 0..groups {
+        let expected = if (i + 1) % EnidN::<N>::GROUPS == 0 {
+            b'\n'
+        } else {
+            SEPARATOR
+        };
+
+        if lines.get(i * 9 + 8).is_some_and(|&c| c != expected) {
+            return false;
+        }
+    }
+
+    if decode_groups(lines, &mut bytes, groups) < groups {
+        return false;
+    }
+
+    let enids = bytes[..groups * 5].chunks_exact(N);
+    out.extend(enids.map(|enid| EnidN::from_bytes(*enid.first_chunk().unwrap())));
+
+    true
+}
+
+/// Decodes the first `groups` groups of 8 characters, at the start of every 9
+/// bytes of `chars`, into `bytes`, returning the number of groups decoded
+/// before the first invalid one.
+#[cfg(feature = "alloc")]
+fn decode_groups(chars: &[u8], bytes: &mut [u8], groups: usize) -> usize {
+    for i in simd::decode_groups(chars, bytes, groups)..groups {
+        match decode(*chars[i * 9..].first_chunk().unwrap(), Decoding::Strict) {
+            Ok(group) => bytes[i * 5..i * 5 + 5].copy_from_slice(&group),
+            Err(_) => return i,
+        }
+    }
+
+    groups
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enid::{Enid40, Enid160};
+    use std::string::ToString;
+
+    #[test]
+    fn codec_matches_enid() {
+        let enid = Enid160::from_bytes(core::array::from_fn(|i| i as u8 * 13));
+        let mut buf = [0; 35];
+
+        assert_eq!(Codec::ENID.encode(&enid, &mut buf), enid.to_string());
+        assert_eq!(Codec::ENID.decode(&enid.to_string()), Ok(enid));
+    }
+
+    #[test]
+    fn codec_invalid() {
+        let codec = Codec::RFC_4648_HEX.with_separator(Some(b'_'));
+
+        assert_eq!(
+            codec.decode::<5>("KGPI7L75"),
+            Ok(Enid40::from_bytes([0xa4, 0x33, 0x23, 0xd4, 0xe5]))
+        );
+        assert_eq!(codec.decode::<5>("kgpc7l75"), Err(EnidParseError));
+        assert_eq!(codec.decode::<5>("KGPI7L7W"), Err(EnidParseError));
+        assert_eq!(codec.decode::<10>("KGPI7L75-KGPI7L75"), Err(EnidParseError));
+        assert_eq!(codec.decode::<10>("KGPI7L75KGPI7L75"), Err(EnidParseError));
+    }
+
+    #[test]
+    fn encode_matches_alphabet() {
+        for value in 0..32u64 {
+            for group in 0..8 {
+                let bytes = (value << (35 - group * 5)).to_be_bytes();
+                let bytes = *bytes.last_chunk().unwrap();
+
+                assert_eq!(encode(bytes), encode_with(bytes, &ALPHABET));
+            }
+        }
+
+        let bytes = [0xa1, 0xb2, 0xc3, 0xd4, 0xe5];
+
+        assert_eq!(encode(bytes), encode_with(bytes, &ALPHABET));
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn batch() {
+        let enids: std::vec::Vec<Enid160> = (0..100u8)
+            .map(|i| Enid160::from_bytes(core::array::from_fn(|j| i ^ (j as u8).wrapping_mul(31))))
+            .collect();
+        let mut s = std::string::String::new();
+        let mut decoded = std::vec::Vec::new();
+
+        encode_batch(&enids, &mut s);
+
+        assert_eq!(s.len(), 100 * 36);
+        assert!(s.lines().eq(enids.iter().map(ToString::to_string)));
+        assert_eq!(decode_batch(&s, &mut decoded), Ok(()));
+        assert_eq!(decoded, enids);
+        assert_eq!(
+            decode_batch::<20>(&s[..s.len() - 2], &mut decoded),
+            Err((99, EnidParseError))
+        );
+        assert_eq!(
+            decode_batch::<5>("m6sc7n75\r\n", &mut std::vec::Vec::new()),
+            Err((0, EnidParseError))
+        );
+    }
+
+    #[test]
+    #[should_panic = "alphabet contains a duplicate character"]
+    fn codec_duplicate() {
+        let _ = Codec::new(*b"00000000000000000000000000000000");
+    }
+}
@@ -0,0 +1,123 @@
+//! NEON implementations, for little-endian AArch64 targets with NEON enabled,
+//! which includes every such target with an operating system.
+//!
+//! This works like the x86 implementation, but NEON can shift each lane by a
+//! different amount, and look up all 32 characters of the alphabet at once.
+
+use crate::base32::ALPHABET;
+use core::arch::aarch64::*;
+
+/// Indexes for a table lookup that spreads a group starting at byte 0 of a
+/// vector into 16-bit lanes, where lane `i` holds the two bytes containing the
+/// `i`th 5-bit value, with the first byte in the high half. Out of range
+/// indexes produce zero.
+const SPREAD_FIRST: [u8; 16] = [1, 0, 1, 0, 2, 1, 2, 1, 3, 2, 4, 3, 4, 3, 0xff, 4];
+
+/// Like `SPREAD_FIRST`, but for a group starting at byte 5.
+const SPREAD_SECOND: [u8; 16] = [6, 5, 6, 5, 7, 6, 7, 6, 8, 7, 9, 8, 9, 8, 0xff, 9];
+
+/// Shifts that move each lane from `SPREAD_FIRST` right to its 5-bit value.
+const SHIFTS: [i16; 8] = [-11, -6, -9, -4, -7, -10, -5, -8];
+
+pub(crate) fn encode_groups(bytes: &[u8], buf: &mut [u8], groups: usize) -> usize {
+    let mut i = 0;
+
+    // SAFETY: NEON is enabled at compile time, and each load and store is of a
+    // slice of the size of the access.
+    unsafe {
+        let spread_first = vld1q_u8(SPREAD_FIRST.as_ptr());
+        let spread_second = vld1q_u8(SPREAD_SECOND.as_ptr());
+        let shifts = vld1q_s16(SHIFTS.as_ptr());
+        let alphabet = uint8x16x2_t(
+            vld1q_u8(ALPHABET.as_ptr()),
+            vld1q_u8(ALPHABET[16..].as_ptr()),
+        );
+        let mask = vdupq_n_u16(31);
+
+        while i + 2 <= groups && i * 5 + 16 <= bytes.len() {
+            let v = vld1q_u8(bytes[i * 5..i * 5 + 16].as_ptr());
+
+            let first = vreinterpretq_u16_u8(vqtbl1q_u8(v, spread_first));
+            let first = vandq_u16(vshlq_u16(first, shifts), mask);
+            let second = vreinterpretq_u16_u8(vqtbl1q_u8(v, spread_second));
+            let second = vandq_u16(vshlq_u16(second, shifts), mask);
+
+            let values = vcombine_u8(vmovn_u16(first), vmovn_u16(second));
+            let chars = vqtbl2q_u8(alphabet, values);
+
+            vst1_u8(buf[i * 9..i * 9 + 8].as_mut_ptr(), vget_low_u8(chars));
+            vst1_u8(buf[i * 9 + 9..i * 9 + 17].as_mut_ptr(), vget_high_u8(chars));
+            i += 2;
+        }
+    }
+
+    i
+}
+
+pub(crate) fn decode_groups(chars: &[u8], bytes: &mut [u8], groups: usize) -> usize {
+    let mut i = 0;
+
+    // SAFETY: NEON is enabled at compile time, and each load and store is of a
+    // slice of the size of the access.
+    unsafe {
+        let alphabet = uint8x16x2_t(
+            vld1q_u8(ALPHABET.as_ptr()),
+            vld1q_u8(ALPHABET[16..].as_ptr()),
+        );
+
+        while i + 2 <= groups {
+            let c = vcombine_u8(
+                vld1_u8(chars[i * 9..i * 9 + 8].as_ptr()),
+                vld1_u8(chars[i * 9 + 9..i * 9 + 17].as_ptr()),
+            );
+
+            // Each comparison is all ones where true, so subtracting it skips
+            // a letter.
+            let offset = vaddq_u8(
+                vdupq_n_u8(b'0'),
+                vandq_u8(vcgtq_u8(c, vdupq_n_u8(b'9')), vdupq_n_u8(b'a' - 10 - b'0')),
+            );
+            let offset = vsubq_u8(offset, vcgtq_u8(c, vdupq_n_u8(b'h')));
+            let offset = vsubq_u8(offset, vcgtq_u8(c, vdupq_n_u8(b'k')));
+            let offset = vsubq_u8(offset, vcgtq_u8(c, vdupq_n_u8(b'n')));
+            let offset = vsubq_u8(offset, vcgtq_u8(c, vdupq_n_u8(b't')));
+            let values = vsubq_u8(c, offset);
+
+            let round_trip = vceqq_u8(vqtbl2q_u8(alphabet, values), c);
+            let in_range = vcltq_u8(values, vdupq_n_u8(32));
+
+            if vminvq_u8(vandq_u8(round_trip, in_range)) != 0xff {
+                break;
+            }
+
+            // Pack pairs of 5-bit values into 10 bits, then pairs of those
+            // into 20 bits, then pairs of those into the 40 bits of a group.
+            let pairs = vreinterpretq_u16_u8(values);
+            let pairs = vorrq_u16(
+                vshlq_n_u16::<5>(vandq_u16(pairs, vdupq_n_u16(0xff))),
+                vshrq_n_u16::<8>(pairs),
+            );
+            let words = vreinterpretq_u32_u16(pairs);
+            let words = vorrq_u32(
+                vshlq_n_u32::<10>(vandq_u32(words, vdupq_n_u32(0xffff))),
+                vshrq_n_u32::<16>(words),
+            );
+            let words = vreinterpretq_u64_u32(words);
+            let words = vorrq_u64(
+                vshlq_n_u64::<20>(vandq_u64(words, vdupq_n_u64(0xffff_ffff))),
+                vshrq_n_u64::<32>(words),
+            );
+
+            let mut decoded = [0; 2];
+            vst1q_u64(decoded.as_mut_ptr(), words);
+
+            for (j, group) in decoded.iter().enumerate() {
+                bytes[(i + j) * 5..(i + j) * 5 + 5].copy_from_slice(&group.to_be_bytes()[3..]);
+            }
+
+            i += 2;
+        }
+    }
+
+    i
+}
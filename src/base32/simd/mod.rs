@@ -0,0 +1,133 @@
+//! Vectorized encoding and decoding of many groups at once, for the batch
+//! functions.
+//!
+//! Each function handles as many groups as it can from the start of the
+//! input, and returns how many it handled, leaving the rest to the scalar
+//! code. Without a supported instruction set, or with the `safe` feature, no
+//! groups are handled.
+
+#[cfg(all(
+    not(feature = "safe"),
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+mod aarch64;
+#[cfg(all(
+    not(feature = "safe"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+mod x86;
+
+#[cfg(all(
+    not(feature = "safe"),
+    target_arch = "aarch64",
+    target_feature = "neon",
+    target_endian = "little"
+))]
+pub(super) use self::aarch64::{decode_groups, encode_groups};
+#[cfg(all(
+    not(feature = "safe"),
+    any(target_arch = "x86", target_arch = "x86_64")
+))]
+pub(super) use self::x86::{decode_groups, encode_groups};
+
+/// Encodes groups of 5 bytes from `bytes` into the first 8 of every 9 bytes of
+/// `buf`.
+#[cfg(not(all(
+    not(feature = "safe"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            target_endian = "little"
+        ),
+    ),
+)))]
+pub(super) const fn encode_groups(_bytes: &[u8], _buf: &mut [u8], _groups: usize) -> usize {
+    0
+}
+
+/// Decodes groups of 8 characters, at the start of every 9 bytes of `chars`,
+/// into `bytes`, stopping before any vector containing an invalid character.
+#[cfg(not(all(
+    not(feature = "safe"),
+    any(
+        target_arch = "x86",
+        target_arch = "x86_64",
+        all(
+            target_arch = "aarch64",
+            target_feature = "neon",
+            target_endian = "little"
+        ),
+    ),
+)))]
+pub(super) const fn decode_groups(_chars: &[u8], _bytes: &mut [u8], _groups: usize) -> usize {
+    0
+}
+
+/// Checks that vectorized functions agree with the scalar ones, for any number
+/// of groups, and reject every invalid character in every position.
+#[cfg(test)]
+fn check_matches_scalar(
+    encode_groups: impl Fn(&[u8], &mut [u8], usize) -> usize,
+    decode_groups: impl Fn(&[u8], &mut [u8], usize) -> usize,
+) {
+    use super::{Decoding, decode, encode};
+
+    let mut bytes = [0; 5 * 40 + 16];
+    let mut chars = [0; 9 * 40];
+    let mut decoded = [0; 5 * 40];
+
+    for (i, b) in bytes.iter_mut().enumerate() {
+        *b = (i as u8).wrapping_mul(167).wrapping_add(13);
+    }
+
+    for groups in 0..=40 {
+        let mut buf = [b'-'; 9 * 40];
+        let encoded = encode_groups(&bytes, &mut buf, groups);
+
+        assert!(encoded <= groups);
+
+        for i in 0..encoded {
+            let group = *bytes[i * 5..].first_chunk().unwrap();
+
+            assert_eq!(buf[i * 9..i * 9 + 8], encode(group));
+            assert_eq!(buf[i * 9 + 8], b'-');
+        }
+    }
+
+    for i in 0..40 {
+        let group = *bytes[i * 5..].first_chunk().unwrap();
+        chars[i * 9..i * 9 + 8].copy_from_slice(&encode(group));
+    }
+
+    let count = decode_groups(&chars, &mut decoded, 40);
+
+    assert_eq!(decoded[..count * 5], bytes[..count * 5]);
+
+    for c in 0..=u8::MAX {
+        if decode([c; 8], Decoding::Strict).is_ok() {
+            continue;
+        }
+
+        for i in (0..9 * 8).filter(|i| i % 9 != 8) {
+            let mut chars = chars;
+            chars[i] = c;
+
+            let count = decode_groups(&chars, &mut decoded, 8);
+
+            assert!(count <= i / 9, "accepted {c:#04x} at {i}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn matches_scalar() {
+        super::check_matches_scalar(super::encode_groups, super::decode_groups);
+    }
+}
@@ -0,0 +1,372 @@
+//! SSSE3 and AVX2 implementations, chosen at runtime with the `std` feature,
+//! or by the target features enabled at compile time otherwise.
+//!
+//! Encoding spreads each group into eight 16-bit lanes holding the two bytes
+//! that contain each 5-bit value, shifts the values down by multiplying, and
+//! looks up their characters. Decoding computes the value of each character
+//! from its range of the alphabet, checks it by encoding it again, and packs
+//! pairs of values together by multiplying and adding.
+
+use crate::base32::ALPHABET;
+#[cfg(target_arch = "x86")]
+use core::arch::x86::*;
+#[cfg(target_arch = "x86_64")]
+use core::arch::x86_64::*;
+
+pub(crate) fn encode_groups(bytes: &[u8], buf: &mut [u8], groups: usize) -> usize {
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2.
+        unsafe { encode_avx2(bytes, buf, groups) }
+    } else if has_ssse3() {
+        // SAFETY: The CPU supports SSSE3.
+        unsafe { encode_ssse3(bytes, buf, groups, 0) }
+    } else {
+        0
+    }
+}
+
+pub(crate) fn decode_groups(chars: &[u8], bytes: &mut [u8], groups: usize) -> usize {
+    if has_avx2() {
+        // SAFETY: The CPU supports AVX2.
+        unsafe { decode_avx2(chars, bytes, groups) }
+    } else if has_ssse3() {
+        // SAFETY: The CPU supports SSSE3.
+        unsafe { decode_ssse3(chars, bytes, groups, 0) }
+    } else {
+        0
+    }
+}
+
+#[cfg(feature = "std")]
+fn has_avx2() -> bool {
+    std::is_x86_feature_detected!("avx2")
+}
+
+#[cfg(not(feature = "std"))]
+const fn has_avx2() -> bool {
+    cfg!(target_feature = "avx2")
+}
+
+#[cfg(feature = "std")]
+fn has_ssse3() -> bool {
+    std::is_x86_feature_detected!("ssse3")
+}
+
+#[cfg(not(feature = "std"))]
+const fn has_ssse3() -> bool {
+    cfg!(target_feature = "ssse3")
+}
+
+/// Shuffle controls that spread a group starting at byte 0 of a vector into
+/// 16-bit lanes, where lane `i` holds the two bytes containing the `i`th 5-bit
+/// value, with the first byte in the high half. Controls of -128 produce zero.
+const SPREAD_FIRST: [i8; 16] = [1, 0, 1, 0, 2, 1, 2, 1, 3, 2, 4, 3, 4, 3, -128, 4];
+
+/// Like `SPREAD_FIRST`, but for a group starting at byte 5.
+const SPREAD_SECOND: [i8; 16] = [6, 5, 6, 5, 7, 6, 7, 6, 8, 7, 9, 8, 9, 8, -128, 9];
+
+/// Multipliers that shift each lane from `SPREAD_FIRST` right by 11, 6, 9, 4,
+/// 7, 10, 5, and 8 bits, when the high half of the product is kept.
+const SHIFTS: [i16; 8] = [
+    1 << 5,
+    1 << 10,
+    1 << 7,
+    1 << 12,
+    1 << 9,
+    1 << 6,
+    1 << 11,
+    1 << 8,
+];
+
+/// Encodes pairs of groups with SSSE3, starting from group `i`.
+#[target_feature(enable = "ssse3")]
+unsafe fn encode_ssse3(bytes: &[u8], buf: &mut [u8], groups: usize, mut i: usize) -> usize {
+    // SAFETY: Each load and store is of a slice of the size of the access.
+    unsafe {
+        let spread_first = _mm_loadu_si128(SPREAD_FIRST.as_ptr().cast());
+        let spread_second = _mm_loadu_si128(SPREAD_SECOND.as_ptr().cast());
+        let shifts = _mm_loadu_si128(SHIFTS.as_ptr().cast());
+        let mask = _mm_set1_epi16(31);
+
+        while i + 2 <= groups && i * 5 + 16 <= bytes.len() {
+            let v = _mm_loadu_si128(bytes[i * 5..i * 5 + 16].as_ptr().cast());
+
+            let first = _mm_shuffle_epi8(v, spread_first);
+            let first = _mm_and_si128(_mm_mulhi_epu16(first, shifts), mask);
+            let second = _mm_shuffle_epi8(v, spread_second);
+            let second = _mm_and_si128(_mm_mulhi_epu16(second, shifts), mask);
+
+            store_chars(buf, i, to_chars(_mm_packus_epi16(first, second)));
+            i += 2;
+        }
+    }
+
+    i
+}
+
+/// Encodes groups of four with AVX2, then any remaining pair with SSSE3.
+#[target_feature(enable = "avx2")]
+unsafe fn encode_avx2(bytes: &[u8], buf: &mut [u8], groups: usize) -> usize {
+    let mut i = 0;
+
+    // SAFETY: Each load and store is of a slice of the size of the access,
+    // and AVX2 implies SSSE3.
+    unsafe {
+        let spread_first =
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(SPREAD_FIRST.as_ptr().cast()));
+        let spread_second =
+            _mm256_broadcastsi128_si256(_mm_loadu_si128(SPREAD_SECOND.as_ptr().cast()));
+        let shifts = _mm256_broadcastsi128_si256(_mm_loadu_si128(SHIFTS.as_ptr().cast()));
+        let mask = _mm256_set1_epi16(31);
+
+        while i + 4 <= groups && i * 5 + 26 <= bytes.len() {
+            let low = _mm_loadu_si128(bytes[i * 5..i * 5 + 16].as_ptr().cast());
+            let high = _mm_loadu_si128(bytes[i * 5 + 10..i * 5 + 26].as_ptr().cast());
+            let v = _mm256_inserti128_si256::<1>(_mm256_castsi128_si256(low), high);
+
+            let first = _mm256_shuffle_epi8(v, spread_first);
+            let first = _mm256_and_si256(_mm256_mulhi_epu16(first, shifts), mask);
+            let second = _mm256_shuffle_epi8(v, spread_second);
+            let second = _mm256_and_si256(_mm256_mulhi_epu16(second, shifts), mask);
+
+            let chars = to_chars_avx2(_mm256_packus_epi16(first, second));
+            store_chars(buf, i, _mm256_castsi256_si128(chars));
+            store_chars(buf, i + 2, _mm256_extracti128_si256::<1>(chars));
+            i += 4;
+        }
+
+        encode_ssse3(bytes, buf, groups, i)
+    }
+}
+
+/// Decodes pairs of groups with SSSE3, starting from group `i`.
+#[target_feature(enable = "ssse3")]
+unsafe fn decode_ssse3(chars: &[u8], bytes: &mut [u8], groups: usize, mut i: usize) -> usize {
+    // SAFETY: Each load and store is of a slice of the size of the access.
+    unsafe {
+        while i + 2 <= groups {
+            let c = _mm_unpacklo_epi64(load_chars(chars, i), load_chars(chars, i + 1));
+            let Some(values) = to_values(c) else {
+                break;
+            };
+
+            // Pack pairs of 5-bit values into 10 bits, then pairs of those
+            // into 20 bits, then pairs of those into the 40 bits of a group.
+            let pairs = _mm_maddubs_epi16(values, _mm_set1_epi16(0x0120));
+            let words = _mm_madd_epi16(pairs, _mm_set1_epi32(0x0001_0400));
+            let words = _mm_or_si128(
+                _mm_srli_epi64::<12>(_mm_slli_epi64::<32>(words)),
+                _mm_srli_epi64::<32>(words),
+            );
+            let mut decoded = [0; 2];
+            _mm_storeu_si128(decoded.as_mut_ptr().cast(), words);
+
+            store_bytes(bytes, i, &decoded);
+            i += 2;
+        }
+    }
+
+    i
+}
+
+/// Decodes groups of four with AVX2, then any remaining pair with SSSE3.
+#[target_feature(enable = "avx2")]
+unsafe fn decode_avx2(chars: &[u8], bytes: &mut [u8], groups: usize) -> usize {
+    let mut i = 0;
+
+    // SAFETY: Each load and store is of a slice of the size of the access,
+    // and AVX2 implies SSSE3.
+    unsafe {
+        while i + 4 <= groups {
+            let low = _mm_unpacklo_epi64(load_chars(chars, i), load_chars(chars, i + 1));
+            let high = _mm_unpacklo_epi64(load_chars(chars, i + 2), load_chars(chars, i + 3));
+            let c = _mm256_inserti128_si256::<1>(_mm256_castsi128_si256(low), high);
+            let Some(values) = to_values_avx2(c) else {
+                break;
+            };
+
+            let pairs = _mm256_maddubs_epi16(values, _mm256_set1_epi16(0x0120));
+            let words = _mm256_madd_epi16(pairs, _mm256_set1_epi32(0x0001_0400));
+            let words = _mm256_or_si256(
+                _mm256_srli_epi64::<12>(_mm256_slli_epi64::<32>(words)),
+                _mm256_srli_epi64::<32>(words),
+            );
+            let mut decoded = [0; 4];
+            _mm256_storeu_si256(decoded.as_mut_ptr().cast(), words);
+
+            store_bytes(bytes, i, &decoded);
+            i += 4;
+        }
+
+        decode_ssse3(chars, bytes, groups, i)
+    }
+}
+
+/// Maps 16 values less than 32 to their characters.
+#[target_feature(enable = "ssse3")]
+#[inline]
+unsafe fn to_chars(values: __m128i) -> __m128i {
+    // SAFETY: Each load is of a slice of 16 bytes.
+    unsafe {
+        let low = _mm_loadu_si128(ALPHABET[..16].as_ptr().cast());
+        let high = _mm_loadu_si128(ALPHABET[16..].as_ptr().cast());
+
+        // Shuffles only use the low 4 bits of each value, so both halves of
+        // the alphabet are looked up, and the right one is chosen.
+        let is_high = _mm_cmpgt_epi8(values, _mm_set1_epi8(15));
+
+        _mm_or_si128(
+            _mm_and_si128(is_high, _mm_shuffle_epi8(high, values)),
+            _mm_andnot_si128(is_high, _mm_shuffle_epi8(low, values)),
+        )
+    }
+}
+
+/// Like `to_chars`, for 32 values.
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn to_chars_avx2(values: __m256i) -> __m256i {
+    // SAFETY: Each load is of a slice of 16 bytes.
+    unsafe {
+        let low = _mm256_broadcastsi128_si256(_mm_loadu_si128(ALPHABET[..16].as_ptr().cast()));
+        let high = _mm256_broadcastsi128_si256(_mm_loadu_si128(ALPHABET[16..].as_ptr().cast()));
+        let is_high = _mm256_cmpgt_epi8(values, _mm256_set1_epi8(15));
+
+        _mm256_blendv_epi8(
+            _mm256_shuffle_epi8(low, values),
+            _mm256_shuffle_epi8(high, values),
+            is_high,
+        )
+    }
+}
+
+/// Maps 16 characters to their values, or returns `None` if any character is
+/// not in the alphabet.
+#[target_feature(enable = "ssse3")]
+#[inline]
+unsafe fn to_values(c: __m128i) -> Option<__m128i> {
+    // SAFETY: The CPU supports SSSE3.
+    unsafe {
+        let splat = |c: u8| _mm_set1_epi8(c as i8);
+
+        // Each comparison is -1 where true, so subtracting it skips a letter.
+        let offset = _mm_add_epi8(
+            splat(b'0'),
+            _mm_and_si128(_mm_cmpgt_epi8(c, splat(b'9')), splat(b'a' - 10 - b'0')),
+        );
+        let offset = _mm_sub_epi8(offset, _mm_cmpgt_epi8(c, splat(b'h')));
+        let offset = _mm_sub_epi8(offset, _mm_cmpgt_epi8(c, splat(b'k')));
+        let offset = _mm_sub_epi8(offset, _mm_cmpgt_epi8(c, splat(b'n')));
+        let offset = _mm_sub_epi8(offset, _mm_cmpgt_epi8(c, splat(b't')));
+        let values = _mm_sub_epi8(c, offset);
+
+        // Other characters get values that encode differently, or are out of
+        // range.
+        let round_trip = _mm_cmpeq_epi8(to_chars(values), c);
+        let in_range = _mm_cmpeq_epi8(_mm_and_si128(values, splat(0xe0)), _mm_setzero_si128());
+        let valid = _mm_and_si128(round_trip, in_range);
+
+        (_mm_movemask_epi8(valid) == 0xffff).then_some(values)
+    }
+}
+
+/// Like `to_values`, for 32 characters.
+#[target_feature(enable = "avx2")]
+#[inline]
+unsafe fn to_values_avx2(c: __m256i) -> Option<__m256i> {
+    // SAFETY: The CPU supports AVX2.
+    unsafe {
+        let splat = |c: u8| _mm256_set1_epi8(c as i8);
+
+        let offset = _mm256_add_epi8(
+            splat(b'0'),
+            _mm256_and_si256(_mm256_cmpgt_epi8(c, splat(b'9')), splat(b'a' - 10 - b'0')),
+        );
+        let offset = _mm256_sub_epi8(offset, _mm256_cmpgt_epi8(c, splat(b'h')));
+        let offset = _mm256_sub_epi8(offset, _mm256_cmpgt_epi8(c, splat(b'k')));
+        let offset = _mm256_sub_epi8(offset, _mm256_cmpgt_epi8(c, splat(b'n')));
+        let offset = _mm256_sub_epi8(offset, _mm256_cmpgt_epi8(c, splat(b't')));
+        let values = _mm256_sub_epi8(c, offset);
+
+        let round_trip = _mm256_cmpeq_epi8(to_chars_avx2(values), c);
+        let in_range = _mm256_cmpeq_epi8(
+            _mm256_and_si256(values, splat(0xe0)),
+            _mm256_setzero_si256(),
+        );
+        let valid = _mm256_and_si256(round_trip, in_range);
+
+        (_mm256_movemask_epi8(valid) == -1).then_some(values)
+    }
+}
+
+/// Loads the 8 characters of group `i`.
+#[target_feature(enable = "ssse3")]
+#[inline]
+unsafe fn load_chars(chars: &[u8], i: usize) -> __m128i {
+    // SAFETY: The load is of a slice of 8 bytes.
+    unsafe { _mm_loadl_epi64(chars[i * 9..i * 9 + 8].as_ptr().cast()) }
+}
+
+/// Stores the characters of groups `i` and `i + 1`.
+#[target_feature(enable = "ssse3")]
+#[inline]
+unsafe fn store_chars(buf: &mut [u8], i: usize, chars: __m128i) {
+    // SAFETY: Each store is of a slice of 8 bytes.
+    unsafe {
+        _mm_storel_epi64(buf[i * 9..i * 9 + 8].as_mut_ptr().cast(), chars);
+        _mm_storel_epi64(
+            buf[i * 9 + 9..i * 9 + 17].as_mut_ptr().cast(),
+            _mm_unpackhi_epi64(chars, chars),
+        );
+    }
+}
+
+/// Stores the bytes of decoded groups, starting from group `i`.
+fn store_bytes(bytes: &mut [u8], i: usize, groups: &[u64]) {
+    for (j, group) in groups.iter().enumerate() {
+        bytes[(i + j) * 5..(i + j) * 5 + 5].copy_from_slice(&group.to_be_bytes()[3..]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base32::simd::check_matches_scalar;
+
+    #[test]
+    fn ssse3() {
+        if !has_ssse3() {
+            return;
+        }
+
+        // SAFETY: The CPU supports SSSE3.
+        let encode =
+            |bytes: &[u8], buf: &mut [u8], groups| unsafe { encode_ssse3(bytes, buf, groups, 0) };
+        // SAFETY: The CPU supports SSSE3.
+        let decode = |chars: &[u8], bytes: &mut [u8], groups| unsafe {
+            decode_ssse3(chars, bytes, groups, 0)
+        };
+
+        assert_eq!(encode(&[0; 26], &mut [0; 36], 4), 4);
+        assert_eq!(decode(&[b'0'; 35], &mut [0; 20], 4), 4);
+        check_matches_scalar(encode, decode);
+    }
+
+    #[test]
+    fn avx2() {
+        if !has_avx2() {
+            return;
+        }
+
+        // SAFETY: The CPU supports AVX2.
+        let encode =
+            |bytes: &[u8], buf: &mut [u8], groups| unsafe { encode_avx2(bytes, buf, groups) };
+        // SAFETY: The CPU supports AVX2.
+        let decode =
+            |chars: &[u8], bytes: &mut [u8], groups| unsafe { decode_avx2(chars, bytes, groups) };
+
+        assert_eq!(encode(&[0; 46], &mut [0; 72], 8), 8);
+        assert_eq!(decode(&[b'0'; 71], &mut [0; 40], 8), 8);
+        check_matches_scalar(encode, decode);
+    }
+}
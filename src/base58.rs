@@ -0,0 +1,290 @@
+use crate::ascii;
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+
+const CHARS: [u8; 58] = *b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+const VALUES: [u8; 256] = {
+    let mut values = [0xff; 256];
+    let mut i = 0;
+
+    while i < CHARS.len() {
+        let idx = CHARS[i] as usize;
+
+        assert!(values[idx] == 0xff);
+        values[idx] = i as u8;
+
+        i += 1;
+    }
+
+    values
+};
+
+/// Encodes an integer of `size` bytes as Base58, writing one `1` for each
+/// leading zero byte as Bitcoin does. The buffer must be long enough for the
+/// largest value of that size.
+const fn encode(value: u128, size: usize, buf: &mut [u8]) -> &str {
+    let zeros = leading_zero_bytes(value, size);
+
+    let mut digits = [0; 22];
+    let mut len = 0;
+    let mut v = value;
+
+    while v > 0 {
+        digits[len] = CHARS[(v % 58) as usize];
+        v /= 58;
+        len += 1;
+    }
+
+    let mut i = 0;
+    while i < zeros {
+        buf[i] = CHARS[0];
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < len {
+        buf[zeros + i] = digits[len - 1 - i];
+        i += 1;
+    }
+
+    let (buf, _) = buf.split_at(zeros + len);
+
+    ascii::to_str(buf)
+}
+
+/// Decodes a Base58 string into an integer of `size` bytes, which must be
+/// encoded in at most `max_len` characters.
+///
+/// Only the string that [`encode`] would produce is accepted, so parsing and
+/// formatting round-trip exactly.
+const fn decode(s: &[u8], size: usize, max_len: usize) -> Result<u128, EnidParseError> {
+    if s.is_empty() || s.len() > max_len {
+        return Err(EnidParseError);
+    }
+
+    let mut value: u128 = 0;
+    let mut ones = 0;
+    let mut i = 0;
+
+    while i < s.len() {
+        let v = VALUES[s[i] as usize];
+
+        if v == 0xff {
+            return Err(EnidParseError);
+        }
+
+        if v == 0 && ones == i {
+            ones += 1;
+        }
+
+        value = value * 58 + v as u128;
+        i += 1;
+    }
+
+    if value >> (size * 8) != 0 || ones != leading_zero_bytes(value, size) {
+        return Err(EnidParseError);
+    }
+
+    Ok(value)
+}
+
+const fn leading_zero_bytes(value: u128, size: usize) -> usize {
+    (value.leading_zeros() as usize - (128 - size * 8)) / 8
+}
+
+impl Enid40 {
+    /// Writes the ENID to the buffer in Base58 encoding, with the Bitcoin
+    /// alphabet, and returns the encoded string.
+    ///
+    /// The string is at most 7 characters long. Each leading zero byte is
+    /// encoded as a `1`, so ENIDs with leading zero bytes have longer strings
+    /// than others of the same value would.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(enid40!("m6sc7n75").write_base58(&mut [0; 7]), "KF6cDF2");
+    /// assert_eq!(Enid40::ZERO.write_base58(&mut [0; 7]), "11111");
+    /// ```
+    pub const fn write_base58<'a>(&self, buf: &'a mut [u8; 7]) -> &'a str {
+        encode(self.to_u64() as u128, 5, buf)
+    }
+
+    /// Returns the ENID in Base58 encoding. See
+    /// [`write_base58`](Self::write_base58) for details.
+    ///
+    /// This requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_base58(&self) -> alloc::string::String {
+        self.write_base58(&mut [0; 7]).into()
+    }
+
+    /// Attempts to create an ENID from a Base58 string written by
+    /// [`write_base58`](Self::write_base58).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid40::parse_base58("KF6cDF2")?, enid40!("m6sc7n75"));
+    /// assert_eq!(Enid40::parse_base58("11112")?, Enid40::from_bytes([0, 0, 0, 0, 1]));
+    /// assert!(Enid40::parse_base58("1112").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not valid Base58, is too
+    /// long or too large for a 40-bit ENID, or is not the string that
+    /// [`write_base58`](Self::write_base58) would produce.
+    pub const fn parse_base58(s: &str) -> Result<Self, EnidParseError> {
+        match decode(s.as_bytes(), 5, 7) {
+            Ok(value) => {
+                let [.., a, b, c, d, e] = value.to_be_bytes();
+                Ok(Self::from_bytes([a, b, c, d, e]))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Enid80 {
+    /// Writes the ENID to the buffer in Base58 encoding, with the Bitcoin
+    /// alphabet, and returns the encoded string.
+    ///
+    /// The string is at most 14 characters long. See
+    /// [`Enid40::write_base58`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.write_base58(&mut [0; 14]), "EXvfkKyMcHtbN8");
+    /// ```
+    pub const fn write_base58<'a>(&self, buf: &'a mut [u8; 14]) -> &'a str {
+        encode(self.to_u128(), 10, buf)
+    }
+
+    /// Returns the ENID in Base58 encoding. See
+    /// [`write_base58`](Self::write_base58) for details.
+    ///
+    /// This requires the `alloc` feature.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_base58(&self) -> alloc::string::String {
+        self.write_base58(&mut [0; 14]).into()
+    }
+
+    /// Attempts to create an ENID from a Base58 string written by
+    /// [`write_base58`](Self::write_base58).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid80::parse_base58("EXvfkKyMcHtbN8")?, enid80!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not valid Base58, is too
+    /// long or too large for an 80-bit ENID, or is not the string that
+    /// [`write_base58`](Self::write_base58) would produce.
+    pub const fn parse_base58(s: &str) -> Result<Self, EnidParseError> {
+        match decode(s.as_bytes(), 10, 14) {
+            Ok(value) => {
+                let [.., a, b, c, d, e, f, g, h, i, j] = value.to_be_bytes();
+                Ok(Self::from_bytes([a, b, c, d, e, f, g, h, i, j]))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Enid {
+    /// Returns the ENID in Base58 encoding.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// assert_eq!(enid!("m6sc7n75").to_base58(), "KF6cDF2");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_base58(&self) -> alloc::string::String {
+        match self {
+            Enid::Enid40(enid) => enid.to_base58(),
+            Enid::Enid80(enid) => enid.to_base58(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let enids = [
+            Enid40::ZERO,
+            Enid40::MAX,
+            Enid40::from_bytes([0, 0, 0, 0, 1]),
+            Enid40::from_bytes([0, 0x3a, 0, 0, 0]),
+            Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5]),
+        ];
+
+        for enid in enids {
+            let mut buf = [0; 7];
+            let s = enid.write_base58(&mut buf);
+            assert_eq!(Enid40::parse_base58(s), Ok(enid));
+        }
+
+        let enids = [
+            Enid80::ZERO,
+            Enid80::MAX,
+            Enid80::from_bytes([0, 0, 0, 0, 0, 0, 0, 0, 0, 0x39]),
+            Enid80::from_bytes([0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]),
+        ];
+
+        for enid in enids {
+            let mut buf = [0; 14];
+            let s = enid.write_base58(&mut buf);
+            assert_eq!(Enid80::parse_base58(s), Ok(enid));
+        }
+
+        assert_eq!(Enid40::MAX.write_base58(&mut [0; 7]), "VtB5VXc");
+        assert_eq!(Enid80::MAX.write_base58(&mut [0; 14]), "FPBt6CHo3fovdL");
+    }
+
+    #[test]
+    fn invalid() {
+        // Empty, invalid characters, and too long.
+        assert_eq!(Enid40::parse_base58(""), Err(EnidParseError));
+        assert_eq!(Enid40::parse_base58("KF6cDF0"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_base58("KF6cDF2a"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_base58("111111"), Err(EnidParseError));
+
+        // Too large: one more than `Enid40::MAX`.
+        assert_eq!(Enid40::parse_base58("VtB5VXd"), Err(EnidParseError));
+
+        // Not canonical: missing or extra leading `1`s.
+        assert_eq!(Enid40::parse_base58("1112"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_base58("1KF6cDF2"), Err(EnidParseError));
+        assert_eq!(Enid80::parse_base58("11111"), Err(EnidParseError));
+    }
+}
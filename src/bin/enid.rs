@@ -0,0 +1,337 @@
+//! A command-line tool for generating, inspecting, and converting ENIDs.
+
+use enid::{Enid, Enid40, Enid80};
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+use std::env;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "\
+Usage:
+    enid generate [--40 | --80] [--sortable] [--seed <SEED>] [-n <COUNT>]
+    enid inspect <ENID>...
+    enid convert [--from <FORMAT>] [--to <FORMAT>] <VALUE>...
+
+Options:
+    --40, --80       Generate 40-bit or 80-bit ENIDs (default: 80-bit)
+    --sortable       Start each 80-bit ENID with a millisecond timestamp
+    --seed <SEED>    Generate ENIDs deterministically from the given seed
+    -n <COUNT>       Number of ENIDs to generate (default: 1)
+    --from <FORMAT>  Format of the input values (default: enid)
+    --to <FORMAT>    Format of the output values (default: enid)
+
+Formats: enid, hex, z85
+";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match args.split_first() {
+        Some((command, args)) => match command.as_str() {
+            "generate" => generate(args),
+            "inspect" => inspect(args),
+            "convert" => convert(args),
+            "help" | "-h" | "--help" => {
+                print!("{USAGE}");
+                Ok(())
+            }
+            _ => Err(format!("unknown command `{command}`")),
+        },
+        None => Err("missing command".into()),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("error: {e}\n\n{USAGE}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn generate(args: &[String]) -> Result<(), String> {
+    let options = GenerateOptions::parse(args)?;
+    let mut source = match options.seed {
+        Some(seed) => Source::Seeded(SmallRng::seed_from_u64(seed)),
+        None => Source::Os,
+    };
+
+    for _ in 0..options.count {
+        if !options.is_enid80 {
+            println!("{}", Enid40::from_bytes(source.bytes()?));
+        } else if options.is_sortable {
+            let enid = Enid80::from_timestamp_millis(now_millis()?, source.bytes()?);
+            println!("{enid}");
+        } else {
+            println!("{}", Enid80::from_bytes(source.bytes()?));
+        }
+    }
+
+    Ok(())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct GenerateOptions {
+    is_enid80: bool,
+    is_sortable: bool,
+    seed: Option<u64>,
+    count: u64,
+}
+
+impl GenerateOptions {
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut options = Self {
+            is_enid80: true,
+            is_sortable: false,
+            seed: None,
+            count: 1,
+        };
+
+        let mut args = args.iter();
+        while let Some(arg) = args.next() {
+            match arg.as_str() {
+                "--40" => options.is_enid80 = false,
+                "--80" => options.is_enid80 = true,
+                "--sortable" => options.is_sortable = true,
+                "--seed" => options.seed = Some(parse_number(args.next(), "--seed")?),
+                "-n" => options.count = parse_number(args.next(), "-n")?,
+                _ => return Err(format!("unexpected argument `{arg}`")),
+            }
+        }
+
+        if options.is_sortable && !options.is_enid80 {
+            return Err("sortable ENIDs must be 80-bit".into());
+        }
+
+        Ok(options)
+    }
+}
+
+fn inspect(args: &[String]) -> Result<(), String> {
+    if args.is_empty() {
+        return Err("missing ENID".into());
+    }
+
+    for arg in args {
+        let enid: Enid = arg.parse().map_err(|_| format!("invalid ENID `{arg}`"))?;
+
+        println!("{enid}");
+        println!("  bits:      {}", enid.as_bytes().len() * 8);
+        println!("  bytes:     {:?}", enid.as_bytes());
+        println!("  hex:       {}", Format::Hex.write(enid));
+        println!("  z85:       {}", Format::Z85.write(enid));
+
+        if let Enid::Enid80(enid) = enid {
+            let millis = enid.timestamp_millis();
+            println!("  timestamp: {} (if sortable)", format_millis(millis));
+        }
+    }
+
+    Ok(())
+}
+
+fn convert(args: &[String]) -> Result<(), String> {
+    let mut from = Format::Enid;
+    let mut to = Format::Enid;
+    let mut values = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--from" => from = Format::parse(args.next())?,
+            "--to" => to = Format::parse(args.next())?,
+            _ => values.push(arg),
+        }
+    }
+
+    if values.is_empty() {
+        return Err("missing value".into());
+    }
+
+    for value in values {
+        let enid = from
+            .read(value)
+            .ok_or_else(|| format!("invalid {} value `{value}`", from.name()))?;
+
+        println!("{}", to.write(enid));
+    }
+
+    Ok(())
+}
+
+#[derive(Copy, Clone)]
+enum Format {
+    Enid,
+    Hex,
+    Z85,
+}
+
+impl Format {
+    fn parse(arg: Option<&String>) -> Result<Self, String> {
+        match arg.map(String::as_str) {
+            Some("enid") => Ok(Self::Enid),
+            Some("hex") => Ok(Self::Hex),
+            Some("z85") => Ok(Self::Z85),
+            Some(arg) => Err(format!("unknown format `{arg}`")),
+            None => Err("missing format".into()),
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Enid => "enid",
+            Self::Hex => "hex",
+            Self::Z85 => "z85",
+        }
+    }
+
+    fn read(self, s: &str) -> Option<Enid> {
+        match self {
+            Self::Enid => s.parse().ok(),
+            Self::Hex => Enid::from_hex_str(s).ok(),
+            Self::Z85 => match s.len() {
+                10 => Enid40::parse_z85(s).ok().map(Enid::from),
+                15 => Enid80::parse_z85(s).ok().map(Enid::from),
+                _ => None,
+            },
+        }
+    }
+
+    fn write(self, enid: Enid) -> String {
+        match self {
+            Self::Enid => enid.to_string(),
+            Self::Hex => format!("{enid:x}"),
+            Self::Z85 => match enid {
+                Enid::Enid40(enid) => enid.write_z85(&mut [0; 10]).to_owned(),
+                Enid::Enid80(enid) => enid.write_z85(&mut [0; 15]).to_owned(),
+            },
+        }
+    }
+}
+
+enum Source {
+    Os,
+    Seeded(SmallRng),
+}
+
+impl Source {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let mut bytes = [0; N];
+
+        match self {
+            Self::Os => getrandom::fill(&mut bytes).map_err(|e| e.to_string())?,
+            Self::Seeded(rng) => rng.fill_bytes(&mut bytes),
+        }
+
+        Ok(bytes)
+    }
+}
+
+fn parse_number(arg: Option<&String>, name: &str) -> Result<u64, String> {
+    let arg = arg.ok_or_else(|| format!("missing value for `{name}`"))?;
+    arg.parse()
+        .map_err(|_| format!("invalid value `{arg}` for `{name}`"))
+}
+
+fn now_millis() -> Result<u64, String> {
+    let elapsed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| "system clock is before the Unix epoch")?;
+
+    u64::try_from(elapsed.as_millis()).map_err(|_| "system clock is out of range".into())
+}
+
+/// Formats a Unix timestamp in milliseconds as an RFC 3339 UTC date and time.
+fn format_millis(millis: u64) -> String {
+    let secs = millis / 1000;
+    let days = secs / 86400;
+    let secs_of_day = secs % 86400;
+
+    // Converts days since the Unix epoch to a civil date, using Howard Hinnant's
+    // `civil_from_days` algorithm.
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z % 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60,
+        millis % 1000,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|&arg| arg.to_owned()).collect()
+    }
+
+    #[test]
+    fn generate_options() {
+        assert_eq!(
+            GenerateOptions::parse(&[]),
+            Ok(GenerateOptions {
+                is_enid80: true,
+                is_sortable: false,
+                seed: None,
+                count: 1,
+            })
+        );
+        assert_eq!(
+            GenerateOptions::parse(&args(&["--40", "--seed", "7", "-n", "3"])),
+            Ok(GenerateOptions {
+                is_enid80: false,
+                is_sortable: false,
+                seed: Some(7),
+                count: 3,
+            })
+        );
+
+        assert!(GenerateOptions::parse(&args(&["--sortable", "--80"])).is_ok());
+        assert!(GenerateOptions::parse(&args(&["--sortable", "--40"])).is_err());
+        assert!(GenerateOptions::parse(&args(&["-n"])).is_err());
+        assert!(GenerateOptions::parse(&args(&["-n", "x"])).is_err());
+        assert!(GenerateOptions::parse(&args(&["--120"])).is_err());
+    }
+
+    #[test]
+    fn seeded_source() {
+        let mut a = Source::Seeded(SmallRng::seed_from_u64(7));
+        let mut b = Source::Seeded(SmallRng::seed_from_u64(7));
+
+        assert_eq!(a.bytes::<10>(), b.bytes::<10>());
+    }
+
+    #[test]
+    fn formats() {
+        let enid: Enid = "y3gx5gxm-mpb8ey39".parse().unwrap();
+
+        for format in ["enid", "hex", "z85"] {
+            let format = Format::parse(Some(&format.to_owned())).unwrap();
+            assert_eq!(format.read(&format.write(enid)), Some(enid));
+        }
+
+        assert!(Format::parse(Some(&"base64".to_owned())).is_err());
+        assert!(Format::parse(None).is_err());
+    }
+
+    #[test]
+    fn format_dates() {
+        assert_eq!(format_millis(0), "1970-01-01T00:00:00.000Z");
+        assert_eq!(format_millis(1_700_000_000_123), "2023-11-14T22:13:20.123Z");
+        assert_eq!(format_millis(951_782_400_000), "2000-02-29T00:00:00.000Z");
+        assert_eq!(format_millis(4_107_542_399_999), "2100-02-28T23:59:59.999Z");
+        assert_eq!(format_millis((1 << 48) - 1), "10889-08-02T05:31:50.655Z");
+    }
+}
@@ -0,0 +1,111 @@
+pub(crate) const fn reverse<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    let mut out = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        out[i] = bytes[N - 1 - i];
+        i += 1;
+    }
+
+    out
+}
+
+/// Interleaves the bits of the upper and lower halves, so that bit `2i` of the
+/// output (counting from the most significant bit) is bit `i` of the upper half,
+/// and bit `2i + 1` is bit `i` of the lower half.
+pub(crate) const fn interleave<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    let half = N * 4;
+    let mut out = [0; N];
+
+    let mut i = 0;
+    while i < N * 8 {
+        let bit = get_bit(&bytes, (i % 2) * half + i / 2);
+        set_bit(&mut out, i, bit);
+        i += 1;
+    }
+
+    out
+}
+
+pub(crate) const fn deinterleave<const N: usize>(bytes: [u8; N]) -> [u8; N] {
+    let half = N * 4;
+    let mut out = [0; N];
+
+    let mut i = 0;
+    while i < N * 8 {
+        let bit = get_bit(&bytes, i);
+        set_bit(&mut out, (i % 2) * half + i / 2, bit);
+        i += 1;
+    }
+
+    out
+}
+
+pub(crate) const fn xor<const N: usize>(a: &[u8; N], b: &[u8; N]) -> [u8; N] {
+    let mut out = [0; N];
+
+    let mut i = 0;
+    while i < N {
+        out[i] = a[i] ^ b[i];
+        i += 1;
+    }
+
+    out
+}
+
+/// Returns the number of leading zero bits, counting from the most significant
+/// bit of the first byte.
+pub(crate) const fn leading_zeros<const N: usize>(bytes: &[u8; N]) -> u32 {
+    let mut i = 0;
+    while i < N {
+        if bytes[i] != 0 {
+            return i as u32 * 8 + bytes[i].leading_zeros();
+        }
+        i += 1;
+    }
+
+    N as u32 * 8
+}
+
+pub(crate) const fn count_ones<const N: usize>(bytes: &[u8; N]) -> u32 {
+    let mut count = 0;
+
+    let mut i = 0;
+    while i < N {
+        count += bytes[i].count_ones();
+        i += 1;
+    }
+
+    count
+}
+
+const fn get_bit<const N: usize>(bytes: &[u8; N], idx: usize) -> u8 {
+    (bytes[idx / 8] >> (7 - idx % 8)) & 1
+}
+
+const fn set_bit<const N: usize>(bytes: &mut [u8; N], idx: usize, bit: u8) {
+    bytes[idx / 8] |= bit << (7 - idx % 8);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn interleave_round_trip() {
+        let bytes = [0xa1, 0xb2, 0xc3, 0xd4, 0xe5];
+        assert_eq!(deinterleave(interleave(bytes)), bytes);
+
+        let bytes = [0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69];
+        assert_eq!(deinterleave(interleave(bytes)), bytes);
+    }
+
+    #[test]
+    fn interleave_placement() {
+        assert_eq!(interleave([0xff, 0xff, 0xf0, 0x00, 0x00]), [0xaa; 5]);
+        assert_eq!(interleave([0x00, 0x00, 0x0f, 0xff, 0xff]), [0x55; 5]);
+        assert_eq!(interleave([0x80, 0, 0, 0, 0]), [0x80, 0, 0, 0, 0]);
+        assert_eq!(interleave([0, 0, 0, 0, 1]), [0, 0, 0, 0, 1]);
+        assert_eq!(interleave([0, 0, 0x08, 0, 0]), [0x40, 0, 0, 0, 0]);
+    }
+}
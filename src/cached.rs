@@ -0,0 +1,119 @@
+use crate::enid::Enid;
+use core::cell::OnceCell;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+
+/// An ENID that caches its string form.
+///
+/// The string is encoded the first time it is needed, and reused after that,
+/// which avoids repeatedly encoding an ID that is formatted many times, such as
+/// a trace ID included in every log line for a request.
+///
+/// The cache uses a [`OnceCell`], so `CachedEnid` is not [`Sync`]. Comparisons
+/// and hashing use the ENID alone.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid80, CachedEnid};
+/// let enid = CachedEnid::new(enid80!("y3gx5gxm-kbhqr4fs"));
+///
+/// assert_eq!(enid.as_str(), "y3gx5gxm-kbhqr4fs");
+/// assert_eq!(format!("request {enid}"), "request y3gx5gxm-kbhqr4fs");
+/// ```
+#[derive(Clone)]
+pub struct CachedEnid {
+    enid: Enid,
+    encoded: OnceCell<([u8; 17], usize)>,
+}
+
+impl CachedEnid {
+    /// Creates a wrapper around the given ENID, without encoding it.
+    #[must_use]
+    #[inline]
+    pub fn new(enid: impl Into<Enid>) -> Self {
+        Self {
+            enid: enid.into(),
+            encoded: OnceCell::new(),
+        }
+    }
+
+    /// Returns the wrapped ENID.
+    #[must_use]
+    #[inline]
+    pub const fn enid(&self) -> Enid {
+        self.enid
+    }
+
+    /// Returns the string form of the ENID, encoding it if this is the first
+    /// call.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        let (buf, len) = self.encoded.get_or_init(|| {
+            let mut buf = [0; 17];
+            let len = self.enid.write_ascii(&mut buf).len();
+            (buf, len)
+        });
+
+        crate::ascii::to_str(&buf[..*len])
+    }
+}
+
+impl Debug for CachedEnid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for CachedEnid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl AsRef<str> for CachedEnid {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<T: Into<Enid>> From<T> for CachedEnid {
+    #[inline]
+    fn from(enid: T) -> Self {
+        Self::new(enid)
+    }
+}
+
+impl PartialEq for CachedEnid {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.enid == other.enid
+    }
+}
+
+impl Eq for CachedEnid {}
+
+impl PartialOrd for CachedEnid {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for CachedEnid {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.enid.cmp(&other.enid)
+    }
+}
+
+impl Hash for CachedEnid {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.enid.hash(state);
+    }
+}
@@ -0,0 +1,212 @@
+use crate::enid::{EnidN, EnidParseError};
+use crate::format;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+
+/// The characters used for check values, in order of value. The first 32 are
+/// the ENID alphabet, followed by the extra check symbols of Crockford's
+/// Base32.
+const CHECK_CHARS: [u8; 37] = *b"0123456789abcdefghjkmnpqrstvwxyz*~$=u";
+
+/// An [`EnidN`] whose string form ends with a check character, such as
+/// `"m6sc7n750"`, to catch typing mistakes.
+///
+/// The check character is the ENID's value modulo 37, written with the check
+/// symbols of Crockford's Base32. Because 37 is prime, every error that
+/// changes a single character, and every swap of two adjacent characters, is
+/// detected when the string is parsed. This is intended for IDs that are read
+/// over the phone or copied from paper. [`CheckedEnid40`] and
+/// [`CheckedEnid80`] are aliases for the common sizes.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, CheckedEnid40};
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let id = CheckedEnid40::new(enid40!("m6sc7n75"));
+///
+/// assert_eq!(id.to_string(), "m6sc7n750");
+/// assert_eq!(CheckedEnid40::parse_str("m6sc7n750")?, id);
+///
+/// // A mistyped character, and two swapped characters.
+/// assert!(CheckedEnid40::parse_str("m6sc7m750").is_err());
+/// assert!(CheckedEnid40::parse_str("m6sc7n570").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct CheckedEnidN<const N: usize>(EnidN<N>);
+
+/// A 40-bit ENID with a check character.
+pub type CheckedEnid40 = CheckedEnidN<5>;
+
+/// An 80-bit ENID with a check character.
+pub type CheckedEnid80 = CheckedEnidN<10>;
+
+impl<const N: usize> CheckedEnidN<N> {
+    /// The length of the string form, including the check character.
+    pub const STR_LEN: usize = EnidN::<N>::STR_LEN + 1;
+
+    /// Wraps the ENID.
+    #[must_use]
+    #[inline]
+    pub const fn new(enid: EnidN<N>) -> Self {
+        Self(enid)
+    }
+
+    /// Returns the ENID.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> EnidN<N> {
+        self.0
+    }
+
+    /// Returns the check character.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, CheckedEnid80};
+    /// let id = CheckedEnid80::new(enid80!("y3gx5gxm-mpb8ey39"));
+    ///
+    /// assert_eq!(id.check_char(), b'c');
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn check_char(&self) -> u8 {
+        let bytes = self.0.as_bytes();
+        let mut rem = 0;
+        let mut i = 0;
+
+        while i < N {
+            rem = (rem * 256 + bytes[i] as usize) % CHECK_CHARS.len();
+            i += 1;
+        }
+
+        CHECK_CHARS[rem]
+    }
+
+    /// Attempts to create an ENID from a string with a check character.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID
+    /// followed by the correct check character.
+    pub const fn parse_str(s: &str) -> Result<Self, EnidParseError> {
+        let Some((&check, enid)) = s.as_bytes().split_last() else {
+            return Err(EnidParseError);
+        };
+
+        match EnidN::parse_str_ascii(enid) {
+            Ok(enid) if Self(enid).check_char() == check => Ok(Self(enid)),
+            _ => Err(EnidParseError),
+        }
+    }
+}
+
+impl<const N: usize> Debug for CheckedEnidN<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for CheckedEnidN<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let chars = format::chars(&self.0).chain([self.check_char()]);
+
+        format::write_padded(f, Self::STR_LEN, chars.map(char::from))
+    }
+}
+
+impl<const N: usize> FromStr for CheckedEnidN<N> {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+impl<const N: usize> From<EnidN<N>> for CheckedEnidN<N> {
+    #[inline]
+    fn from(enid: EnidN<N>) -> Self {
+        Self::new(enid)
+    }
+}
+
+impl<const N: usize> From<CheckedEnidN<N>> for EnidN<N> {
+    #[inline]
+    fn from(enid: CheckedEnidN<N>) -> Self {
+        enid.get()
+    }
+}
+
+impl<const N: usize> AsRef<EnidN<N>> for CheckedEnidN<N> {
+    #[inline]
+    fn as_ref(&self) -> &EnidN<N> {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base32;
+    use crate::enid::{Enid40, Enid80};
+    use std::string::ToString;
+
+    #[test]
+    fn detects_errors() {
+        let enids = [
+            Enid40::ZERO.into_bytes(),
+            Enid40::MAX.into_bytes(),
+            [0xa1, 0xb2, 0xc3, 0xd4, 0xe5],
+            [0x12, 0x34, 0x56, 0x78, 0x9a],
+        ];
+
+        for bytes in enids {
+            let id = CheckedEnid40::new(Enid40::from_bytes(bytes));
+            let s = id.to_string();
+            assert_eq!(CheckedEnid40::parse_str(&s), Ok(id));
+
+            let mut buf = s.into_bytes();
+
+            // Every substitution of a single character.
+            for i in 0..Enid40::STR_LEN {
+                let original = buf[i];
+
+                for &c in &base32::ALPHABET {
+                    if c != original {
+                        buf[i] = c;
+                        let s = core::str::from_utf8(&buf).unwrap();
+                        assert!(CheckedEnid40::parse_str(s).is_err(), "{s}");
+                    }
+                }
+
+                buf[i] = original;
+            }
+
+            // Every swap of two different adjacent characters.
+            for i in 0..Enid40::STR_LEN - 1 {
+                if buf[i] != buf[i + 1] {
+                    buf.swap(i, i + 1);
+                    let s = core::str::from_utf8(&buf).unwrap();
+                    assert!(CheckedEnid40::parse_str(s).is_err(), "{s}");
+                    buf.swap(i, i + 1);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let id = CheckedEnid80::new(Enid80::from_bytes([0xff; 10]));
+        let s = id.to_string();
+
+        assert_eq!(CheckedEnid80::parse_str(&s), Ok(id));
+        assert_eq!(CheckedEnid80::parse_str(&s[..17]), Err(EnidParseError));
+        assert_eq!(CheckedEnid80::parse_str(""), Err(EnidParseError));
+    }
+}
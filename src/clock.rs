@@ -0,0 +1,205 @@
+//! Sources of the current time, for ENIDs that embed timestamps.
+
+/// A source of the current time.
+pub trait EnidClock {
+    /// Returns the number of milliseconds since the Unix epoch.
+    fn now_millis(&self) -> u64;
+}
+
+impl<C: EnidClock + ?Sized> EnidClock for &C {
+    #[inline]
+    fn now_millis(&self) -> u64 {
+        (**self).now_millis()
+    }
+}
+
+/// A clock that always returns the same time, which is useful for testing.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::clock::{EnidClock, FixedClock};
+/// let clock = FixedClock(1_700_000_000_000);
+///
+/// assert_eq!(clock.now_millis(), 1_700_000_000_000);
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FixedClock(pub u64);
+
+impl EnidClock for FixedClock {
+    #[inline]
+    fn now_millis(&self) -> u64 {
+        self.0
+    }
+}
+
+/// A clock that reads the system time.
+///
+/// Times before the Unix epoch are treated as the epoch itself.
+#[cfg(feature = "std")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl EnidClock for SystemClock {
+    fn now_millis(&self) -> u64 {
+        use std::time::{SystemTime, UNIX_EPOCH};
+
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_millis() as u64)
+    }
+}
+
+/// A clock that converts ticks from a monotonic counter into wall-clock time.
+///
+/// This allows timestamped ENIDs to be created on targets without
+/// `std::time`, such as microcontrollers with a hardware timer. The tick count
+/// is read by calling a function, so it can wrap the `now` method of a HAL's
+/// monotonic timer, and is converted to milliseconds using the tick rate.
+/// The epoch offset is the Unix time in milliseconds at which the counter read
+/// zero, which can be set from an RTC or a time server at startup.
+///
+/// For timers that implement [`embedded_time::Clock`], use
+/// [`EmbeddedTimeClock`] instead. Otherwise, the closure can read from
+/// whatever the target provides, such as an RTIC monotonic,
+/// `embassy_time::Instant::now`, or a hardware counter register.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::clock::{EnidClock, TickClock};
+/// # use core::sync::atomic::{AtomicU64, Ordering};
+/// static TICKS: AtomicU64 = AtomicU64::new(0);
+///
+/// let clock = TickClock::new(|| TICKS.load(Ordering::Relaxed), 32_768, 1_700_000_000_000);
+///
+/// TICKS.store(65_536, Ordering::Relaxed);
+/// assert_eq!(clock.now_millis(), 1_700_000_002_000);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct TickClock<F> {
+    ticks: F,
+    ticks_per_second: u64,
+    epoch_offset_millis: u64,
+}
+
+impl<F: Fn() -> u64> TickClock<F> {
+    /// Creates a clock from a tick counter, its rate in ticks per second, and
+    /// the Unix time in milliseconds at which it read zero.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `ticks_per_second` is zero.
+    #[must_use]
+    #[inline]
+    pub const fn new(ticks: F, ticks_per_second: u64, epoch_offset_millis: u64) -> Self {
+        assert!(ticks_per_second != 0, "tick rate must not be zero");
+
+        Self {
+            ticks,
+            ticks_per_second,
+            epoch_offset_millis,
+        }
+    }
+
+    /// Sets the Unix time in milliseconds at which the counter read zero.
+    #[inline]
+    pub const fn set_epoch_offset_millis(&mut self, epoch_offset_millis: u64) {
+        self.epoch_offset_millis = epoch_offset_millis;
+    }
+}
+
+impl<F: Fn() -> u64> EnidClock for TickClock<F> {
+    #[inline]
+    fn now_millis(&self) -> u64 {
+        let elapsed = u128::from((self.ticks)()) * 1000 / u128::from(self.ticks_per_second);
+
+        self.epoch_offset_millis
+            .saturating_add(u64::try_from(elapsed).unwrap_or(u64::MAX))
+    }
+}
+
+/// A clock that reads an [`embedded_time::Clock`], such as a hardware timer.
+///
+/// Instants from the clock count from its own epoch, usually when it started,
+/// so they are converted to milliseconds and added to the Unix time in
+/// milliseconds at that epoch, as with [`TickClock`]. If the clock cannot be
+/// read, or the time since its epoch does not fit in milliseconds, the epoch
+/// offset is returned.
+///
+/// This requires the `embedded-time` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::clock::{EmbeddedTimeClock, EnidClock};
+/// use embedded_time::fraction::Fraction;
+/// use embedded_time::{Clock, Instant, clock};
+///
+/// // A 1 kHz timer that has been running for 2 seconds.
+/// struct Timer;
+///
+/// impl Clock for Timer {
+///     type T = u32;
+///
+///     const SCALING_FACTOR: Fraction = Fraction::new(1, 1_000);
+///
+///     fn try_now(&self) -> Result<Instant<Self>, clock::Error> {
+///         Ok(Instant::new(2_000))
+///     }
+/// }
+///
+/// let clock = EmbeddedTimeClock::new(Timer, 1_700_000_000_000);
+///
+/// assert_eq!(clock.now_millis(), 1_700_000_002_000);
+/// ```
+#[cfg(feature = "embedded-time")]
+#[derive(Debug, Copy, Clone)]
+pub struct EmbeddedTimeClock<C> {
+    clock: C,
+    epoch_offset_millis: u64,
+}
+
+#[cfg(feature = "embedded-time")]
+impl<C: embedded_time::Clock> EmbeddedTimeClock<C> {
+    /// Creates a clock from an `embedded-time` clock and the Unix time in
+    /// milliseconds at its epoch.
+    #[must_use]
+    #[inline]
+    pub const fn new(clock: C, epoch_offset_millis: u64) -> Self {
+        Self {
+            clock,
+            epoch_offset_millis,
+        }
+    }
+
+    /// Sets the Unix time in milliseconds at the clock's epoch.
+    #[inline]
+    pub const fn set_epoch_offset_millis(&mut self, epoch_offset_millis: u64) {
+        self.epoch_offset_millis = epoch_offset_millis;
+    }
+
+    /// Returns the wrapped clock.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> C {
+        self.clock
+    }
+}
+
+#[cfg(feature = "embedded-time")]
+impl<C: embedded_time::Clock> EnidClock for EmbeddedTimeClock<C> {
+    fn now_millis(&self) -> u64 {
+        use embedded_time::duration::Milliseconds;
+
+        let elapsed = self
+            .clock
+            .try_now()
+            .ok()
+            .and_then(|now| Milliseconds::<u64>::try_from(now.duration_since_epoch()).ok())
+            .map_or(0, |Milliseconds(millis)| millis);
+
+        self.epoch_offset_millis.saturating_add(elapsed)
+    }
+}
@@ -0,0 +1,189 @@
+//! Codecs for framing streams of ENIDs with [`tokio_util`].
+//!
+//! [`Enid40Codec`] and [`Enid80Codec`] frame ENIDs as their fixed-size bytes.
+//! [`EnidCodec`] frames ENIDs of either size as a tag byte followed by the
+//! bytes, where the tag is `0` for an [`Enid40`] and `1` for an [`Enid80`].
+//! This is the same layout as the `borsh` encoding of [`Enid`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use bytes::BytesMut;
+//! # use enid::{enid40, Enid};
+//! # use enid::codec::EnidCodec;
+//! # use tokio_util::codec::{Decoder, Encoder};
+//! let mut buf = BytesMut::new();
+//! EnidCodec.encode(Enid::from(enid40!("m6sc7n75")), &mut buf)?;
+//!
+//! assert_eq!(&buf[..], &[0, 0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+//! assert_eq!(EnidCodec.decode(&mut buf)?, Some(Enid::from(enid40!("m6sc7n75"))));
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use crate::enid::{Enid, Enid40, Enid80};
+use bytes::{Buf, BufMut, BytesMut};
+use std::io::{self, ErrorKind};
+use tokio_util::codec::{Decoder, Encoder};
+
+/// A codec that frames [`Enid40`] values as 5 bytes each.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Enid40Codec;
+
+/// A codec that frames [`Enid80`] values as 10 bytes each.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct Enid80Codec;
+
+/// A codec that frames [`Enid`] values as a tag byte followed by 5 or 10 bytes.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct EnidCodec;
+
+impl Encoder<Enid40> for Enid40Codec {
+    type Error = io::Error;
+
+    #[inline]
+    fn encode(&mut self, enid: Enid40, dst: &mut BytesMut) -> io::Result<()> {
+        dst.put_slice(enid.as_bytes());
+        Ok(())
+    }
+}
+
+impl Decoder for Enid40Codec {
+    type Item = Enid40;
+    type Error = io::Error;
+
+    #[inline]
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Enid40>> {
+        let Some(&bytes) = src.first_chunk() else {
+            return Ok(None);
+        };
+
+        src.advance(bytes.len());
+        Ok(Some(Enid40::from_bytes(bytes)))
+    }
+}
+
+impl Encoder<Enid80> for Enid80Codec {
+    type Error = io::Error;
+
+    #[inline]
+    fn encode(&mut self, enid: Enid80, dst: &mut BytesMut) -> io::Result<()> {
+        dst.put_slice(enid.as_bytes());
+        Ok(())
+    }
+}
+
+impl Decoder for Enid80Codec {
+    type Item = Enid80;
+    type Error = io::Error;
+
+    #[inline]
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Enid80>> {
+        let Some(&bytes) = src.first_chunk() else {
+            return Ok(None);
+        };
+
+        src.advance(bytes.len());
+        Ok(Some(Enid80::from_bytes(bytes)))
+    }
+}
+
+impl Encoder<Enid> for EnidCodec {
+    type Error = io::Error;
+
+    fn encode(&mut self, enid: Enid, dst: &mut BytesMut) -> io::Result<()> {
+        match enid {
+            Enid::Enid40(enid) => {
+                dst.put_u8(0);
+                Enid40Codec.encode(enid, dst)
+            }
+            Enid::Enid80(enid) => {
+                dst.put_u8(1);
+                Enid80Codec.encode(enid, dst)
+            }
+        }
+    }
+}
+
+impl Decoder for EnidCodec {
+    type Item = Enid;
+    type Error = io::Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> io::Result<Option<Enid>> {
+        let len = match src.first() {
+            None => return Ok(None),
+            Some(0) => 5,
+            Some(1) => 10,
+            Some(tag) => {
+                return Err(io::Error::new(
+                    ErrorKind::InvalidData,
+                    std::format!("invalid Enid tag: {tag}"),
+                ));
+            }
+        };
+
+        if src.len() < 1 + len {
+            src.reserve(1 + len - src.len());
+            return Ok(None);
+        }
+
+        let tag = src.get_u8();
+
+        if tag == 0 {
+            Enid40Codec.decode(src).map(|enid| enid.map(Enid::Enid40))
+        } else {
+            Enid80Codec.decode(src).map(|enid| enid.map(Enid::Enid80))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enid40, enid80};
+
+    #[test]
+    fn partial_frames() {
+        let mut buf = BytesMut::new();
+        EnidCodec
+            .encode(Enid::from(enid80!("y3gx5gxm-kbhqr4fs")), &mut buf)
+            .unwrap();
+        EnidCodec
+            .encode(Enid::from(enid40!("m6sc7n75")), &mut buf)
+            .unwrap();
+
+        let mut partial = buf.split_to(4);
+        assert_eq!(EnidCodec.decode(&mut partial).unwrap(), None);
+
+        partial.unsplit(buf);
+        assert_eq!(
+            EnidCodec.decode(&mut partial).unwrap(),
+            Some(Enid::from(enid80!("y3gx5gxm-kbhqr4fs"))),
+        );
+        assert_eq!(
+            EnidCodec.decode(&mut partial).unwrap(),
+            Some(Enid::from(enid40!("m6sc7n75"))),
+        );
+        assert_eq!(EnidCodec.decode(&mut partial).unwrap(), None);
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh_layout() {
+        for enid in [
+            Enid::from(enid40!("m6sc7n75")),
+            Enid::from(enid80!("y3gx5gxm-kbhqr4fs")),
+        ] {
+            let mut buf = BytesMut::new();
+            EnidCodec.encode(enid, &mut buf).unwrap();
+
+            assert_eq!(buf[..], borsh::to_vec(&enid).unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid_tag() {
+        let mut buf = BytesMut::from(&[2, 0, 0, 0, 0, 0][..]);
+
+        assert!(EnidCodec.decode(&mut buf).is_err());
+    }
+}
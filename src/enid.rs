@@ -1,8 +1,12 @@
-use crate::base32;
+use crate::base32::Decoding;
+use crate::format::{self, Compact, Grouped, Uppercase, Urn};
+use crate::{ascii, base32, bits, hash};
+use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::error::Error;
-use core::fmt::{self, Debug, Display, Formatter};
-use core::str::{self, FromStr};
+use core::fmt::{self, Alignment, Debug, Display, Formatter, LowerHex, UpperHex, Write};
+use core::hash::{Hash, Hasher};
+use core::str::FromStr;
 
 /// An error returned when parsing an invalid ENID string.
 ///
@@ -19,25 +23,242 @@ impl Display for EnidParseError {
 
 impl Error for EnidParseError {}
 
-/// A 40-bit ENID.
+impl From<EnidParseErrorDetailed> for EnidParseError {
+    #[inline]
+    fn from(_: EnidParseErrorDetailed) -> Self {
+        Self
+    }
+}
+
+/// An error returned when parsing an invalid ENID string, describing what was
+/// wrong with it.
+///
+/// This is returned by [`EnidN::parse_str_detailed`] and
+/// [`Enid::parse_str_detailed`], for tools that report why an ENID was
+/// rejected. Positions are byte offsets into the string.
 ///
 /// # Examples
 ///
 /// ```
-/// # use enid::Enid40;
+/// # use enid::{Enid80, EnidParseErrorDetailed};
+/// let err = Enid80::parse_str_detailed("y3gx5gxm-mpu8ey39").unwrap_err();
+///
+/// assert_eq!(err, EnidParseErrorDetailed::InvalidCharacter { position: 11, byte: b'u' });
+/// assert_eq!(err.to_string(), "invalid character 'u' at position 11");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum EnidParseErrorDetailed {
+    /// The string is not the length of an ENID.
+    InvalidLength {
+        /// The length of an ENID string.
+        expected: usize,
+        /// The length of the string.
+        found: usize,
+    },
+
+    /// A character is not in the ENID alphabet.
+    InvalidCharacter {
+        /// The position of the character.
+        position: usize,
+        /// The first byte of the character.
+        byte: u8,
+    },
+
+    /// A hyphen is missing between two groups.
+    MissingSeparator {
+        /// The position where the hyphen was expected.
+        position: usize,
+    },
+}
+
+impl Display for EnidParseErrorDetailed {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match *self {
+            Self::InvalidLength { expected, found } => {
+                write!(f, "invalid ENID length: expected {expected}, found {found}")
+            }
+            Self::InvalidCharacter { position, byte } if byte.is_ascii_graphic() => {
+                write!(
+                    f,
+                    "invalid character {:?} at position {position}",
+                    char::from(byte)
+                )
+            }
+            Self::InvalidCharacter { position, byte } => {
+                write!(f, "invalid byte {byte:#04x} at position {position}")
+            }
+            Self::MissingSeparator { position } => {
+                write!(f, "missing '-' at position {position}")
+            }
+        }
+    }
+}
+
+impl Error for EnidParseErrorDetailed {}
+
+/// An error returned when converting an [`Enid`] to an ENID of a different
+/// size.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EnidSizeError;
+
+impl Display for EnidSizeError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("ENID is not the expected size")
+    }
+}
+
+impl Error for EnidSizeError {}
+
+/// An error returned when converting a byte slice that is not the size of an
+/// ENID.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid;
+/// let err = Enid::try_from(&[0; 7][..]).unwrap_err();
+///
+/// assert_eq!(err.byte_len(), 7);
+/// assert_eq!(err.expected_sizes(), [5, 10]);
+/// assert_eq!(err.to_string(), "expected 5 or 10 bytes for an ENID, found 7");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EnidLengthError {
+    len: usize,
+    sizes: &'static [usize],
+}
+
+impl EnidLengthError {
+    /// Returns the length of the byte slice.
+    #[must_use]
+    #[inline]
+    pub const fn byte_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the sizes in bytes that would have been accepted.
+    #[must_use]
+    #[inline]
+    pub const fn expected_sizes(&self) -> &'static [usize] {
+        self.sizes
+    }
+}
+
+impl Display for EnidLengthError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("expected ")?;
+
+        for (i, size) in self.sizes.iter().enumerate() {
+            if i > 0 {
+                f.write_str(" or ")?;
+            }
+
+            write!(f, "{size}")?;
+        }
+
+        write!(f, " bytes for an ENID, found {}", self.len)
+    }
+}
+
+impl Error for EnidLengthError {}
+
+/// An error returned when a buffer is too small to hold the string form of
+/// an ENID.
+///
+/// This is returned by methods that write to a caller's buffer, such as
+/// [`EnidN::encode_to_slice`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct BufferTooSmallError {
+    required: usize,
+    len: usize,
+}
+
+impl BufferTooSmallError {
+    pub(crate) const fn new(required: usize, len: usize) -> Self {
+        Self { required, len }
+    }
+
+    /// Returns the length the buffer needed to be.
+    #[must_use]
+    #[inline]
+    pub const fn required_len(&self) -> usize {
+        self.required
+    }
+
+    /// Returns the length of the buffer.
+    #[must_use]
+    #[inline]
+    pub const fn buf_len(&self) -> usize {
+        self.len
+    }
+}
+
+impl Display for BufferTooSmallError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "buffer of length {} is too small for an ENID of length {}",
+            self.len, self.required
+        )
+    }
+}
+
+impl Error for BufferTooSmallError {}
+
+/// An ENID of `N` bytes.
+///
+/// ENIDs are formatted as groups of 8 characters for every 5 bytes, separated
+/// by hyphens, so `N` must be a non-zero multiple of 5. Using any other size is
+/// a compile-time error.
+///
+/// [`Enid40`], [`Enid80`], [`Enid120`], and [`Enid160`] are aliases for the common sizes,
+/// and have additional methods and trait implementations. Other sizes can be
+/// defined as aliases in the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::EnidN;
 /// # fn main() -> Result<(), enid::EnidParseError> {
-/// let enid = Enid40::parse_str("m6sc7n75")?;
+/// type Enid200 = EnidN<25>;
 ///
-/// assert_eq!(enid.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+/// let enid = Enid200::from_bytes([0xff; 25]);
+///
+/// assert_eq!(enid.to_string(), "zzzzzzzz-zzzzzzzz-zzzzzzzz-zzzzzzzz-zzzzzzzz");
+/// assert_eq!(enid.to_string().parse(), Ok(enid));
 /// # Ok(())
 /// # }
 /// ```
-#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+///
+/// Sizes that are not a multiple of 5 bytes will not compile:
+///
+/// ```compile_fail
+/// # use enid::EnidN;
+/// let enid = EnidN::<7>::from_bytes([0; 7]);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 #[repr(transparent)]
-pub struct Enid40([u8; 5]);
+pub struct EnidN<const N: usize>([u8; N]);
 
-impl Enid40 {
-    /// An ENID filled with zeros (`"00000000"`).
+impl<const N: usize> EnidN<N> {
+    pub(crate) const GROUPS: usize = {
+        assert!(N > 0 && N % 5 == 0, "ENIDs must be a multiple of 5 bytes");
+        N / 5
+    };
+
+    /// The length of the ENID's string form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{Enid40, Enid80};
+    /// assert_eq!(Enid40::STR_LEN, 8);
+    /// assert_eq!(Enid80::STR_LEN, 17);
+    /// ```
+    pub const STR_LEN: usize = Self::GROUPS * 9 - 1;
+
+    /// An ENID filled with zeros, such as `"00000000"`.
     ///
     /// # Examples
     ///
@@ -45,7 +266,30 @@ impl Enid40 {
     /// # use enid::Enid40;
     /// assert_eq!(Enid40::ZERO, Enid40::from_bytes([0; 5]));
     /// ```
-    pub const ZERO: Self = Self([0; 5]);
+    pub const ZERO: Self = Self::from_bytes([0; N]);
+
+    /// The nil ENID, which is the same as [`ZERO`](Self::ZERO) and is the
+    /// smallest ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(Enid40::NIL, enid40!("00000000"));
+    /// assert!(Enid40::NIL <= enid40!("m6sc7n75"));
+    /// ```
+    pub const NIL: Self = Self::ZERO;
+
+    /// The largest ENID, filled with ones, such as `"zzzzzzzz"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// assert_eq!(Enid80::MAX, enid80!("zzzzzzzz-zzzzzzzz"));
+    /// assert!(Enid80::MAX >= enid80!("y3gx5gxm-mpb8ey39"));
+    /// ```
+    pub const MAX: Self = Self::from_bytes([0xff; N]);
 
     /// Creates an ENID from the given bytes.
     ///
@@ -60,7 +304,9 @@ impl Enid40 {
     /// ```
     #[must_use]
     #[inline]
-    pub const fn from_bytes(bytes: [u8; 5]) -> Self {
+    pub const fn from_bytes(bytes: [u8; N]) -> Self {
+        let _ = Self::GROUPS;
+
         Self(bytes)
     }
 
@@ -69,11 +315,13 @@ impl Enid40 {
     /// # Examples
     ///
     /// ```
-    /// # use enid::Enid40;
+    /// # use enid::{Enid40, Enid80};
     /// # fn main() -> Result<(), enid::EnidParseError> {
-    /// let enid = Enid40::parse_str("m6sc7n75")?;
+    /// let enid40 = Enid40::parse_str("m6sc7n75")?;
+    /// let enid80 = Enid80::parse_str("y3gx5gxm-mpb8ey39")?;
     ///
-    /// assert_eq!(enid.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    /// assert_eq!(enid40.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    /// assert_eq!(enid80.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
     /// # Ok(())
     /// # }
     /// ```
@@ -103,247 +351,421 @@ impl Enid40 {
     /// # Errors
     ///
     /// Returns an [`EnidParseError`] if the string is not a valid ENID.
+    #[inline]
     pub const fn parse_str_ascii(s: &[u8]) -> Result<Self, EnidParseError> {
-        if s.len() != 8 {
-            return Err(EnidParseError);
-        }
-
-        match base32::decode(*s.first_chunk().unwrap()) {
-            Ok(bytes) => Ok(Self(bytes)),
-            Err(e) => Err(e),
-        }
+        Self::parse_ascii(s, Decoding::Strict, false)
     }
 
-    /// Returns a reference to the underlying bytes.
+    /// Attempts to create an ENID from a string, describing what was wrong
+    /// with the string if it is not a valid ENID.
+    ///
+    /// This accepts the same strings as [`parse_str`](Self::parse_str).
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::enid40;
-    /// let enid = enid40!("m6sc7n75");
-    ///
-    /// assert_eq!(enid.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    /// # use enid::{enid80, Enid80, EnidParseErrorDetailed};
+    /// assert_eq!(Enid80::parse_str_detailed("y3gx5gxm-mpb8ey39"), Ok(enid80!("y3gx5gxm-mpb8ey39")));
+    /// assert_eq!(
+    ///     Enid80::parse_str_detailed("y3gx5gxm"),
+    ///     Err(EnidParseErrorDetailed::InvalidLength { expected: 17, found: 8 }),
+    /// );
+    /// assert_eq!(
+    ///     Enid80::parse_str_detailed("y3gx5gxm_mpb8ey39"),
+    ///     Err(EnidParseErrorDetailed::MissingSeparator { position: 8 }),
+    /// );
     /// ```
-    #[must_use]
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseErrorDetailed`] for the first problem found in
+    /// the string.
     #[inline]
-    pub const fn as_bytes(&self) -> &[u8; 5] {
-        &self.0
+    pub const fn parse_str_detailed(s: &str) -> Result<Self, EnidParseErrorDetailed> {
+        Self::parse_ascii_detailed(s.as_bytes(), Decoding::Strict, false)
     }
 
-    /// Returns the underlying bytes.
+    /// Returns whether a string is a valid ENID of this size, without
+    /// decoding it.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::enid40;
-    /// let enid = enid40!("m6sc7n75");
-    ///
-    /// assert_eq!(enid.into_bytes(), [0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    /// # use enid::{Enid40, Enid80};
+    /// assert!(Enid40::is_valid_str("m6sc7n75"));
+    /// assert!(!Enid40::is_valid_str("m6sc7nu5"));
+    /// assert!(!Enid80::is_valid_str("m6sc7n75"));
     /// ```
     #[must_use]
     #[inline]
-    pub const fn into_bytes(self) -> [u8; 5] {
-        self.0
+    pub const fn is_valid_str(s: &str) -> bool {
+        s.len() == Self::STR_LEN && Self::first_invalid_offset(s).is_none()
     }
 
-    /// Returns `true` if the ENID is filled with zeros.
+    /// Returns the offset of the first byte that prevents a string from
+    /// being a valid ENID of this size, or `None` if it is valid.
+    ///
+    /// If the string is a valid prefix of an ENID but is too short, this is
+    /// the length of the string; if it is too long, this is the length of an
+    /// ENID string.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::enid40;
-    /// assert_eq!(enid40!("00000000").is_zero(), true);
-    /// assert_eq!(enid40!("m6sc7n75").is_zero(), false);
+    /// # use enid::Enid80;
+    /// assert_eq!(Enid80::first_invalid_offset("y3gx5gxm-mpb8ey39"), None);
+    /// assert_eq!(Enid80::first_invalid_offset("y3gx5gxm-mpu8ey39"), Some(11));
+    /// assert_eq!(Enid80::first_invalid_offset("y3gx5gxmmpb8ey39"), Some(8));
+    /// assert_eq!(Enid80::first_invalid_offset("y3gx5gxm"), Some(8));
     /// ```
     #[must_use]
-    #[inline]
-    pub const fn is_zero(&self) -> bool {
-        matches!(self.0, [0, 0, 0, 0, 0])
-    }
-
-    // TODO: Use `ascii::Char` once it's stable.
-    // https://github.com/rust-lang/rust/issues/110998
-    pub(crate) const fn write_to_buffer<'a>(&self, buf: &'a mut [u8; 8]) -> &'a str {
-        *buf = base32::encode(self.0);
-
-        // SAFETY: Only ASCII characters are written to the buffer.
-        unsafe { str::from_utf8_unchecked(buf) }
-    }
-}
-
-impl Debug for Enid40 {
-    #[inline]
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
-    }
-}
-
-impl Display for Enid40 {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.pad(self.write_to_buffer(&mut [0; 8]))
-    }
-}
+    pub const fn first_invalid_offset(s: &str) -> Option<usize> {
+        let s = s.as_bytes();
+        let len = if s.len() < Self::STR_LEN {
+            s.len()
+        } else {
+            Self::STR_LEN
+        };
 
-impl FromStr for Enid40 {
-    type Err = EnidParseError;
+        let mut i = 0;
+        while i < len {
+            let valid = if i % 9 == 8 {
+                s[i] == base32::SEPARATOR
+            } else {
+                base32::is_valid(s[i], Decoding::Strict)
+            };
 
-    #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse_str(s)
-    }
-}
+            if !valid {
+                return Some(i);
+            }
 
-impl From<[u8; 5]> for Enid40 {
-    #[inline]
-    fn from(bytes: [u8; 5]) -> Self {
-        Self::from_bytes(bytes)
-    }
-}
+            i += 1;
+        }
 
-impl From<Enid40> for [u8; 5] {
-    #[inline]
-    fn from(enid: Enid40) -> Self {
-        enid.into_bytes()
+        if s.len() == Self::STR_LEN {
+            None
+        } else {
+            Some(len)
+        }
     }
-}
-
-/// An 80-bit ENID.
-///
-/// # Examples
-///
-/// ```
-/// # use enid::Enid80;
-/// # fn main() -> Result<(), enid::EnidParseError> {
-/// let enid = Enid80::parse_str("y3gx5gxm-mpb8ey39")?;
-///
-/// assert_eq!(enid.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
-/// # Ok(())
-/// # }
-/// ```
-#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
-#[repr(transparent)]
-pub struct Enid80([u8; 10]);
 
-impl Enid80 {
-    /// An ENID filled with zeros (`"00000000-00000000"`).
+    /// Attempts to create an ENID from a string that may have been
+    /// transcribed by a person.
+    ///
+    /// Like Crockford's Base32, uppercase characters are accepted, and
+    /// characters that are easily confused are mapped to the character they
+    /// were probably meant to be: `o` to `0`, `i` and `l` to `1`, and `u` to
+    /// `v`. Because ENIDs never contain these characters, this does not change
+    /// the meaning of any valid ENID.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::Enid80;
-    /// assert_eq!(Enid80::ZERO, Enid80::from_bytes([0; 10]));
+    /// # use enid::{enid40, enid80, Enid40, Enid80};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid40::parse_str_lenient("M6SC7N75")?, enid40!("m6sc7n75"));
+    /// assert_eq!(Enid80::parse_str_lenient("OOOOOOOI-LUUUUUUU")?, enid80!("00000001-1vvvvvvv"));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub const ZERO: Self = Self([0; 10]);
-
-    /// Creates an ENID from the given bytes.
-    ///
-    /// # Examples
     ///
-    /// ```
-    /// # use enid::Enid80;
-    /// let bytes = [0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69];
-    /// let enid = Enid80::from_bytes(bytes);
+    /// # Errors
     ///
-    /// assert_eq!(enid.to_string(), "y3gx5gxm-mpb8ey39");
-    /// ```
-    #[must_use]
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID after
+    /// the mapping.
     #[inline]
-    pub const fn from_bytes(bytes: [u8; 10]) -> Self {
-        Self(bytes)
+    pub const fn parse_str_lenient(s: &str) -> Result<Self, EnidParseError> {
+        Self::parse_ascii(s.as_bytes(), Decoding::Lenient, false)
     }
 
-    /// Attempts to create an ENID from the given string.
+    /// Attempts to create an ENID from its compact string form, which has no
+    /// hyphens between groups.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::Enid80;
+    /// # use enid::{enid80, Enid80};
     /// # fn main() -> Result<(), enid::EnidParseError> {
-    /// let enid = Enid80::parse_str("y3gx5gxm-mpb8ey39")?;
-    ///
-    /// assert_eq!(enid.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+    /// assert_eq!(Enid80::parse_str_compact("y3gx5gxmmpb8ey39")?, enid80!("y3gx5gxm-mpb8ey39"));
+    /// assert!(Enid80::parse_str_compact("y3gx5gxm-mpb8ey39").is_err());
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns an [`EnidParseError`] if the string is not a valid ENID.
+    /// Returns an [`EnidParseError`] if the string is not a valid compact
+    /// ENID.
     #[inline]
-    pub const fn parse_str(s: &str) -> Result<Self, EnidParseError> {
-        Self::parse_str_ascii(s.as_bytes())
+    pub const fn parse_str_compact(s: &str) -> Result<Self, EnidParseError> {
+        Self::parse_ascii(s.as_bytes(), Decoding::Strict, true)
     }
 
-    /// Attempts to create an ENID from a string of ASCII characters.
+    /// Attempts to create an ENID from a string written by a Crockford's
+    /// Base32 encoder.
+    ///
+    /// ENIDs use the same alphabet as Crockford's Base32, but only accept its
+    /// canonical lowercase characters. This accepts either case, reads `o` as
+    /// `0` and `i` and `l` as `1`, and ignores hyphens wherever they appear, as
+    /// Crockford's Base32 specifies. Check symbols are not supported.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::Enid80;
+    /// # use enid::{enid80, Enid80};
     /// # fn main() -> Result<(), enid::EnidParseError> {
-    /// let enid = Enid80::parse_str_ascii(b"y3gx5gxm-mpb8ey39")?;
+    /// let enid = Enid80::parse_str_crockford("Y3GX-5GXM-MPB8-EY39")?;
     ///
-    /// assert_eq!(enid.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+    /// assert_eq!(enid, enid80!("y3gx5gxm-mpb8ey39"));
+    /// assert_eq!(Enid80::parse_str_crockford("OOOOOOOI-LOOOOOOO")?, enid80!("00000001-10000000"));
     /// # Ok(())
     /// # }
     /// ```
     ///
     /// # Errors
     ///
-    /// Returns an [`EnidParseError`] if the string is not a valid ENID.
-    pub const fn parse_str_ascii(s: &[u8]) -> Result<Self, EnidParseError> {
-        if s.len() != 17 {
-            return Err(EnidParseError);
-        }
-
-        let mut bytes = [0; 10];
-
-        match base32::decode(*s.first_chunk().unwrap()) {
-            Ok(chunk) => *bytes.first_chunk_mut().unwrap() = chunk,
-            Err(e) => return Err(e),
-        };
-
-        if s[8] != b'-' {
-            return Err(EnidParseError);
-        };
+    /// Returns an [`EnidParseError`] if the string does not contain exactly
+    /// the right number of valid characters for the size of ENID.
+    pub const fn parse_str_crockford(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.as_bytes();
+        let mut bytes = [0; N];
+        let mut chars = [0; 8];
+        let mut len = 0;
+        let mut i = 0;
+
+        while i < s.len() {
+            if s[i] != base32::SEPARATOR {
+                if len == Self::GROUPS * 8 {
+                    return Err(EnidParseError);
+                }
+
+                chars[len % 8] = s[i];
+                len += 1;
+
+                if len % 8 == 0 {
+                    let group = len / 8 - 1;
+
+                    match base32::decode(chars, Decoding::Crockford) {
+                        Ok(chunk) => {
+                            let mut j = 0;
+                            while j < 5 {
+                                bytes[group * 5 + j] = chunk[j];
+                                j += 1;
+                            }
+                        }
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
 
-        match base32::decode(*s.last_chunk().unwrap()) {
-            Ok(chunk) => *bytes.last_chunk_mut().unwrap() = chunk,
-            Err(e) => return Err(e),
-        };
+            i += 1;
+        }
 
-        Ok(Self(bytes))
+        if len == Self::GROUPS * 8 {
+            Ok(Self::from_bytes(bytes))
+        } else {
+            Err(EnidParseError)
+        }
     }
 
-    /// Returns a reference to the underlying bytes.
+    /// Attempts to create an ENID from a URN, such as `"urn:enid:m6sc7n75"`.
+    ///
+    /// The `urn:enid:` prefix is case-insensitive, as RFC 8141 specifies, but
+    /// the ENID itself must be in its usual form.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::enid80;
-    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
-    ///
-    /// assert_eq!(enid.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid40::parse_urn("urn:enid:m6sc7n75")?, enid40!("m6sc7n75"));
+    /// assert_eq!(Enid40::parse_urn("URN:ENID:m6sc7n75")?, enid40!("m6sc7n75"));
+    /// assert!(Enid40::parse_urn("m6sc7n75").is_err());
+    /// # Ok(())
+    /// # }
     /// ```
-    #[must_use]
-    #[inline]
-    pub const fn as_bytes(&self) -> &[u8; 10] {
-        &self.0
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string does not start with
+    /// `urn:enid:`, or the rest is not a valid ENID.
+    pub const fn parse_urn(s: &str) -> Result<Self, EnidParseError> {
+        match format::strip_urn_prefix(s.as_bytes()) {
+            Some(s) => Self::parse_str_ascii(s),
+            None => Err(EnidParseError),
+        }
     }
 
-    /// Returns the underlying bytes.
+    /// Attempts to create an ENID from its uppercase string form, such as
+    /// `"Y3GX5GXM-MPB8EY39"`.
+    ///
+    /// Lowercase characters are rejected. Use
+    /// [`parse_str_lenient`](Self::parse_str_lenient) to accept either case.
     ///
     /// # Examples
     ///
     /// ```
-    /// # use enid::enid80;
-    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
-    ///
-    /// assert_eq!(enid.into_bytes(), [0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+    /// # use enid::{enid80, Enid80};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid80::parse_str_uppercase("Y3GX5GXM-MPB8EY39")?, enid80!("y3gx5gxm-mpb8ey39"));
+    /// assert!(Enid80::parse_str_uppercase("y3gx5gxm-mpb8ey39").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid uppercase
+    /// ENID.
+    #[inline]
+    pub const fn parse_str_uppercase(s: &str) -> Result<Self, EnidParseError> {
+        Self::parse_ascii(s.as_bytes(), Decoding::Uppercase, false)
+    }
+
+    /// Attempts to create an ENID from its bytes written as hexadecimal
+    /// digits of either case, as produced by the [`LowerHex`] and [`UpperHex`]
+    /// implementations.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(Enid40::from_hex_str("a1b2c3d4e5")?, enid);
+    /// assert_eq!(Enid40::from_hex_str("A1B2C3D4E5")?, enid);
+    /// assert!(Enid40::from_hex_str("a1b2c3d4").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not exactly `2 * N`
+    /// hexadecimal digits.
+    pub const fn from_hex_str(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.as_bytes();
+
+        if s.len() != N * 2 {
+            return Err(EnidParseError);
+        }
+
+        let mut bytes = [0; N];
+        let mut i = 0;
+
+        while i < N {
+            match (ascii::hex_value(s[i * 2]), ascii::hex_value(s[i * 2 + 1])) {
+                (Some(hi), Some(lo)) => bytes[i] = hi << 4 | lo,
+                _ => return Err(EnidParseError),
+            }
+
+            i += 1;
+        }
+
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Parses the groups of an ENID, which are separated by hyphens unless
+    /// `compact` is set.
+    const fn parse_ascii(
+        s: &[u8],
+        decoding: Decoding,
+        compact: bool,
+    ) -> Result<Self, EnidParseError> {
+        match Self::parse_ascii_detailed(s, decoding, compact) {
+            Ok(enid) => Ok(enid),
+            Err(_) => Err(EnidParseError),
+        }
+    }
+
+    const fn parse_ascii_detailed(
+        s: &[u8],
+        decoding: Decoding,
+        compact: bool,
+    ) -> Result<Self, EnidParseErrorDetailed> {
+        let (len, stride) = if compact {
+            (Self::GROUPS * 8, 8)
+        } else {
+            (Self::STR_LEN, 9)
+        };
+
+        if s.len() != len {
+            return Err(EnidParseErrorDetailed::InvalidLength {
+                expected: len,
+                found: s.len(),
+            });
+        }
+
+        let mut bytes = [0; N];
+        let mut group = 0;
+
+        while group < Self::GROUPS {
+            if !compact && group > 0 && s[group * 9 - 1] != base32::SEPARATOR {
+                return Err(EnidParseErrorDetailed::MissingSeparator {
+                    position: group * 9 - 1,
+                });
+            }
+
+            let (_, chars) = s.split_at(group * stride);
+
+            let chars = *chars.first_chunk().unwrap();
+            match base32::decode(chars, decoding) {
+                Ok(chunk) => {
+                    let mut i = 0;
+                    while i < 5 {
+                        bytes[group * 5 + i] = chunk[i];
+                        i += 1;
+                    }
+                }
+                Err(_) => {
+                    let mut i = 0;
+                    while base32::is_valid(chars[i], decoding) {
+                        i += 1;
+                    }
+
+                    return Err(EnidParseErrorDetailed::InvalidCharacter {
+                        position: group * stride + i,
+                        byte: chars[i],
+                    });
+                }
+            }
+
+            group += 1;
+        }
+
+        Ok(Self(bytes))
+    }
+
+    /// Returns a reference to the underlying bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        &self.0
+    }
+
+    /// Returns the underlying bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.into_bytes(), [0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
     /// ```
     #[must_use]
     #[inline]
-    pub const fn into_bytes(self) -> [u8; 10] {
+    pub const fn into_bytes(self) -> [u8; N] {
         self.0
     }
 
@@ -352,68 +774,1263 @@ impl Enid80 {
     /// # Examples
     ///
     /// ```
-    /// # use enid::enid80;
-    /// assert_eq!(enid80!("00000000-00000000").is_zero(), true);
-    /// assert_eq!(enid80!("y3gx5gxm-mpb8ey39").is_zero(), false);
+    /// # use enid::{enid40, enid80};
+    /// assert_eq!(enid40!("00000000").is_zero(), true);
+    /// assert_eq!(enid40!("m6sc7n75").is_zero(), false);
+    /// assert_eq!(enid80!("00000000-00000000").is_zero(), true);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        let mut i = 0;
+        while i < N {
+            if self.0[i] != 0 {
+                return false;
+            }
+            i += 1;
+        }
+
+        true
+    }
+
+    /// Returns `true` if this is the [nil](Self::NIL) ENID.
+    ///
+    /// This is the same as [`is_zero`](Self::is_zero).
+    #[must_use]
+    #[inline]
+    pub const fn is_nil(&self) -> bool {
+        self.is_zero()
+    }
+
+    /// Returns the next ENID, treating the bytes as a big-endian integer, or
+    /// `None` if this is the [largest](Self::MAX) ENID.
+    ///
+    /// This is useful for range scans that exclude a starting ID, such as
+    /// "every ID after this one".
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80, Enid80};
+    /// assert_eq!(enid40!("0000000z").successor(), Some(enid40!("00000010")));
+    /// assert_eq!(enid80!("0000000z-zzzzzzzz").successor(), Some(enid80!("00000010-00000000")));
+    /// assert_eq!(Enid80::MAX.successor(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn successor(self) -> Option<Self> {
+        let mut bytes = self.0;
+        let mut i = N;
+
+        while i > 0 {
+            i -= 1;
+
+            if bytes[i] == 0xff {
+                bytes[i] = 0;
+            } else {
+                bytes[i] += 1;
+                return Some(Self(bytes));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the previous ENID, treating the bytes as a big-endian integer,
+    /// or `None` if this is the [nil](Self::NIL) ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(enid40!("00000010").predecessor(), Some(enid40!("0000000z")));
+    /// assert_eq!(Enid40::NIL.predecessor(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn predecessor(self) -> Option<Self> {
+        let mut bytes = self.0;
+        let mut i = N;
+
+        while i > 0 {
+            i -= 1;
+
+            if bytes[i] == 0 {
+                bytes[i] = 0xff;
+            } else {
+                bytes[i] -= 1;
+                return Some(Self(bytes));
+            }
+        }
+
+        None
+    }
+
+    /// Returns the XOR of two ENIDs, which is the distance between them in the
+    /// metric used by Kademlia and similar distributed hash tables.
+    ///
+    /// Distances can be compared directly, so peers can be sorted by their
+    /// distance to a target with `sort_by_key`, or with
+    /// [`cmp_distance`](Self::cmp_distance).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let a = enid40!("m6sc7n75");
+    /// let b = enid40!("m6sc7n74");
+    ///
+    /// assert_eq!(a.xor_distance(&b), enid40!("00000001"));
+    /// assert_eq!(a.xor_distance(&a), Enid40::NIL);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn xor_distance(&self, other: &Self) -> Self {
+        Self(bits::xor(&self.0, &other.0))
+    }
+
+    /// Returns the index of the most significant bit that differs between two
+    /// ENIDs, counting from the least significant bit, or `None` if they are
+    /// equal.
+    ///
+    /// This is the index of the Kademlia routing table bucket that `other`
+    /// belongs to, relative to this ENID: bucket `i` holds the ENIDs at an
+    /// [XOR distance](Self::xor_distance) from `2^i` up to `2^(i + 1)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let node = enid40!("00000000");
+    ///
+    /// assert_eq!(node.bucket_index(&enid40!("00000001")), Some(0));
+    /// assert_eq!(node.bucket_index(&enid40!("00000010")), Some(5));
+    /// assert_eq!(node.bucket_index(&enid40!("z0000000")), Some(39));
+    /// assert_eq!(node.bucket_index(&node), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn bucket_index(&self, other: &Self) -> Option<u32> {
+        let zeros = bits::leading_zeros(&bits::xor(&self.0, &other.0));
+
+        if zeros == N as u32 * 8 {
+            None
+        } else {
+            Some(N as u32 * 8 - 1 - zeros)
+        }
+    }
+
+    /// Compares the [XOR distances](Self::xor_distance) of two ENIDs from this
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let target = enid40!("m6sc7n75");
+    /// let mut peers = [enid40!("00000000"), enid40!("m6sc7n74"), enid40!("m6sc0000")];
+    ///
+    /// peers.sort_by(|a, b| target.cmp_distance(a, b));
+    ///
+    /// assert_eq!(peers, [enid40!("m6sc7n74"), enid40!("m6sc0000"), enid40!("00000000")]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn cmp_distance(&self, a: &Self, b: &Self) -> Ordering {
+        self.xor_distance(a).cmp(&self.xor_distance(b))
+    }
+
+    /// Returns the number of leading bits that two ENIDs have in common.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let a = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(a.common_prefix_len(&enid40!("m6sc7n74")), 39);
+    /// assert_eq!(a.common_prefix_len(&enid40!("m6sc0000")), 22);
+    /// assert_eq!(a.common_prefix_len(&a), 40);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn common_prefix_len(&self, other: &Self) -> u32 {
+        bits::leading_zeros(&bits::xor(&self.0, &other.0))
+    }
+
+    /// Returns the number of bits that differ between two ENIDs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let a = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(a.hamming_distance(&enid40!("m6sc7n74")), 1);
+    /// assert_eq!(a.hamming_distance(&enid40!("m6sc7n76")), 2);
+    /// assert_eq!(a.hamming_distance(&a), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn hamming_distance(&self, other: &Self) -> u32 {
+        bits::count_ones(&bits::xor(&self.0, &other.0))
+    }
+
+    /// Returns the ENID with its bytes in reverse order.
+    ///
+    /// Sequentially-allocated IDs differ mostly in their trailing bytes, so
+    /// storing them in a range-partitioned database sends all writes to the same
+    /// partition. Reversing the bytes moves the fastest-changing byte to the
+    /// front. The original ENID can be recovered with
+    /// [`from_reversed`](Self::from_reversed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80, Enid40};
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.to_reversed().as_bytes(), &[0xe5, 0xd4, 0xc3, 0xb2, 0xa1]);
+    /// assert_eq!(Enid40::from_reversed(enid.to_reversed()), enid);
+    ///
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.to_reversed().as_bytes(), &[0x69, 0x78, 0x87, 0x96, 0xa5, 0xb4, 0xc3, 0xd2, 0xe1, 0xf0]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_reversed(self) -> Self {
+        Self(bits::reverse(self.0))
+    }
+
+    /// Reverses the transform applied by [`to_reversed`](Self::to_reversed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(Enid80::from_reversed(enid.to_reversed()), enid);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_reversed(enid: Self) -> Self {
+        Self(bits::reverse(enid.0))
+    }
+
+    /// Returns the ENID with the bits of its upper and lower halves interleaved.
+    ///
+    /// Bit `2i` of the result (counting from the most significant bit) is bit `i`
+    /// of the upper half, and bit `2i + 1` is bit `i` of the lower half.
+    ///
+    /// For IDs with a time-ordered prefix and a random suffix, this spreads the
+    /// random bits across the whole key, which avoids write hotspots in
+    /// range-partitioned databases. The original ENID can be recovered with
+    /// [`from_interleaved`](Self::from_interleaved).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(Enid40::from_interleaved(enid.to_interleaved()), enid);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_interleaved(self) -> Self {
+        Self(bits::interleave(self.0))
+    }
+
+    /// Reverses the transform applied by [`to_interleaved`](Self::to_interleaved).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(Enid80::from_interleaved(enid.to_interleaved()), enid);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_interleaved(enid: Self) -> Self {
+        Self(bits::deinterleave(enid.0))
+    }
+
+    /// Returns a stable 64-bit hash of the ENID.
+    ///
+    /// The hash is the 64-bit FNV-1a hash of the underlying bytes (offset basis
+    /// `0xcbf29ce484222325`, prime `0x100000001b3`). Unlike the [`Hash`]
+    /// implementation, this algorithm will never change, so it can be used to
+    /// assign IDs to buckets consistently across processes and languages.
+    ///
+    /// [`Hash`]: core::hash::Hash
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80};
+    /// assert_eq!(enid40!("m6sc7n75").portable_hash(), 0xa54a92685a230468);
+    /// assert_eq!(enid80!("y3gx5gxm-mpb8ey39").portable_hash(), 0xaf3627f285011102);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn portable_hash(&self) -> u64 {
+        hash::fnv1a_64(&self.0)
+    }
+
+    /// Maps the ENID to one of `shards` shards, numbered from zero.
+    ///
+    /// This uses jump consistent hashing of the
+    /// [`portable_hash`](Self::portable_hash), so the mapping is stable across
+    /// processes, and when the number of shards grows only the IDs that move to
+    /// the new shards are reassigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80};
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.shard(10), 6);
+    /// assert_eq!(enid.shard(100), 64);
+    /// assert_eq!(enid80!("y3gx5gxm-mpb8ey39").shard(10), 7);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub const fn shard(&self, shards: u32) -> u32 {
+        assert!(shards > 0, "number of shards must be non-zero");
+
+        hash::jump_consistent_hash(self.portable_hash(), shards)
+    }
+
+    /// Returns an adapter that formats the ENID without hyphens between
+    /// groups, for systems that do not accept them, such as DNS labels.
+    ///
+    /// The compact form can be parsed with
+    /// [`parse_str_compact`](Self::parse_str_compact).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.compact().to_string(), "y3gx5gxmmpb8ey39");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn compact(&self) -> Compact<N> {
+        Compact(*self)
+    }
+
+    /// Returns an adapter that formats the ENID as a URN, such as
+    /// `"urn:enid:m6sc7n75"`, for systems that require URN syntax.
+    ///
+    /// The URN can be parsed with [`parse_urn`](Self::parse_urn).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.urn().to_string(), "urn:enid:y3gx5gxm-mpb8ey39");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn urn(&self) -> Urn<Self> {
+        Urn(*self)
+    }
+
+    /// Returns an adapter that formats the ENID in uppercase, such as
+    /// `"Y3GX5GXM-MPB8EY39"`, for systems that only accept uppercase
+    /// identifiers.
+    ///
+    /// The adapter can also be parsed from a string, which accepts the same
+    /// strings as [`parse_str_uppercase`](Self::parse_str_uppercase).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// # use enid::format::Uppercase;
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.uppercase().to_string(), "Y3GX5GXM-MPB8EY39");
+    /// assert_eq!("Y3GX5GXM-MPB8EY39".parse::<Uppercase<10>>()?.get(), enid);
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn uppercase(&self) -> Uppercase<N> {
+        Uppercase(*self)
+    }
+
+    /// Writes the string form of the ENID to the start of a buffer, and
+    /// returns it as a string.
+    ///
+    /// This formats the ENID without allocating or using [`Display`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    /// let mut buf = [0; 32];
+    ///
+    /// assert_eq!(enid.encode_to_slice(&mut buf), Ok("y3gx5gxm-mpb8ey39"));
+    /// assert!(enid.encode_to_slice(&mut buf[..16]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferTooSmallError`] if the buffer is shorter than
+    /// [`STR_LEN`](Self::STR_LEN).
+    #[inline]
+    pub const fn encode_to_slice<'a>(
+        &self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, BufferTooSmallError> {
+        if buf.len() < Self::STR_LEN {
+            return Err(BufferTooSmallError::new(Self::STR_LEN, buf.len()));
+        }
+
+        Ok(self.write_to_buffer(buf))
+    }
+
+    /// Writes the string form of the ENID to a [`fmt::Write`], such as a
+    /// fixed-capacity string.
+    ///
+    /// Unlike formatting with [`Display`], this ignores padding and
+    /// alignment, and writes up to four groups with each call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let mut s = String::from("id=");
+    /// enid40!("m6sc7n75").format_into(&mut s)?;
+    ///
+    /// assert_eq!(s, "id=m6sc7n75");
+    /// # Ok::<(), std::fmt::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer returns an error.
+    #[inline]
+    pub fn format_into<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        self.write_prefix(w, Self::STR_LEN)
+    }
+
+    /// Writes the first `len` characters of the string form of the ENID.
+    ///
+    /// Up to four groups are encoded into a buffer and written with one call,
+    /// so ENIDs of up to 160 bits take a single call. With the `safe` feature,
+    /// this also means each ENID is validated as UTF-8 only once.
+    #[inline]
+    fn write_prefix<W: Write + ?Sized>(&self, w: &mut W, mut len: usize) -> fmt::Result {
+        if Self::GROUPS == 1 {
+            let chars = base32::encode(*self.0.first_chunk().unwrap());
+            return w.write_str(ascii::to_str(&chars[..len.min(8)]));
+        }
+
+        // Each group is preceded by a separator, which is skipped for the
+        // first group.
+        let mut buf = [base32::SEPARATOR; 4 * 9];
+
+        for (i, chunk) in self.0.chunks(4 * 5).enumerate() {
+            if len == 0 {
+                break;
+            }
+
+            for (chars, bytes) in buf[1..].chunks_mut(9).zip(chunk.chunks_exact(5)) {
+                chars[..8].copy_from_slice(&base32::encode(*bytes.first_chunk().unwrap()));
+            }
+
+            let start = usize::from(i == 0);
+            let end = (chunk.len() / 5 * 9).min(start + len);
+            w.write_str(ascii::to_str(&buf[start..end]))?;
+            len -= end - start;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the string form of the ENID to the start of the buffer, which
+    /// must be at least [`STR_LEN`](Self::STR_LEN) bytes long.
+    pub(crate) const fn write_to_buffer<'a>(&self, buf: &'a mut [u8]) -> &'a str {
+        ascii::to_str(self.write_ascii(buf))
+    }
+
+    /// Like `write_to_buffer`, but returns the written bytes, for callers that
+    /// do not need a string.
+    pub(crate) const fn write_ascii<'a>(&self, buf: &'a mut [u8]) -> &'a [u8] {
+        let (buf, _) = buf.split_at_mut(Self::STR_LEN);
+        let mut group = 0;
+
+        while group < Self::GROUPS {
+            let (_, bytes) = self.0.split_at(group * 5);
+            let chars = base32::encode(*bytes.first_chunk().unwrap());
+
+            let mut i = 0;
+            while i < 8 {
+                buf[group * 9 + i] = chars[i];
+                i += 1;
+            }
+
+            if group > 0 {
+                buf[group * 9 - 1] = base32::SEPARATOR;
+            }
+
+            group += 1;
+        }
+
+        buf
+    }
+}
+
+impl<const N: usize> Default for EnidN<N> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const N: usize> Debug for EnidN<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for EnidN<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        // The string can be any length, so it is written up to four groups at a
+        // time rather than through `Formatter::pad`, which needs the whole
+        // string.
+        let len = f
+            .precision()
+            .map_or(Self::STR_LEN, |p| p.min(Self::STR_LEN));
+        let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+        let fill = f.fill();
+
+        for _ in 0..before {
+            f.write_char(fill)?;
+        }
+
+        self.write_prefix(f, len)?;
+
+        for _ in 0..after {
+            f.write_char(fill)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats the bytes of the ENID as lowercase hexadecimal digits.
+///
+/// The alternate flag (`{:#x}`) adds a `0x` prefix.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid40;
+/// let enid = enid40!("m6sc7n75");
+///
+/// assert_eq!(format!("{enid:x}"), "a1b2c3d4e5");
+/// assert_eq!(format!("{enid:#x}"), "0xa1b2c3d4e5");
+/// ```
+impl<const N: usize> LowerHex for EnidN<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_hex(f, &self.0, b"0123456789abcdef")
+    }
+}
+
+/// Formats the bytes of the ENID as uppercase hexadecimal digits.
+///
+/// The alternate flag (`{:#X}`) adds a `0x` prefix.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid40;
+/// let enid = enid40!("m6sc7n75");
+///
+/// assert_eq!(format!("{enid:X}"), "A1B2C3D4E5");
+/// ```
+impl<const N: usize> UpperHex for EnidN<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        fmt_hex(f, &self.0, b"0123456789ABCDEF")
+    }
+}
+
+fn fmt_hex(f: &mut Formatter, bytes: &[u8], digits: &[u8; 16]) -> fmt::Result {
+    let prefix = if f.alternate() { "0x" } else { "" };
+    let chars =
+        prefix.chars().chain(bytes.iter().flat_map(|&b| {
+            [digits[(b >> 4) as usize], digits[(b & 0xf) as usize]].map(char::from)
+        }));
+
+    format::write_padded(f, prefix.len() + bytes.len() * 2, chars)
+}
+
+impl<const N: usize> FromStr for EnidN<N> {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+impl<const N: usize> From<[u8; N]> for EnidN<N> {
+    #[inline]
+    fn from(bytes: [u8; N]) -> Self {
+        Self::from_bytes(bytes)
+    }
+}
+
+impl<const N: usize> From<EnidN<N>> for [u8; N] {
+    #[inline]
+    fn from(enid: EnidN<N>) -> Self {
+        enid.into_bytes()
+    }
+}
+
+impl<const N: usize> TryFrom<&[u8]> for EnidN<N> {
+    type Error = EnidLengthError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes.try_into() {
+            Ok(bytes) => Ok(Self::from_bytes(bytes)),
+            Err(_) => Err(EnidLengthError {
+                len: bytes.len(),
+                sizes: const { &[N] },
+            }),
+        }
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for EnidN<N> {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const N: usize> Borrow<[u8]> for EnidN<N> {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+/// Compares an ENID with a string by encoding it one character at a time,
+/// without writing it to a buffer.
+fn eq_str<const N: usize>(enid: &EnidN<N>, s: &str) -> bool {
+    s.len() == EnidN::<N>::STR_LEN && format::chars(enid).eq(s.bytes())
+}
+
+impl<const N: usize> PartialEq<str> for EnidN<N> {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        eq_str(self, other)
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for EnidN<N> {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        eq_str(self, other)
+    }
+}
+
+impl<const N: usize> PartialEq<EnidN<N>> for str {
+    #[inline]
+    fn eq(&self, other: &EnidN<N>) -> bool {
+        eq_str(other, self)
+    }
+}
+
+impl<const N: usize> PartialEq<EnidN<N>> for &str {
+    #[inline]
+    fn eq(&self, other: &EnidN<N>) -> bool {
+        eq_str(other, self)
+    }
+}
+
+/// A 40-bit ENID.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid40;
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let enid = Enid40::parse_str("m6sc7n75")?;
+///
+/// assert_eq!(enid.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+/// # Ok(())
+/// # }
+/// ```
+pub type Enid40 = EnidN<5>;
+
+impl Enid40 {
+    /// Attempts to create an ENID from its grouped string form, which has a
+    /// hyphen after the fourth character, such as `"m6sc-7n75"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid40::parse_str_grouped("m6sc-7n75")?, enid40!("m6sc7n75"));
+    /// assert!(Enid40::parse_str_grouped("m6sc7n75").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid grouped
+    /// ENID.
+    pub const fn parse_str_grouped(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.as_bytes();
+
+        if s.len() != 9 || s[4] != base32::SEPARATOR {
+            return Err(EnidParseError);
+        }
+
+        let (hi, lo) = s.split_at(4);
+        let (_, lo) = lo.split_at(1);
+        let mut buf = [0; 8];
+        let mut i = 0;
+
+        while i < 4 {
+            buf[i] = hi[i];
+            buf[i + 4] = lo[i];
+            i += 1;
+        }
+
+        Self::parse_str_ascii(&buf)
+    }
+
+    /// Returns an adapter that formats the ENID with a hyphen after the fourth
+    /// character, such as `"m6sc-7n75"`, which is easier to read aloud.
+    ///
+    /// The grouped form can be parsed with
+    /// [`parse_str_grouped`](Self::parse_str_grouped).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.grouped().to_string(), "m6sc-7n75");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn grouped(&self) -> Grouped {
+        Grouped(*self)
+    }
+
+    /// Creates an ENID from an integer, treating the bytes as a big-endian
+    /// integer.
+    ///
+    /// Returns `None` if the value does not fit in 40 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(Enid40::from_u64(0xa1_b2c3_d4e5), Some(enid40!("m6sc7n75")));
+    /// assert_eq!(Enid40::from_u64(1 << 40), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_u64(value: u64) -> Option<Self> {
+        match value.to_be_bytes() {
+            [0, 0, 0, bytes @ ..] => Some(Self(bytes)),
+            _ => None,
+        }
+    }
+
+    /// Returns the ENID as an integer, treating the bytes as a big-endian
+    /// integer.
+    ///
+    /// The result is always less than `2^40`, and integers compare in the same
+    /// order as the ENIDs they came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// assert_eq!(enid40!("m6sc7n75").to_u64(), 0xa1_b2c3_d4e5);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_u64(self) -> u64 {
+        let b = self.0;
+
+        u64::from_be_bytes([0, 0, 0, b[0], b[1], b[2], b[3], b[4]])
+    }
+
+    /// Creates an ENID from a signed integer, such as a value read from a
+    /// `BIGINT` database column.
+    ///
+    /// This is the inverse of [`to_i64`](Self::to_i64). Returns `None` if the
+    /// value is negative or does not fit in 40 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(Enid40::from_i64(0xa1_b2c3_d4e5), Some(enid40!("m6sc7n75")));
+    /// assert_eq!(Enid40::from_i64(-1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_i64(value: i64) -> Option<Self> {
+        if value < 0 {
+            None
+        } else {
+            Self::from_u64(value as u64)
+        }
+    }
+
+    /// Returns the ENID as a signed integer, for storage in databases that
+    /// only support signed 64-bit integers, such as PostgreSQL's `BIGINT`.
+    ///
+    /// The result is always between `0` and `2^40 - 1`, so it never has the
+    /// sign bit set, and integers compare in the same order as the ENIDs they
+    /// came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let a = enid40!("m6sc7n75");
+    /// let b = enid40!("zzzzzzzz");
+    ///
+    /// assert_eq!(a.to_i64(), 0xa1_b2c3_d4e5);
+    /// assert_eq!(b.to_i64(), (1 << 40) - 1);
+    /// assert!(a.to_i64() < b.to_i64());
+    /// assert_eq!(Enid40::from_i64(a.to_i64()), Some(a));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_i64(self) -> i64 {
+        self.to_u64() as i64
+    }
+
+    /// Adds an integer to the ENID, treating the bytes as a big-endian
+    /// integer, or returns `None` if the result does not fit in 40 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(enid40!("00000000").checked_add_u64(32), Some(enid40!("00000010")));
+    /// assert_eq!(Enid40::MAX.checked_add_u64(1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn checked_add_u64(self, n: u64) -> Option<Self> {
+        match self.to_u64().checked_add(n) {
+            Some(value) => Self::from_u64(value),
+            None => None,
+        }
+    }
+
+    /// Adds an integer to the ENID, treating the bytes as a big-endian
+    /// integer, and wrapping around at `2^40`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(Enid40::MAX.wrapping_add_u64(33), enid40!("00000010"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn wrapping_add_u64(self, n: u64) -> Self {
+        let [_, _, _, bytes @ ..] = self.to_u64().wrapping_add(n).to_be_bytes();
+
+        Self(bytes)
+    }
+
+    /// Subtracts an integer from the ENID, treating the bytes as a big-endian
+    /// integer, or returns `None` if the result would be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// assert_eq!(enid40!("00000010").checked_sub_u64(1), Some(enid40!("0000000z")));
+    /// assert_eq!(Enid40::NIL.checked_sub_u64(1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn checked_sub_u64(self, n: u64) -> Option<Self> {
+        match self.to_u64().checked_sub(n) {
+            Some(value) => Self::from_u64(value),
+            None => None,
+        }
+    }
+
+    /// Returns how far `other` is after this ENID, treating the bytes as
+    /// big-endian integers, or `None` if `other` is before this ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let start = enid40!("00000010");
+    /// let end = enid40!("00000100");
+    ///
+    /// assert_eq!(start.distance_to(end), Some(992));
+    /// assert_eq!(end.distance_to(start), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn distance_to(self, other: Self) -> Option<u64> {
+        other.to_u64().checked_sub(self.to_u64())
+    }
+}
+
+impl From<Enid40> for u64 {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        enid.to_u64()
+    }
+}
+
+/// An 80-bit ENID.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid80;
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let enid = Enid80::parse_str("y3gx5gxm-mpb8ey39")?;
+///
+/// assert_eq!(enid.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+/// # Ok(())
+/// # }
+/// ```
+pub type Enid80 = EnidN<10>;
+
+impl Enid80 {
+    /// Creates an ENID from two 40-bit halves.
+    ///
+    /// This is the inverse of [`split`](Self::split). The string form is the
+    /// strings of the two halves, separated by a hyphen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80, Enid80};
+    /// let enid = Enid80::join(enid40!("y3gx5gxm"), enid40!("mpb8ey39"));
+    ///
+    /// assert_eq!(enid, enid80!("y3gx5gxm-mpb8ey39"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn join(hi: Enid40, lo: Enid40) -> Self {
+        let hi = hi.into_bytes();
+        let lo = lo.into_bytes();
+
+        Self([
+            hi[0], hi[1], hi[2], hi[3], hi[4], lo[0], lo[1], lo[2], lo[3], lo[4],
+        ])
+    }
+
+    /// Splits the ENID into its upper and lower 40-bit halves.
+    ///
+    /// These are the two groups of the string form, so the upper half can be
+    /// used as a key for routing or partitioning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80};
+    /// let (hi, lo) = enid80!("y3gx5gxm-mpb8ey39").split();
+    ///
+    /// assert_eq!(hi, enid40!("y3gx5gxm"));
+    /// assert_eq!(lo, enid40!("mpb8ey39"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn split(self) -> (Enid40, Enid40) {
+        let (hi, lo) = self.0.split_first_chunk::<5>().unwrap();
+
+        (
+            Enid40::from_bytes(*hi),
+            Enid40::from_bytes(*lo.first_chunk().unwrap()),
+        )
+    }
+
+    /// Creates an ENID from the upper 16 bits and lower 64 bits.
+    ///
+    /// This is the inverse of [`as_words`](Self::as_words).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let enid = Enid80::from_words(0xf0e1, 0xd2c3_b4a5_9687_7869);
+    ///
+    /// assert_eq!(enid.to_string(), "y3gx5gxm-mpb8ey39");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_words(hi: u16, lo: u64) -> Self {
+        let hi = hi.to_be_bytes();
+        let lo = lo.to_be_bytes();
+
+        Self([
+            hi[0], hi[1], lo[0], lo[1], lo[2], lo[3], lo[4], lo[5], lo[6], lo[7],
+        ])
+    }
+
+    /// Returns the upper 16 bits and lower 64 bits of the ENID.
+    ///
+    /// The bytes are treated as a big-endian integer, so `hi` holds the first 2
+    /// bytes and `lo` holds the last 8 bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.as_words(), (0xf0e1, 0xd2c3_b4a5_9687_7869));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_words(&self) -> (u16, u64) {
+        let (hi, lo) = self.0.split_first_chunk::<2>().unwrap();
+        let lo: &[u8; 8] = lo.first_chunk().unwrap();
+
+        (u16::from_be_bytes(*hi), u64::from_be_bytes(*lo))
+    }
+
+    /// Creates an ENID from an integer, treating the bytes as a big-endian
+    /// integer.
+    ///
+    /// Returns `None` if the value does not fit in 80 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// assert_eq!(Enid80::from_u128(0xf0e1_d2c3_b4a5_9687_7869), Some(enid80!("y3gx5gxm-mpb8ey39")));
+    /// assert_eq!(Enid80::from_u128(1 << 80), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_u128(value: u128) -> Option<Self> {
+        match value.to_be_bytes() {
+            [0, 0, 0, 0, 0, 0, bytes @ ..] => Some(Self(bytes)),
+            _ => None,
+        }
+    }
+
+    /// Returns the ENID as an integer, treating the bytes as a big-endian
+    /// integer.
+    ///
+    /// The result is always less than `2^80`, and integers compare in the same
+    /// order as the ENIDs they came from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// assert_eq!(enid80!("y3gx5gxm-mpb8ey39").to_u128(), 0xf0e1_d2c3_b4a5_9687_7869);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_u128(self) -> u128 {
+        let b = self.0;
+
+        u128::from_be_bytes([
+            0, 0, 0, 0, 0, 0, b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9],
+        ])
+    }
+
+    /// Adds an integer to the ENID, treating the bytes as a big-endian
+    /// integer, or returns `None` if the result does not fit in 80 bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// assert_eq!(enid80!("00000000-zzzzzzzz").checked_add_u64(1), Some(enid80!("00000001-00000000")));
+    /// assert_eq!(Enid80::MAX.checked_add_u64(1), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn checked_add_u64(self, n: u64) -> Option<Self> {
+        match self.to_u128().checked_add(n as u128) {
+            Some(value) => Self::from_u128(value),
+            None => None,
+        }
+    }
+
+    /// Adds an integer to the ENID, treating the bytes as a big-endian
+    /// integer, and wrapping around at `2^80`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// assert_eq!(Enid80::MAX.wrapping_add_u64(1), enid80!("00000000-00000000"));
     /// ```
     #[must_use]
     #[inline]
-    pub const fn is_zero(&self) -> bool {
-        matches!(self.0, [0, 0, 0, 0, 0, 0, 0, 0, 0, 0])
-    }
+    pub const fn wrapping_add_u64(self, n: u64) -> Self {
+        let [_, _, _, _, _, _, bytes @ ..] = self.to_u128().wrapping_add(n as u128).to_be_bytes();
 
-    // TODO: Use `ascii::Char` once it's stable.
-    // https://github.com/rust-lang/rust/issues/110998
-    pub(crate) const fn write_to_buffer<'a>(&self, buf: &'a mut [u8; 17]) -> &'a str {
-        *buf.first_chunk_mut().unwrap() = base32::encode(*self.0.first_chunk().unwrap());
-
-        buf[8] = b'-';
-
-        *buf.last_chunk_mut().unwrap() = base32::encode(*self.0.last_chunk().unwrap());
-
-        // SAFETY: Only ASCII characters are written to the buffer.
-        unsafe { str::from_utf8_unchecked(buf) }
+        Self(bytes)
     }
-}
 
-impl Debug for Enid80 {
+    /// Subtracts an integer from the ENID, treating the bytes as a big-endian
+    /// integer, or returns `None` if the result would be negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// assert_eq!(enid80!("00000001-00000000").checked_sub_u64(1), Some(enid80!("00000000-zzzzzzzz")));
+    /// assert_eq!(Enid80::NIL.checked_sub_u64(1), None);
+    /// ```
+    #[must_use]
     #[inline]
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        Display::fmt(self, f)
-    }
-}
-
-impl Display for Enid80 {
-    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
-        f.pad(self.write_to_buffer(&mut [0; 17]))
+    pub const fn checked_sub_u64(self, n: u64) -> Option<Self> {
+        match self.to_u128().checked_sub(n as u128) {
+            Some(value) => Self::from_u128(value),
+            None => None,
+        }
     }
-}
-
-impl FromStr for Enid80 {
-    type Err = EnidParseError;
 
+    /// Returns how far `other` is after this ENID, treating the bytes as
+    /// big-endian integers, or `None` if `other` is before this ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let start = enid80!("00000000-zzzzzzzz");
+    /// let end = enid80!("00000001-00000000");
+    ///
+    /// assert_eq!(start.distance_to(end), Some(1));
+    /// assert_eq!(end.distance_to(start), None);
+    /// ```
+    #[must_use]
     #[inline]
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Self::parse_str(s)
+    pub const fn distance_to(self, other: Self) -> Option<u128> {
+        other.to_u128().checked_sub(self.to_u128())
     }
-}
 
-impl From<[u8; 10]> for Enid80 {
+    /// Reduces the ENID to a 40-bit ENID by XORing its two halves together.
+    ///
+    /// Each byte of the result is the XOR of the corresponding bytes of the
+    /// first and last five bytes of this ENID. Unlike truncation, every bit of
+    /// this ENID affects the result, so it is well distributed even if only one
+    /// half is random. The reduction will never change, so it can be used to
+    /// derive keys for secondary indexes and bloom filters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, enid80};
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.xor_fold(), enid40!("anvnbeyx"));
+    /// ```
+    #[must_use]
     #[inline]
-    fn from(bytes: [u8; 10]) -> Self {
-        Self::from_bytes(bytes)
+    pub const fn xor_fold(&self) -> Enid40 {
+        let b = &self.0;
+
+        Enid40::from_bytes([
+            b[0] ^ b[5],
+            b[1] ^ b[6],
+            b[2] ^ b[7],
+            b[3] ^ b[8],
+            b[4] ^ b[9],
+        ])
     }
 }
 
-impl From<Enid80> for [u8; 10] {
+impl From<Enid80> for u128 {
     #[inline]
     fn from(enid: Enid80) -> Self {
-        enid.into_bytes()
+        enid.to_u128()
     }
 }
 
+/// A 120-bit ENID.
+///
+/// This is formatted as three groups of 8 characters, separated by hyphens,
+/// which allows a routing prefix and an 80-bit random part to be combined in
+/// one identifier.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid120;
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let enid = Enid120::parse_str("y3gx5gxm-mpb8ey39-04hmasw9")?;
+///
+/// assert_eq!(enid.as_bytes()[10..], [0x01, 0x23, 0x45, 0x67, 0x89]);
+/// # Ok(())
+/// # }
+/// ```
+pub type Enid120 = EnidN<15>;
+
+/// A 160-bit ENID.
+///
+/// This is formatted as four groups of 8 characters, separated by hyphens,
+/// and gives collision resistance comparable to a SHA-1 hash.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid160;
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let enid = Enid160::parse_str("y3gx5gxm-mpb8ey39-04hmasw9-nf6yy00h")?;
+///
+/// assert_eq!(enid.as_bytes()[..5], [0xf0, 0xe1, 0xd2, 0xc3, 0xb4]);
+/// # Ok(())
+/// # }
+/// ```
+pub type Enid160 = EnidN<20>;
+
 /// An ENID, either 40 or 80 bits.
 ///
+/// ENIDs are byte arrays with an alignment of 1, so an `Enid` is stored in 11
+/// bytes, the 10-byte ENID and a tag, with no padding. The tag has unused
+/// values, so `Option<Enid>` is also 11 bytes.
+///
 /// # Examples
 ///
 /// ```
@@ -424,7 +2041,7 @@ impl From<Enid80> for [u8; 10] {
 /// assert_eq!(enid40.as_bytes(), &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
 /// assert_eq!(enid80.as_bytes(), &[0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
 /// ```
-#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub enum Enid {
     /// A 40-bit ENID.
     Enid40(Enid40),
@@ -434,6 +2051,31 @@ pub enum Enid {
 }
 
 impl Enid {
+    /// The smallest ENID, the nil 40-bit ENID `"00000000"`.
+    ///
+    /// ENIDs of different sizes are ordered by their bytes, so every 80-bit
+    /// ENID is greater than this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// assert_eq!(Enid::NIL, enid!("00000000"));
+    /// assert!(Enid::NIL < enid!("00000000-00000000"));
+    /// ```
+    pub const NIL: Self = Self::Enid40(Enid40::NIL);
+
+    /// The largest ENID, the 80-bit ENID `"zzzzzzzz-zzzzzzzz"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// assert_eq!(Enid::MAX, enid!("zzzzzzzz-zzzzzzzz"));
+    /// assert!(Enid::MAX > enid!("zzzzzzzz"));
+    /// ```
+    pub const MAX: Self = Self::Enid80(Enid80::MAX);
+
     /// Attempts to create an ENID from the given string.
     ///
     /// # Examples
@@ -476,16 +2118,304 @@ impl Enid {
     ///
     /// # Errors
     ///
-    /// Returns an [`EnidParseError`] if the string is not a valid ENID.
-    #[inline]
-    pub const fn parse_str_ascii(s: &[u8]) -> Result<Self, EnidParseError> {
-        if s.len() == 8 {
-            match Enid40::parse_str_ascii(s) {
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID.
+    #[inline]
+    pub const fn parse_str_ascii(s: &[u8]) -> Result<Self, EnidParseError> {
+        if s.len() == 8 {
+            match Enid40::parse_str_ascii(s) {
+                Ok(enid) => Ok(Self::Enid40(enid)),
+                Err(e) => Err(e),
+            }
+        } else {
+            match Enid80::parse_str_ascii(s) {
+                Ok(enid) => Ok(Self::Enid80(enid)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    /// Attempts to create an ENID of either size from a string, describing
+    /// what was wrong with the string if it is not a valid ENID.
+    ///
+    /// A string that is not 8 characters long is checked as an [`Enid80`], so
+    /// an invalid length is reported against the length of an 80-bit ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid, EnidParseErrorDetailed};
+    /// assert_eq!(Enid::parse_str_detailed("m6sc7n75"), Ok(enid!("m6sc7n75")));
+    /// assert_eq!(
+    ///     Enid::parse_str_detailed("m6sc7n7\u{e9}"),
+    ///     Err(EnidParseErrorDetailed::InvalidLength { expected: 17, found: 9 }),
+    /// );
+    /// assert_eq!(
+    ///     Enid::parse_str_detailed("m6sc7nu5").unwrap_err().to_string(),
+    ///     "invalid character 'u' at position 6",
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseErrorDetailed`] for the first problem found in
+    /// the string.
+    #[inline]
+    pub const fn parse_str_detailed(s: &str) -> Result<Self, EnidParseErrorDetailed> {
+        let s = s.as_bytes();
+
+        if s.len() == 8 {
+            match Enid40::parse_ascii_detailed(s, Decoding::Strict, false) {
+                Ok(enid) => Ok(Self::Enid40(enid)),
+                Err(e) => Err(e),
+            }
+        } else {
+            match Enid80::parse_ascii_detailed(s, Decoding::Strict, false) {
+                Ok(enid) => Ok(Self::Enid80(enid)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    /// Returns whether a string is a valid ENID of either size, without
+    /// decoding it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid;
+    /// assert!(Enid::is_valid_str("m6sc7n75"));
+    /// assert!(Enid::is_valid_str("y3gx5gxm-mpb8ey39"));
+    /// assert!(!Enid::is_valid_str("y3gx5gxm-"));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_valid_str(s: &str) -> bool {
+        Enid40::is_valid_str(s) || Enid80::is_valid_str(s)
+    }
+
+    /// Returns the offset of the first byte that prevents a string from
+    /// being a valid ENID of either size, or `None` if it is valid.
+    ///
+    /// A string that is not 8 characters long is checked as an [`Enid80`].
+    /// See [`Enid80::first_invalid_offset`] for how the offset is chosen.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid;
+    /// assert_eq!(Enid::first_invalid_offset("m6sc7n75"), None);
+    /// assert_eq!(Enid::first_invalid_offset("m6sc7nu5"), Some(6));
+    /// assert_eq!(Enid::first_invalid_offset("m6sc7n75-"), Some(9));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn first_invalid_offset(s: &str) -> Option<usize> {
+        if s.len() == 8 {
+            Enid40::first_invalid_offset(s)
+        } else {
+            Enid80::first_invalid_offset(s)
+        }
+    }
+
+    /// Attempts to create an ENID of either size from a string that may have
+    /// been transcribed by a person.
+    ///
+    /// See [`Enid40::parse_str_lenient`] for the characters that are accepted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid::parse_str_lenient("Y3GX5GXM-MPB8EY39")?, enid!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID after
+    /// the mapping.
+    pub const fn parse_str_lenient(s: &str) -> Result<Self, EnidParseError> {
+        if s.len() == 8 {
+            match Enid40::parse_str_lenient(s) {
+                Ok(enid) => Ok(Self::Enid40(enid)),
+                Err(e) => Err(e),
+            }
+        } else {
+            match Enid80::parse_str_lenient(s) {
+                Ok(enid) => Ok(Self::Enid80(enid)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    /// Parses a user-supplied ENID string of either size, normalizing it first.
+    ///
+    /// Surrounding whitespace is trimmed, and the string is parsed as with
+    /// [`parse_str_lenient`](Self::parse_str_lenient), so uppercase and
+    /// confusable characters are accepted. An 80-bit ENID may also be given
+    /// without its hyphen. This is intended as the single routine for
+    /// normalizing IDs from web forms and imported files; the canonical string
+    /// is the [`Display`] form of the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid::canonicalize("  M6SC7N75\n")?, enid!("m6sc7n75"));
+    /// assert_eq!(Enid::canonicalize("Y3GX5GXMMPB8EY39")?, enid!("y3gx5gxm-mpb8ey39"));
+    /// assert_eq!(Enid::canonicalize("Y3GX5GXM-MPB8EY39")?.to_string(), "y3gx5gxm-mpb8ey39");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the normalized string is not a valid
+    /// ENID.
+    pub const fn canonicalize(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.trim_ascii().as_bytes();
+
+        match s.len() {
+            8 => match Enid40::parse_ascii(s, Decoding::Lenient, false) {
+                Ok(enid) => Ok(Self::Enid40(enid)),
+                Err(e) => Err(e),
+            },
+            16 => match Enid80::parse_ascii(s, Decoding::Lenient, true) {
+                Ok(enid) => Ok(Self::Enid80(enid)),
+                Err(e) => Err(e),
+            },
+            _ => match Enid80::parse_ascii(s, Decoding::Lenient, false) {
+                Ok(enid) => Ok(Self::Enid80(enid)),
+                Err(e) => Err(e),
+            },
+        }
+    }
+
+    /// Returns an adapter that formats the ENID as a URN, such as
+    /// `"urn:enid:m6sc7n75"`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// assert_eq!(enid!("m6sc7n75").urn().to_string(), "urn:enid:m6sc7n75");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn urn(&self) -> Urn<Self> {
+        Urn(*self)
+    }
+
+    /// Attempts to create an ENID of either size from a URN, such as
+    /// `"urn:enid:m6sc7n75"`.
+    ///
+    /// See [`Enid40::parse_urn`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = Enid::parse_urn("urn:enid:y3gx5gxm-mpb8ey39")?;
+    ///
+    /// assert_eq!(enid, enid!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string does not start with
+    /// `urn:enid:`, or the rest is not a valid ENID.
+    pub const fn parse_urn(s: &str) -> Result<Self, EnidParseError> {
+        match format::strip_urn_prefix(s.as_bytes()) {
+            Some(s) => Self::parse_str_ascii(s),
+            None => Err(EnidParseError),
+        }
+    }
+
+    /// Attempts to create an ENID of either size from a string written by a
+    /// Crockford's Base32 encoder.
+    ///
+    /// See [`Enid40::parse_str_crockford`] for the characters that are
+    /// accepted. The size is chosen by the number of characters other than
+    /// hyphens: 8 for an [`Enid40`] and 16 for an [`Enid80`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid::parse_str_crockford("M6SC-7N75")?, enid!("m6sc7n75"));
+    /// assert_eq!(Enid::parse_str_crockford("Y3GX5GXMMPB8EY39")?, enid!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID of
+    /// either size.
+    pub const fn parse_str_crockford(s: &str) -> Result<Self, EnidParseError> {
+        let bytes = s.as_bytes();
+        let mut len = 0;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            if bytes[i] != base32::SEPARATOR {
+                len += 1;
+            }
+
+            i += 1;
+        }
+
+        if len == 8 {
+            match Enid40::parse_str_crockford(s) {
+                Ok(enid) => Ok(Self::Enid40(enid)),
+                Err(e) => Err(e),
+            }
+        } else {
+            match Enid80::parse_str_crockford(s) {
+                Ok(enid) => Ok(Self::Enid80(enid)),
+                Err(e) => Err(e),
+            }
+        }
+    }
+
+    /// Attempts to create an ENID of either size from its bytes written as
+    /// hexadecimal digits.
+    ///
+    /// The size is chosen by the length of the string: 10 digits for an
+    /// [`Enid40`] and 20 for an [`Enid80`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = enid!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(Enid::from_hex_str("f0e1d2c3b4a596877869")?, enid);
+    /// assert_eq!(format!("{enid:x}"), "f0e1d2c3b4a596877869");
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not 10 or 20
+    /// hexadecimal digits.
+    pub const fn from_hex_str(s: &str) -> Result<Self, EnidParseError> {
+        if s.len() == 10 {
+            match Enid40::from_hex_str(s) {
                 Ok(enid) => Ok(Self::Enid40(enid)),
                 Err(e) => Err(e),
             }
         } else {
-            match Enid80::parse_str_ascii(s) {
+            match Enid80::from_hex_str(s) {
                 Ok(enid) => Ok(Self::Enid80(enid)),
                 Err(e) => Err(e),
             }
@@ -548,6 +2478,184 @@ impl Enid {
     pub const fn is_enid80(&self) -> bool {
         matches!(self, Enid::Enid80(_))
     }
+
+    /// Returns `true` if the ENID is filled with zeros, whatever its size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// assert_eq!(enid!("00000000").is_nil(), true);
+    /// assert_eq!(enid!("00000000-00000000").is_nil(), true);
+    /// assert_eq!(enid!("m6sc7n75").is_nil(), false);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn is_nil(&self) -> bool {
+        match self {
+            Enid::Enid40(enid) => enid.is_nil(),
+            Enid::Enid80(enid) => enid.is_nil(),
+        }
+    }
+
+    /// Returns a reference to the 40-bit ENID, or `None` if this is an 80-bit
+    /// ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, enid40};
+    /// assert_eq!(enid!("m6sc7n75").as_enid40(), Some(&enid40!("m6sc7n75")));
+    /// assert_eq!(enid!("y3gx5gxm-mpb8ey39").as_enid40(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_enid40(&self) -> Option<&Enid40> {
+        match self {
+            Enid::Enid40(enid) => Some(enid),
+            Enid::Enid80(_) => None,
+        }
+    }
+
+    /// Returns a reference to the 80-bit ENID, or `None` if this is a 40-bit
+    /// ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, enid80};
+    /// assert_eq!(enid!("y3gx5gxm-mpb8ey39").as_enid80(), Some(&enid80!("y3gx5gxm-mpb8ey39")));
+    /// assert_eq!(enid!("m6sc7n75").as_enid80(), None);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn as_enid80(&self) -> Option<&Enid80> {
+        match self {
+            Enid::Enid80(enid) => Some(enid),
+            Enid::Enid40(_) => None,
+        }
+    }
+
+    /// Returns the 40-bit ENID, or `None` if this is an 80-bit ENID.
+    #[must_use]
+    #[inline]
+    pub const fn into_enid40(self) -> Option<Enid40> {
+        match self {
+            Enid::Enid40(enid) => Some(enid),
+            Enid::Enid80(_) => None,
+        }
+    }
+
+    /// Returns the 80-bit ENID, or `None` if this is a 40-bit ENID.
+    #[must_use]
+    #[inline]
+    pub const fn into_enid80(self) -> Option<Enid80> {
+        match self {
+            Enid::Enid80(enid) => Some(enid),
+            Enid::Enid40(_) => None,
+        }
+    }
+
+    /// Returns a stable 64-bit hash of the ENID.
+    ///
+    /// This is the same as the [`portable_hash`](EnidN::portable_hash) of the
+    /// contained [`Enid40`] or [`Enid80`]: the 64-bit FNV-1a hash of the
+    /// underlying bytes. The algorithm will never change.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// let enid40 = enid!("m6sc7n75");
+    /// let enid80 = enid!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid40.portable_hash(), 0xa54a92685a230468);
+    /// assert_eq!(enid80.portable_hash(), 0xaf3627f285011102);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn portable_hash(&self) -> u64 {
+        hash::fnv1a_64(self.as_bytes())
+    }
+
+    /// Maps the ENID to one of `shards` shards, numbered from zero.
+    ///
+    /// This is the same as the [`shard`](EnidN::shard) of the contained
+    /// [`Enid40`] or [`Enid80`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shards` is zero.
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub const fn shard(&self, shards: u32) -> u32 {
+        match self {
+            Enid::Enid40(enid) => enid.shard(shards),
+            Enid::Enid80(enid) => enid.shard(shards),
+        }
+    }
+
+    /// Writes the string form of the ENID to the start of a buffer, and
+    /// returns it as a string.
+    ///
+    /// See [`Enid80::encode_to_slice`] for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// let mut buf = [0; 17];
+    ///
+    /// assert_eq!(enid!("m6sc7n75").encode_to_slice(&mut buf), Ok("m6sc7n75"));
+    /// assert!(enid!("y3gx5gxm-mpb8ey39").encode_to_slice(&mut buf[..8]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferTooSmallError`] if the buffer is shorter than the
+    /// string form of the ENID.
+    #[inline]
+    pub const fn encode_to_slice<'a>(
+        &self,
+        buf: &'a mut [u8],
+    ) -> Result<&'a str, BufferTooSmallError> {
+        match self {
+            Enid::Enid40(enid) => enid.encode_to_slice(buf),
+            Enid::Enid80(enid) => enid.encode_to_slice(buf),
+        }
+    }
+
+    /// Writes the string form of the ENID to a [`fmt::Write`].
+    ///
+    /// See [`Enid80::format_into`] for details.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the writer returns an error.
+    #[inline]
+    pub fn format_into<W: Write + ?Sized>(&self, w: &mut W) -> fmt::Result {
+        match self {
+            Enid::Enid40(enid) => enid.format_into(w),
+            Enid::Enid80(enid) => enid.format_into(w),
+        }
+    }
+
+    pub(crate) const fn write_ascii<'a>(&self, buf: &'a mut [u8; 17]) -> &'a [u8] {
+        match self {
+            Enid::Enid40(enid) => enid.write_ascii(buf),
+            Enid::Enid80(enid) => enid.write_ascii(buf),
+        }
+    }
+}
+
+// Hashes only the bytes, consistently with `Borrow<[u8]>`. ENIDs of different
+// sizes never have equal bytes, so the variant does not need to be hashed.
+impl Hash for Enid {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
 }
 
 impl PartialOrd for Enid {
@@ -579,6 +2687,26 @@ impl Display for Enid {
     }
 }
 
+impl LowerHex for Enid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Enid::Enid40(enid) => LowerHex::fmt(enid, f),
+            Enid::Enid80(enid) => LowerHex::fmt(enid, f),
+        }
+    }
+}
+
+impl UpperHex for Enid {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Enid::Enid40(enid) => UpperHex::fmt(enid, f),
+            Enid::Enid80(enid) => UpperHex::fmt(enid, f),
+        }
+    }
+}
+
 impl FromStr for Enid {
     type Err = EnidParseError;
 
@@ -588,6 +2716,24 @@ impl FromStr for Enid {
     }
 }
 
+impl TryFrom<Enid> for Enid40 {
+    type Error = EnidSizeError;
+
+    #[inline]
+    fn try_from(enid: Enid) -> Result<Self, Self::Error> {
+        enid.into_enid40().ok_or(EnidSizeError)
+    }
+}
+
+impl TryFrom<Enid> for Enid80 {
+    type Error = EnidSizeError;
+
+    #[inline]
+    fn try_from(enid: Enid) -> Result<Self, Self::Error> {
+        enid.into_enid80().ok_or(EnidSizeError)
+    }
+}
+
 impl From<Enid40> for Enid {
     #[inline]
     fn from(enid: Enid40) -> Self {
@@ -616,22 +2762,86 @@ impl From<[u8; 10]> for Enid {
     }
 }
 
+impl AsRef<[u8]> for Enid {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl Borrow<[u8]> for Enid {
+    #[inline]
+    fn borrow(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl TryFrom<&[u8]> for Enid {
+    type Error = EnidLengthError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        match bytes.len() {
+            5 => Enid40::try_from(bytes).map(Self::Enid40),
+            10 => Enid80::try_from(bytes).map(Self::Enid80),
+            len => Err(EnidLengthError {
+                len,
+                sizes: &[5, 10],
+            }),
+        }
+    }
+}
+
+impl PartialEq<str> for Enid {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        match self {
+            Self::Enid40(enid) => enid == other,
+            Self::Enid80(enid) => enid == other,
+        }
+    }
+}
+
+impl PartialEq<&str> for Enid {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<Enid> for str {
+    #[inline]
+    fn eq(&self, other: &Enid) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<Enid> for &str {
+    #[inline]
+    fn eq(&self, other: &Enid) -> bool {
+        other == *self
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::enid;
+    use std::format;
     use std::string::ToString;
 
     #[test]
     fn default_zero() {
         assert_eq!(Enid40::default(), Enid40::ZERO);
         assert_eq!(Enid80::default(), Enid80::ZERO);
+        assert_eq!(Enid120::default(), Enid120::ZERO);
+        assert_eq!(Enid160::default(), Enid160::ZERO);
     }
 
     #[test]
     fn enid40() {
         fn assert_valid(bytes: [u8; 5], string: &str) {
-            let enid = Enid40(bytes);
+            let enid = Enid40::from_bytes(bytes);
             assert_eq!(enid.to_string(), string);
             assert_eq!(Enid40::from_str(string), Ok(enid));
         }
@@ -664,7 +2874,7 @@ mod tests {
     #[test]
     fn enid80() {
         fn assert_valid(bytes: [u8; 10], string: &str) {
-            let enid = Enid80(bytes);
+            let enid = Enid80::from_bytes(bytes);
             assert_eq!(enid.to_string(), string);
             assert_eq!(Enid80::from_str(string), Ok(enid));
         }
@@ -701,13 +2911,280 @@ mod tests {
         assert_invalid("00000000");
     }
 
+    #[test]
+    fn enid120() {
+        fn assert_valid(bytes: [u8; 15], string: &str) {
+            let enid = Enid120::from_bytes(bytes);
+            assert_eq!(enid.to_string(), string);
+            assert_eq!(Enid120::from_str(string), Ok(enid));
+        }
+
+        assert_valid([0; 15], "00000000-00000000-00000000");
+        assert_valid([0xff; 15], "zzzzzzzz-zzzzzzzz-zzzzzzzz");
+        assert_valid(
+            [0, 0, 0, 0, 1, 0, 0, 0, 0, 31, 0, 0, 0, 0, 32],
+            "00000001-0000000z-00000010",
+        );
+        assert_valid(
+            [
+                240, 225, 210, 195, 180, 165, 150, 135, 120, 105, 1, 35, 69, 103, 137,
+            ],
+            "y3gx5gxm-mpb8ey39-04hmasw9",
+        );
+
+        fn assert_invalid(string: &str) {
+            assert_eq!(Enid120::from_str(string), Err(EnidParseError));
+        }
+
+        assert_invalid("");
+        assert_invalid("00000000-00000000");
+        assert_invalid("00000000-00000000-00000000-");
+        assert_invalid("00000000-0000000000000000");
+        assert_invalid("00000000-00000000-0000000i");
+        assert_invalid("00000000-00000000-00000000-00000000");
+    }
+
+    #[test]
+    fn enid160() {
+        fn assert_valid(bytes: [u8; 20], string: &str) {
+            let enid = Enid160::from_bytes(bytes);
+            assert_eq!(enid.to_string(), string);
+            assert_eq!(Enid160::from_str(string), Ok(enid));
+        }
+
+        assert_valid([0; 20], "00000000-00000000-00000000-00000000");
+        assert_valid([0xff; 20], "zzzzzzzz-zzzzzzzz-zzzzzzzz-zzzzzzzz");
+        assert_valid(
+            [0, 0, 0, 0, 1, 0, 0, 0, 0, 31, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0],
+            "00000001-0000000z-00000010-00000000",
+        );
+        assert_valid(
+            [
+                240, 225, 210, 195, 180, 165, 150, 135, 120, 105, 1, 35, 69, 103, 137, 171, 205,
+                239, 0, 17,
+            ],
+            "y3gx5gxm-mpb8ey39-04hmasw9-nf6yy00h",
+        );
+
+        fn assert_invalid(string: &str) {
+            assert_eq!(Enid160::from_str(string), Err(EnidParseError));
+        }
+
+        assert_invalid("");
+        assert_invalid("00000000-00000000");
+        assert_invalid("00000000-00000000-00000000");
+        assert_invalid("00000000-00000000-00000000-00000000-");
+        assert_invalid("00000000-00000000-00000000000000000");
+        assert_invalid("00000000-0000000000000000-00000000");
+        assert_invalid("0000000000000000-00000000-00000000");
+        assert_invalid("00000000-00000000-00000000-0000000i");
+        assert_invalid("u0000000-00000000-00000000-00000000");
+    }
+
+    #[test]
+    fn crockford() {
+        let enid = Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+
+        assert_eq!(Enid40::parse_str_crockford("-m6sc-7N75-"), Ok(enid));
+        assert_eq!(Enid40::parse_str_crockford("m6sc7n7"), Err(EnidParseError));
+        assert_eq!(
+            Enid40::parse_str_crockford("m6sc7n750"),
+            Err(EnidParseError)
+        );
+        assert_eq!(Enid40::parse_str_crockford("m6sc7nu5"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_str_crockford("m6sc7n7*"), Err(EnidParseError));
+        assert_eq!(Enid::parse_str_crockford("m6sc7n7"), Err(EnidParseError));
+    }
+
+    #[test]
+    fn parse_detailed() {
+        use EnidParseErrorDetailed::*;
+
+        assert_eq!(
+            Enid40::parse_str_detailed(""),
+            Err(InvalidLength {
+                expected: 8,
+                found: 0
+            })
+        );
+        assert_eq!(
+            Enid80::parse_str_detailed("y3gx5gxm-mpb8eY39"),
+            Err(InvalidCharacter {
+                position: 14,
+                byte: b'Y'
+            })
+        );
+        assert_eq!(
+            Enid80::parse_str_detailed("y3gx5gxmmpb8ey39-"),
+            Err(MissingSeparator { position: 8 })
+        );
+
+        let err = Enid40::parse_str_detailed("m6sc\u{e9}n7").unwrap_err();
+
+        assert_eq!(
+            err,
+            InvalidCharacter {
+                position: 4,
+                byte: 0xc3
+            }
+        );
+        assert_eq!(err.to_string(), "invalid byte 0xc3 at position 4");
+        assert_eq!(EnidParseError::from(err), EnidParseError);
+    }
+
+    #[test]
+    fn is_valid_str() {
+        assert!(Enid40::is_valid_str("zzzzzzzz"));
+        assert!(Enid160::is_valid_str("00000000-00000000-00000000-0000000z"));
+        assert!(!Enid40::is_valid_str("m6sc7n75-"));
+        assert!(!Enid40::is_valid_str("M6SC7N75"));
+        assert_eq!(Enid40::first_invalid_offset(""), Some(0));
+        assert_eq!(Enid40::first_invalid_offset("m6sc7n75-"), Some(8));
+        assert_eq!(Enid80::first_invalid_offset("y3gx5gxm-"), Some(9));
+        assert_eq!(
+            Enid80::first_invalid_offset("y3gx5gxm-mpb8ey3\u{e9}"),
+            Some(16)
+        );
+        assert_eq!(Enid::first_invalid_offset("y3gx5gxm-mpb8ey39"), None);
+    }
+
+    #[test]
+    fn eq_str() {
+        let enid40 = Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+        let enid80 = Enid80::from_bytes([0xff; 10]);
+
+        assert_eq!(enid40, "m6sc7n75");
+        assert_eq!("m6sc7n75", enid40);
+        assert_eq!(*"zzzzzzzz-zzzzzzzz", enid80);
+        assert_ne!(enid40, "m6sc7n7");
+        assert_ne!(enid40, "m6sc7n750");
+        assert_ne!(enid40, "M6SC7N75");
+        assert_ne!(enid80, "zzzzzzzzzzzzzzzz");
+        assert_eq!(Enid::from(enid40), "m6sc7n75");
+        assert_eq!("zzzzzzzz-zzzzzzzz", Enid::from(enid80));
+        assert_ne!(Enid::from(enid80), "m6sc7n75");
+    }
+
+    #[test]
+    fn try_from_slice() {
+        let bytes = [0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0, 0, 0, 0, 0];
+
+        assert_eq!(
+            Enid40::try_from(&bytes[..5]),
+            Ok(enid!("m6sc7n75").into_enid40().unwrap())
+        );
+        assert_eq!(
+            Enid80::try_from(&bytes[..]).map(|e| e.split().1),
+            Ok(Enid40::ZERO)
+        );
+        assert_eq!(Enid::try_from(&bytes[..5]), Ok(enid!("m6sc7n75")));
+        assert!(Enid::try_from(&bytes[..]).unwrap().is_enid80());
+
+        let err = Enid80::try_from(&bytes[..5]).unwrap_err();
+
+        assert_eq!(err.byte_len(), 5);
+        assert_eq!(err.expected_sizes(), [10]);
+        assert_eq!(err.to_string(), "expected 10 bytes for an ENID, found 5");
+        assert!(Enid::try_from(&[][..]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn borrow_bytes() {
+        use std::collections::{BTreeSet, HashSet};
+
+        let bytes: &[u8] = &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5];
+        let enids = [enid!("m6sc7n75"), enid!("y3gx5gxm-mpb8ey39")];
+
+        assert!(enids.into_iter().collect::<HashSet<_>>().contains(bytes));
+        assert!(enids.into_iter().collect::<BTreeSet<_>>().contains(bytes));
+        assert!(HashSet::from([enids[0].into_enid40().unwrap()]).contains(bytes));
+        assert_eq!(enids[1].as_ref(), enids[1].as_bytes());
+    }
+
+    #[test]
+    fn display_padding() {
+        let enid = Enid80::from_bytes([0xff; 10]);
+
+        assert_eq!(format!("{enid:20}"), "zzzzzzzz-zzzzzzzz   ");
+        assert_eq!(format!("{enid:>20}"), "   zzzzzzzz-zzzzzzzz");
+        assert_eq!(format!("{enid:*^20}"), "*zzzzzzzz-zzzzzzzz**");
+        assert_eq!(format!("{enid:10}"), "zzzzzzzz-zzzzzzzz");
+        assert_eq!(format!("{enid:.9}"), "zzzzzzzz-");
+        assert_eq!(format!("{enid:>6.4}"), "  zzzz");
+    }
+
+    #[test]
+    fn display_long() {
+        let enid = EnidN::<25>::from_bytes([0xff; 25]);
+        let s = "zzzzzzzz-zzzzzzzz-zzzzzzzz-zzzzzzzz-zzzzzzzz";
+
+        assert_eq!(enid.to_string(), s);
+        assert_eq!(format!("{enid:.36}"), s[..36]);
+        assert_eq!(format!("{enid:.40}"), s[..40]);
+
+        let mut out = std::string::String::new();
+        enid.format_into(&mut out).unwrap();
+
+        assert_eq!(out, s);
+    }
+
+    #[test]
+    fn layout() {
+        use core::mem::{align_of, size_of};
+
+        assert_eq!(size_of::<Enid40>(), 5);
+        assert_eq!(size_of::<Enid80>(), 10);
+        assert_eq!(size_of::<Enid>(), 11);
+        assert_eq!(size_of::<Option<Enid>>(), 11);
+        assert_eq!(align_of::<Enid>(), 1);
+    }
+
+    #[test]
+    fn successor_predecessor() {
+        let carries = Enid80::from_bytes([0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, 0xff]);
+        let carried = Enid80::from_bytes([0, 0, 0, 0, 0, 0, 1, 0, 0, 0]);
+
+        assert_eq!(carries.successor(), Some(carried));
+        assert_eq!(carried.predecessor(), Some(carries));
+        assert_eq!(Enid40::NIL.successor(), Enid40::from_u64(1));
+        assert_eq!(Enid40::MAX.predecessor(), Enid40::from_u64((1 << 40) - 2));
+        assert_eq!(Enid40::MAX.successor(), None);
+        assert_eq!(Enid80::NIL.predecessor(), None);
+    }
+
+    #[test]
+    fn shard() {
+        let enid = Enid160::from_bytes(core::array::from_fn(|i| i as u8));
+
+        assert_eq!(enid.shard(1), 0);
+        assert_eq!(enid.shard(7), 3);
+        assert_eq!(enid!("m6sc7n75").shard(10), 6);
+    }
+
+    #[test]
+    fn reversed_interleaved() {
+        let bytes: [u8; 15] = core::array::from_fn(|i| i as u8 * 17);
+        let enid = Enid120::from_bytes(bytes);
+
+        assert_eq!(enid.to_reversed().as_bytes()[0], bytes[14]);
+        assert_eq!(Enid120::from_reversed(enid.to_reversed()), enid);
+        assert_eq!(Enid120::from_interleaved(enid.to_interleaved()), enid);
+
+        // The top bit of each half lands in the first two bits.
+        let enid = Enid160::from_bytes(core::array::from_fn(|i| if i == 10 { 0x80 } else { 0 }));
+
+        assert_eq!(enid.to_interleaved().as_bytes()[0], 0x40);
+        assert_eq!(Enid160::from_interleaved(enid.to_interleaved()), enid);
+    }
+
     #[test]
     fn enid_var() {
         fn assert_valid(bytes: &[u8], string: &str) {
             let enid = if bytes.len() == 5 {
-                Enid::Enid40(Enid40(bytes.try_into().unwrap()))
+                Enid::Enid40(Enid40::from_bytes(bytes.try_into().unwrap()))
             } else {
-                Enid::Enid80(Enid80(bytes.try_into().unwrap()))
+                Enid::Enid80(Enid80::from_bytes(bytes.try_into().unwrap()))
             };
 
             assert_eq!(enid.to_string(), string);
@@ -0,0 +1,213 @@
+use crate::base32;
+use crate::enid::{EnidN, EnidParseError};
+use crate::format;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::ops::RangeInclusive;
+use core::str::FromStr;
+
+/// The leading characters of an ENID's string form, for finding ENIDs by
+/// prefix, like the short hashes used by git.
+///
+/// A prefix of an [`EnidN`] has between one character and the full number of
+/// characters of its string form, such as 1 to 8 characters for an `Enid40`,
+/// or 1 to 16 for an `Enid80`. The separator between groups is optional at the
+/// end of the prefix, but required elsewhere.
+///
+/// Because ENIDs are ordered by their string forms, the ENIDs matching a
+/// prefix form a contiguous range, which can be used to search a sorted
+/// collection, such as a [`BTreeMap`](std::collections::BTreeMap), or a
+/// database index.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid80, EnidPrefix};
+/// # fn main() -> Result<(), enid::EnidParseError> {
+/// let prefix = EnidPrefix::<10>::parse_str("y3gx5gxm-mp")?;
+///
+/// assert!(prefix.matches(&enid80!("y3gx5gxm-mpb8ey39")));
+/// assert!(!prefix.matches(&enid80!("y3gx5gxm-mqb8ey39")));
+/// assert_eq!(
+///     prefix.to_range(),
+///     enid80!("y3gx5gxm-mp000000")..=enid80!("y3gx5gxm-mpzzzzzz"),
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EnidPrefix<const N: usize> {
+    start: EnidN<N>,
+    chars: usize,
+}
+
+impl<const N: usize> EnidPrefix<N> {
+    /// Attempts to create a prefix from the given string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is empty, is longer than
+    /// the string form of an ENID, or is not the start of a valid ENID.
+    pub const fn parse_str(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.as_bytes();
+
+        if s.is_empty() || s.len() > EnidN::<N>::STR_LEN {
+            return Err(EnidParseError);
+        }
+
+        let mut bytes = [0; N];
+        let mut chars = 0;
+        let mut i = 0;
+
+        while i < s.len() {
+            if i % 9 == 8 {
+                if s[i] != base32::SEPARATOR {
+                    return Err(EnidParseError);
+                }
+            } else {
+                let Some(value) = base32::char_value(s[i]) else {
+                    return Err(EnidParseError);
+                };
+
+                let mut bit = 0;
+                while bit < 5 {
+                    if value >> (4 - bit) & 1 == 1 {
+                        let pos = chars * 5 + bit;
+                        bytes[pos / 8] |= 0x80 >> (pos % 8);
+                    }
+
+                    bit += 1;
+                }
+
+                chars += 1;
+            }
+
+            i += 1;
+        }
+
+        Ok(Self {
+            start: EnidN::from_bytes(bytes),
+            chars,
+        })
+    }
+
+    /// Returns the number of characters in the prefix, not including
+    /// separators.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> usize {
+        self.chars
+    }
+
+    /// Returns `false`, as a prefix always has at least one character.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the ENID's string form starts with the prefix.
+    #[must_use]
+    #[inline]
+    pub const fn matches(&self, enid: &EnidN<N>) -> bool {
+        enid.common_prefix_len(&self.start) as usize >= self.chars * 5
+    }
+
+    /// Returns the smallest ENID that matches the prefix.
+    #[must_use]
+    #[inline]
+    pub const fn start(&self) -> EnidN<N> {
+        self.start
+    }
+
+    /// Returns the largest ENID that matches the prefix.
+    #[must_use]
+    pub const fn end(&self) -> EnidN<N> {
+        let mut bytes = self.start.into_bytes();
+        let mut pos = self.chars * 5;
+
+        while pos < N * 8 {
+            bytes[pos / 8] |= 0x80 >> (pos % 8);
+            pos += 1;
+        }
+
+        EnidN::from_bytes(bytes)
+    }
+
+    /// Returns the range of ENIDs that match the prefix.
+    #[must_use]
+    #[inline]
+    pub const fn to_range(&self) -> RangeInclusive<EnidN<N>> {
+        RangeInclusive::new(self.start(), self.end())
+    }
+}
+
+impl<const N: usize> Debug for EnidPrefix<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for EnidPrefix<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let len = self.chars + (self.chars - 1) / 8;
+        let chars = format::chars(&self.start).take(len);
+
+        format::write_padded(f, len, chars.map(char::from))
+    }
+}
+
+impl<const N: usize> FromStr for EnidPrefix<N> {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enid::{Enid40, Enid80};
+    use std::string::ToString;
+
+    #[test]
+    fn matches_string_prefix() {
+        let enids = [
+            Enid80::ZERO,
+            Enid80::MAX,
+            Enid80::from_bytes([0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]),
+        ];
+
+        for enid in enids {
+            let s = enid.to_string();
+
+            for len in 1..=s.len() {
+                let prefix = EnidPrefix::<10>::parse_str(&s[..len]).unwrap();
+                let range = prefix.to_range();
+
+                assert!(prefix.matches(&enid));
+                assert!(range.contains(&enid));
+                assert!(range.start().to_string().starts_with(&s[..len]));
+                assert!(range.end().to_string().starts_with(&s[..len]));
+                assert_eq!(prefix.to_string(), s[..len].trim_end_matches('-'));
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(EnidPrefix::<5>::parse_str("").is_err());
+        assert!(EnidPrefix::<5>::parse_str("m6u").is_err());
+        assert!(EnidPrefix::<5>::parse_str("m6sc7n75-").is_err());
+        assert!(EnidPrefix::<10>::parse_str("y3gx5gxmm").is_err());
+
+        let prefix = EnidPrefix::<5>::parse_str("m6sc7n75").unwrap();
+        assert_eq!(
+            prefix.to_range(),
+            Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5])
+                ..=Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5])
+        );
+    }
+}
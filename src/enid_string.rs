@@ -0,0 +1,297 @@
+use crate::ascii;
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+use core::borrow::Borrow;
+use core::cmp::Ordering;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::str::FromStr;
+
+/// The string form of an [`Enid40`] or [`Enid80`], stored inline without
+/// allocating.
+///
+/// This is returned by [`to_enid_string`](Enid::to_enid_string), and
+/// dereferences to [`str`], so it can be used as an owned string where a
+/// [`String`](std::string::String) is not available or too costly.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid80, EnidString};
+/// let s: EnidString = enid80!("y3gx5gxm-mpb8ey39").to_enid_string();
+///
+/// assert_eq!(s, "y3gx5gxm-mpb8ey39");
+/// assert_eq!(s.len(), 17);
+/// assert!(s.starts_with("y3gx"));
+/// ```
+#[derive(Copy, Clone)]
+pub struct EnidString {
+    buf: [u8; 17],
+    len: u8,
+}
+
+impl EnidString {
+    /// Returns the string.
+    #[must_use]
+    #[inline]
+    pub const fn as_str(&self) -> &str {
+        let (s, _) = self.buf.split_at(self.len as usize);
+        ascii::to_str(s)
+    }
+
+    /// Returns the ENID the string was formed from.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// let enid = enid!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.to_enid_string().to_enid(), enid);
+    /// ```
+    #[must_use]
+    pub const fn to_enid(&self) -> Enid {
+        match Enid::parse_str(self.as_str()) {
+            Ok(enid) => enid,
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+impl Enid40 {
+    /// Returns the string form of the ENID, stored inline in an
+    /// [`EnidString`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// assert_eq!(enid40!("m6sc7n75").to_enid_string(), "m6sc7n75");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_enid_string(&self) -> EnidString {
+        let mut buf = [0; 17];
+        self.write_ascii(&mut buf);
+
+        EnidString { buf, len: 8 }
+    }
+}
+
+impl Enid80 {
+    /// Returns the string form of the ENID, stored inline in an
+    /// [`EnidString`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// assert_eq!(enid80!("y3gx5gxm-mpb8ey39").to_enid_string(), "y3gx5gxm-mpb8ey39");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_enid_string(&self) -> EnidString {
+        let mut buf = [0; 17];
+        self.write_ascii(&mut buf);
+
+        EnidString { buf, len: 17 }
+    }
+}
+
+impl Enid {
+    /// Returns the string form of the ENID, stored inline in an
+    /// [`EnidString`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// assert_eq!(enid!("m6sc7n75").to_enid_string(), "m6sc7n75");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn to_enid_string(&self) -> EnidString {
+        match self {
+            Enid::Enid40(enid) => enid.to_enid_string(),
+            Enid::Enid80(enid) => enid.to_enid_string(),
+        }
+    }
+}
+
+impl Deref for EnidString {
+    type Target = str;
+
+    #[inline]
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for EnidString {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<[u8]> for EnidString {
+    #[inline]
+    fn as_ref(&self) -> &[u8] {
+        self.as_str().as_bytes()
+    }
+}
+
+impl Borrow<str> for EnidString {
+    #[inline]
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl Debug for EnidString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl Display for EnidString {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+impl FromStr for EnidString {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Enid::parse_str(s).map(|enid| enid.to_enid_string())
+    }
+}
+
+impl From<Enid40> for EnidString {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        enid.to_enid_string()
+    }
+}
+
+impl From<Enid80> for EnidString {
+    #[inline]
+    fn from(enid: Enid80) -> Self {
+        enid.to_enid_string()
+    }
+}
+
+impl From<Enid> for EnidString {
+    #[inline]
+    fn from(enid: Enid) -> Self {
+        enid.to_enid_string()
+    }
+}
+
+impl From<EnidString> for Enid {
+    #[inline]
+    fn from(s: EnidString) -> Self {
+        s.to_enid()
+    }
+}
+
+impl PartialEq for EnidString {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for EnidString {}
+
+impl PartialEq<str> for EnidString {
+    #[inline]
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for EnidString {
+    #[inline]
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialEq<EnidString> for str {
+    #[inline]
+    fn eq(&self, other: &EnidString) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl PartialEq<EnidString> for &str {
+    #[inline]
+    fn eq(&self, other: &EnidString) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl PartialOrd for EnidString {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for EnidString {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl Hash for EnidString {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Enid, EnidString, enid};
+
+    #[test]
+    fn round_trip() {
+        let enid40 = enid!("m6sc7n75");
+        let enid80 = enid!("y3gx5gxm-mpb8ey39");
+
+        assert_eq!(EnidString::from(enid40).to_enid(), enid40);
+        assert_eq!(Enid::from(enid80.to_enid_string()), enid80);
+        assert_eq!("y3gx5gxm-mpb8ey39".parse(), Ok(enid80.to_enid_string()));
+        assert!("y3gx5gxm".parse::<EnidString>().unwrap() < enid80.to_enid_string());
+        assert!("y3gx5gxm-".parse::<EnidString>().is_err());
+    }
+
+    #[test]
+    fn format() {
+        use std::format;
+
+        let s = enid!("m6sc7n75").to_enid_string();
+
+        assert_eq!(format!("{s:>10}"), "  m6sc7n75");
+        assert_eq!(format!("{s:?}"), "\"m6sc7n75\"");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let s = enid!("y3gx5gxm-mpb8ey39").to_enid_string();
+        let json = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(json, r#""y3gx5gxm-mpb8ey39""#);
+        assert_eq!(serde_json::from_str::<EnidString>(&json).unwrap(), s);
+        assert!(serde_json::from_str::<EnidString>(r#""y3gx5gxm""#).is_ok());
+        assert!(serde_json::from_str::<EnidString>(r#""Y3GX5GXM""#).is_err());
+    }
+}
@@ -0,0 +1,162 @@
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+use std::string::String;
+
+/// An error returned when an ENID cannot be read from an environment variable.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvError {
+    var: String,
+    kind: EnvErrorKind,
+}
+
+/// The reason an ENID could not be read from an environment variable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum EnvErrorKind {
+    /// The variable is not set.
+    Missing,
+
+    /// The variable is not valid Unicode.
+    NotUnicode,
+
+    /// The variable does not contain a valid ENID.
+    Malformed(EnidParseError),
+}
+
+impl EnvError {
+    /// Returns the name of the environment variable.
+    #[must_use]
+    #[inline]
+    pub fn var(&self) -> &str {
+        &self.var
+    }
+
+    /// Returns the reason for the error.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> EnvErrorKind {
+        self.kind
+    }
+}
+
+impl Display for EnvError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.kind {
+            EnvErrorKind::Missing => write!(f, "environment variable `{}` is not set", self.var),
+            EnvErrorKind::NotUnicode => {
+                write!(
+                    f,
+                    "environment variable `{}` is not valid Unicode",
+                    self.var
+                )
+            }
+            EnvErrorKind::Malformed(_) => {
+                write!(f, "environment variable `{}` is not a valid ENID", self.var)
+            }
+        }
+    }
+}
+
+impl Error for EnvError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            EnvErrorKind::Malformed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+fn from_env<T: FromStr<Err = EnidParseError>>(var: &str) -> Result<T, EnvError> {
+    let kind = match std::env::var(var) {
+        Ok(value) => match value.trim().parse() {
+            Ok(enid) => return Ok(enid),
+            Err(err) => EnvErrorKind::Malformed(err),
+        },
+        Err(std::env::VarError::NotPresent) => EnvErrorKind::Missing,
+        Err(std::env::VarError::NotUnicode(_)) => EnvErrorKind::NotUnicode,
+    };
+
+    Err(EnvError {
+        var: var.into(),
+        kind,
+    })
+}
+
+impl Enid40 {
+    /// Reads an ENID from an environment variable.
+    ///
+    /// Whitespace around the value is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40, EnvErrorKind};
+    /// # unsafe { std::env::set_var("NODE_ID", "m6sc7n75") };
+    /// assert_eq!(Enid40::from_env("NODE_ID"), Ok(enid40!("m6sc7n75")));
+    ///
+    /// let err = Enid40::from_env("MISSING_NODE_ID").unwrap_err();
+    /// assert_eq!(err.kind(), EnvErrorKind::Missing);
+    /// assert_eq!(err.to_string(), "environment variable `MISSING_NODE_ID` is not set");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnvError`] if the variable is not set, is not valid
+    /// Unicode, or is not a valid ENID.
+    #[inline]
+    pub fn from_env(var: &str) -> Result<Self, EnvError> {
+        from_env(var)
+    }
+}
+
+impl Enid80 {
+    /// Reads an ENID from an environment variable.
+    ///
+    /// Whitespace around the value is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80, EnvErrorKind};
+    /// # unsafe { std::env::set_var("NAMESPACE_ID", "y3gx5gxm-kbhqr4fs") };
+    /// # unsafe { std::env::set_var("BAD_NAMESPACE_ID", "y3gx5gxm") };
+    /// assert_eq!(Enid80::from_env("NAMESPACE_ID"), Ok(enid80!("y3gx5gxm-kbhqr4fs")));
+    ///
+    /// let err = Enid80::from_env("BAD_NAMESPACE_ID").unwrap_err();
+    /// assert!(matches!(err.kind(), EnvErrorKind::Malformed(_)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnvError`] if the variable is not set, is not valid
+    /// Unicode, or is not a valid ENID.
+    #[inline]
+    pub fn from_env(var: &str) -> Result<Self, EnvError> {
+        from_env(var)
+    }
+}
+
+impl Enid {
+    /// Reads an ENID of either size from an environment variable.
+    ///
+    /// Whitespace around the value is ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # unsafe { std::env::set_var("SERVICE_NODE_ID", "m6sc7n75") };
+    /// assert_eq!(Enid::from_env("SERVICE_NODE_ID"), Ok(enid!("m6sc7n75")));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnvError`] if the variable is not set, is not valid
+    /// Unicode, or is not a valid ENID.
+    #[inline]
+    pub fn from_env(var: &str) -> Result<Self, EnvError> {
+        from_env(var)
+    }
+}
@@ -0,0 +1,173 @@
+use crate::clock::EnidClock;
+use crate::enid::{Enid80, EnidParseError};
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+
+/// An 80-bit ENID that embeds the time at which it expires.
+///
+/// The first 32 bits hold the expiry time in seconds since the Unix epoch, as a
+/// big-endian integer, and the remaining 48 bits hold an arbitrary payload. This
+/// allows the validity of cache keys and signed links to be checked from the ID
+/// alone.
+///
+/// The expiry time is not protected against tampering, so it should only be
+/// trusted if the ID itself is authenticated.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::ExpiringEnid80;
+/// # use enid::clock::FixedClock;
+/// let enid = ExpiringEnid80::new(1_700_000_000, [0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0xf6]);
+///
+/// assert_eq!(enid.expires_at(), 1_700_000_000);
+/// assert_eq!(enid.is_expired(&FixedClock(1_699_999_999_999)), false);
+/// assert_eq!(enid.is_expired(&FixedClock(1_700_000_000_000)), true);
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct ExpiringEnid80(Enid80);
+
+impl ExpiringEnid80 {
+    /// Creates an ENID that expires at the given time, in seconds since the Unix
+    /// epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::ExpiringEnid80;
+    /// let enid = ExpiringEnid80::new(1_700_000_000, [0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0xf6]);
+    ///
+    /// assert_eq!(enid.to_string(), "cn9z2051-pb1x9sfp");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn new(expires_at: u32, payload: [u8; 6]) -> Self {
+        let t = expires_at.to_be_bytes();
+        let p = payload;
+
+        Self(Enid80::from_bytes([
+            t[0], t[1], t[2], t[3], p[0], p[1], p[2], p[3], p[4], p[5],
+        ]))
+    }
+
+    /// Interprets the given ENID as an expiring ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, ExpiringEnid80};
+    /// let enid = ExpiringEnid80::from_enid(enid80!("cn9z2051-pb1x9sfp"));
+    ///
+    /// assert_eq!(enid.expires_at(), 1_700_000_000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_enid(enid: Enid80) -> Self {
+        Self(enid)
+    }
+
+    /// Returns the underlying ENID.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, ExpiringEnid80};
+    /// let enid = enid80!("cn9z2051-pb1x9sfp");
+    ///
+    /// assert_eq!(ExpiringEnid80::from_enid(enid).into_enid(), enid);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn into_enid(self) -> Enid80 {
+        self.0
+    }
+
+    /// Returns the time at which the ENID expires, in seconds since the Unix
+    /// epoch.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::ExpiringEnid80;
+    /// let enid = ExpiringEnid80::new(1_700_000_000, [0; 6]);
+    ///
+    /// assert_eq!(enid.expires_at(), 1_700_000_000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn expires_at(&self) -> u32 {
+        u32::from_be_bytes(*self.0.as_bytes().first_chunk().unwrap())
+    }
+
+    /// Returns the payload stored after the expiry time.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::ExpiringEnid80;
+    /// let enid = ExpiringEnid80::new(1_700_000_000, [0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0xf6]);
+    ///
+    /// assert_eq!(enid.payload(), [0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0xf6]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn payload(&self) -> [u8; 6] {
+        *self.0.as_bytes().last_chunk().unwrap()
+    }
+
+    /// Returns `true` if the expiry time has been reached according to the given
+    /// clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::ExpiringEnid80;
+    /// # use enid::clock::FixedClock;
+    /// let enid = ExpiringEnid80::new(1_700_000_000, [0; 6]);
+    ///
+    /// assert_eq!(enid.is_expired(&FixedClock(1_699_999_999_999)), false);
+    /// assert_eq!(enid.is_expired(&FixedClock(1_700_000_000_000)), true);
+    /// ```
+    #[must_use]
+    pub fn is_expired<C: EnidClock + ?Sized>(&self, clock: &C) -> bool {
+        clock.now_millis() / 1000 >= u64::from(self.expires_at())
+    }
+}
+
+impl Debug for ExpiringEnid80 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for ExpiringEnid80 {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl FromStr for ExpiringEnid80 {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Enid80::from_str(s).map(Self)
+    }
+}
+
+impl From<Enid80> for ExpiringEnid80 {
+    #[inline]
+    fn from(enid: Enid80) -> Self {
+        Self::from_enid(enid)
+    }
+}
+
+impl From<ExpiringEnid80> for Enid80 {
+    #[inline]
+    fn from(enid: ExpiringEnid80) -> Self {
+        enid.into_enid()
+    }
+}
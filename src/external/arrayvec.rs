@@ -0,0 +1,87 @@
+#![cfg(feature = "arrayvec")]
+
+use crate::ascii;
+use crate::enid::{Enid, Enid40, Enid80};
+use arrayvec::ArrayString;
+
+impl Enid40 {
+    /// Returns the string form of the ENID in an [`ArrayString`].
+    ///
+    /// This requires the `arrayvec` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let s = enid40!("m6sc7n75").to_array_string();
+    ///
+    /// assert_eq!(s.as_str(), "m6sc7n75");
+    /// assert_eq!(s.capacity(), 8);
+    /// ```
+    #[must_use]
+    pub fn to_array_string(&self) -> ArrayString<8> {
+        let mut s = ArrayString::new();
+        s.push_str(self.write_to_buffer(&mut [0; 8]));
+        s
+    }
+}
+
+impl Enid80 {
+    /// Returns the string form of the ENID in an [`ArrayString`].
+    ///
+    /// This requires the `arrayvec` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// assert_eq!(enid80!("y3gx5gxm-mpb8ey39").to_array_string().as_str(), "y3gx5gxm-mpb8ey39");
+    /// ```
+    #[must_use]
+    pub fn to_array_string(&self) -> ArrayString<17> {
+        let mut s = ArrayString::new();
+        s.push_str(self.write_to_buffer(&mut [0; 17]));
+        s
+    }
+}
+
+impl Enid {
+    /// Returns the string form of the ENID in an [`ArrayString`] with room
+    /// for either size.
+    ///
+    /// This requires the `arrayvec` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// assert_eq!(enid!("m6sc7n75").to_array_string().as_str(), "m6sc7n75");
+    /// ```
+    #[must_use]
+    pub fn to_array_string(&self) -> ArrayString<17> {
+        let mut s = ArrayString::new();
+        s.push_str(ascii::to_str(self.write_ascii(&mut [0; 17])));
+        s
+    }
+}
+
+impl From<Enid40> for ArrayString<8> {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        enid.to_array_string()
+    }
+}
+
+impl From<Enid80> for ArrayString<17> {
+    #[inline]
+    fn from(enid: Enid80) -> Self {
+        enid.to_array_string()
+    }
+}
+
+impl From<Enid> for ArrayString<17> {
+    #[inline]
+    fn from(enid: Enid) -> Self {
+        enid.to_array_string()
+    }
+}
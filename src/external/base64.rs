@@ -0,0 +1,192 @@
+#![cfg(feature = "base64")]
+
+use crate::ascii;
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+
+/// Encodes the bytes as unpadded base64url. The buffer must be exactly the
+/// length of the encoded string.
+fn encode<'a>(bytes: &[u8], buf: &'a mut [u8]) -> &'a str {
+    let len = URL_SAFE_NO_PAD
+        .encode_slice(bytes, buf)
+        .expect("buffer is the length of the encoded string");
+    debug_assert_eq!(len, buf.len());
+
+    ascii::to_str(buf)
+}
+
+/// Decodes unpadded base64url into exactly `N` bytes. Non-canonical strings,
+/// where the unused bits of the last character are not zero, are rejected.
+fn decode<const N: usize>(s: &str) -> Result<[u8; N], EnidParseError> {
+    let mut bytes = [0; N];
+
+    match URL_SAFE_NO_PAD.decode_slice(s, &mut bytes) {
+        Ok(len) if len == N => Ok(bytes),
+        _ => Err(EnidParseError),
+    }
+}
+
+impl Enid40 {
+    /// Writes the ENID's bytes to the buffer in unpadded base64url encoding,
+    /// and returns the encoded string.
+    ///
+    /// This requires the `base64` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.write_base64url(&mut [0; 7]), "obLD1OU");
+    /// ```
+    #[inline]
+    pub fn write_base64url<'a>(&self, buf: &'a mut [u8; 7]) -> &'a str {
+        encode(self.as_bytes(), buf)
+    }
+
+    /// Returns the ENID's bytes in unpadded base64url encoding.
+    ///
+    /// This requires the `base64` and `alloc` features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.to_base64url(), "obLD1OU");
+    /// ```
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_base64url(&self) -> alloc::string::String {
+        self.write_base64url(&mut [0; 7]).into()
+    }
+
+    /// Attempts to create an ENID from an unpadded base64url string written
+    /// by [`write_base64url`](Self::write_base64url).
+    ///
+    /// This requires the `base64` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid40::parse_base64url("obLD1OU")?, enid40!("m6sc7n75"));
+    /// assert!(Enid40::parse_base64url("obLD1OV").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not 7 characters of
+    /// canonical, unpadded base64url.
+    #[inline]
+    pub fn parse_base64url(s: &str) -> Result<Self, EnidParseError> {
+        decode(s).map(Self::from_bytes)
+    }
+}
+
+impl Enid80 {
+    /// Writes the ENID's bytes to the buffer in unpadded base64url encoding,
+    /// and returns the encoded string.
+    ///
+    /// This requires the `base64` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.write_base64url(&mut [0; 14]), "8OHSw7Sllod4aQ");
+    /// ```
+    #[inline]
+    pub fn write_base64url<'a>(&self, buf: &'a mut [u8; 14]) -> &'a str {
+        encode(self.as_bytes(), buf)
+    }
+
+    /// Returns the ENID's bytes in unpadded base64url encoding.
+    ///
+    /// This requires the `base64` and `alloc` features.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_base64url(&self) -> alloc::string::String {
+        self.write_base64url(&mut [0; 14]).into()
+    }
+
+    /// Attempts to create an ENID from an unpadded base64url string written
+    /// by [`write_base64url`](Self::write_base64url).
+    ///
+    /// This requires the `base64` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid80::parse_base64url("8OHSw7Sllod4aQ")?, enid80!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not 14 characters of
+    /// canonical, unpadded base64url.
+    #[inline]
+    pub fn parse_base64url(s: &str) -> Result<Self, EnidParseError> {
+        decode(s).map(Self::from_bytes)
+    }
+}
+
+impl Enid {
+    /// Returns the ENID's bytes in unpadded base64url encoding.
+    ///
+    /// This requires the `base64` and `alloc` features.
+    #[cfg(feature = "alloc")]
+    #[must_use]
+    #[inline]
+    pub fn to_base64url(&self) -> alloc::string::String {
+        match self {
+            Enid::Enid40(enid) => enid.to_base64url(),
+            Enid::Enid80(enid) => enid.to_base64url(),
+        }
+    }
+
+    /// Attempts to create an ENID of either size from an unpadded base64url
+    /// string.
+    ///
+    /// The size is chosen by the length of the string: 7 characters for an
+    /// [`Enid40`] and 14 for an [`Enid80`].
+    ///
+    /// This requires the `base64` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// assert_eq!(Enid::parse_base64url("obLD1OU")?, enid!("m6sc7n75"));
+    /// assert_eq!(Enid::parse_base64url("8OHSw7Sllod4aQ")?, enid!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a base64url-encoded
+    /// ENID.
+    pub fn parse_base64url(s: &str) -> Result<Self, EnidParseError> {
+        if s.len() == 7 {
+            Enid40::parse_base64url(s).map(Self::Enid40)
+        } else {
+            Enid80::parse_base64url(s).map(Self::Enid80)
+        }
+    }
+}
@@ -2,18 +2,12 @@
 
 extern crate alloc;
 
-use crate::enid::{Enid, Enid40, Enid80};
+use crate::enid::{Enid, Enid40, Enid80, EnidN};
+use crate::{NonNilEnidN, OptionEnidN};
 use borsh::io::{Error, ErrorKind, Read, Result, Write};
 use borsh::{BorshDeserialize, BorshSerialize};
 
-impl BorshSerialize for Enid40 {
-    #[inline]
-    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
-        writer.write_all(self.as_bytes())
-    }
-}
-
-impl BorshSerialize for Enid80 {
+impl<const N: usize> BorshSerialize for EnidN<N> {
     #[inline]
     fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
         writer.write_all(self.as_bytes())
@@ -35,17 +29,10 @@ impl BorshSerialize for Enid {
     }
 }
 
-impl BorshDeserialize for Enid40 {
+impl<const N: usize> BorshDeserialize for EnidN<N> {
     #[inline]
     fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        <[u8; 5]>::deserialize_reader(reader).map(Self::from_bytes)
-    }
-}
-
-impl BorshDeserialize for Enid80 {
-    #[inline]
-    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
-        <[u8; 10]>::deserialize_reader(reader).map(Self::from_bytes)
+        <[u8; N]>::deserialize_reader(reader).map(Self::from_bytes)
     }
 }
 
@@ -61,3 +48,56 @@ impl BorshDeserialize for Enid {
         }
     }
 }
+
+impl<const N: usize> BorshSerialize for NonNilEnidN<N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.get().serialize(writer)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for NonNilEnidN<N> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        Self::new(EnidN::deserialize_reader(reader)?)
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "ENID is filled with zeros"))
+    }
+}
+
+impl<const N: usize> BorshSerialize for OptionEnidN<N> {
+    #[inline]
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.to_enid().serialize(writer)
+    }
+}
+
+impl<const N: usize> BorshDeserialize for OptionEnidN<N> {
+    #[inline]
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        EnidN::deserialize_reader(reader).map(Self::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Enid, Enid120, Enid160, enid};
+
+    #[test]
+    fn round_trip() {
+        let enid120 = Enid120::from_bytes(core::array::from_fn(|i| i as u8));
+        let enid160 = Enid160::from_bytes(core::array::from_fn(|i| 0xff - i as u8));
+        let enid = enid!("y3gx5gxm-mpb8ey39");
+
+        let bytes = borsh::to_vec(&enid120).unwrap();
+        assert_eq!(bytes, enid120.as_bytes());
+        assert_eq!(borsh::from_slice::<Enid120>(&bytes).unwrap(), enid120);
+
+        let bytes = borsh::to_vec(&enid160).unwrap();
+        assert_eq!(bytes, enid160.as_bytes());
+        assert_eq!(borsh::from_slice::<Enid160>(&bytes).unwrap(), enid160);
+        assert!(borsh::from_slice::<Enid120>(&bytes).is_err());
+
+        let bytes = borsh::to_vec(&enid).unwrap();
+        assert_eq!(bytes[0], 1);
+        assert_eq!(borsh::from_slice::<Enid>(&bytes).unwrap(), enid);
+    }
+}
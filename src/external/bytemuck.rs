@@ -1,7 +1,11 @@
 #![cfg(feature = "bytemuck")]
+// These traits can only be implemented with `unsafe`, even in `safe` mode.
+#![allow(unsafe_code)]
 
 use crate::enid::{Enid40, Enid80};
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
 
 unsafe impl Zeroable for Enid40 {}
 
@@ -14,3 +18,142 @@ unsafe impl Pod for Enid80 {}
 unsafe impl TransparentWrapper<[u8; 5]> for Enid40 {}
 
 unsafe impl TransparentWrapper<[u8; 10]> for Enid80 {}
+
+/// An error returned when a byte slice cannot be viewed as a slice of ENIDs,
+/// because its length is not a multiple of the size of an ENID.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EnidSliceError {
+    len: usize,
+    enid_size: usize,
+}
+
+impl EnidSliceError {
+    /// Returns the length of the byte slice.
+    #[must_use]
+    #[inline]
+    pub const fn byte_len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the size of each ENID in bytes.
+    #[must_use]
+    #[inline]
+    pub const fn enid_size(&self) -> usize {
+        self.enid_size
+    }
+}
+
+impl Display for EnidSliceError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(
+            f,
+            "byte slice of length {} is not a multiple of {}",
+            self.len, self.enid_size
+        )
+    }
+}
+
+impl Error for EnidSliceError {}
+
+fn check_len<const N: usize>(bytes: &[u8]) -> Result<(), EnidSliceError> {
+    if bytes.len() % N == 0 {
+        Ok(())
+    } else {
+        Err(EnidSliceError {
+            len: bytes.len(),
+            enid_size: N,
+        })
+    }
+}
+
+impl Enid40 {
+    /// Views a slice of packed bytes as a slice of ENIDs, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let bytes = [0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0, 0, 0, 0, 0];
+    /// let enids = Enid40::slice_from_bytes(&bytes)?;
+    ///
+    /// assert_eq!(enids, [enid40!("m6sc7n75"), Enid40::ZERO]);
+    /// assert!(Enid40::slice_from_bytes(&bytes[1..]).is_err());
+    /// # Ok::<(), enid::EnidSliceError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidSliceError`] if the length of the slice is not a
+    /// multiple of 5.
+    #[inline]
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], EnidSliceError> {
+        check_len::<5>(bytes)?;
+        Ok(bytemuck::cast_slice(bytes))
+    }
+
+    /// Views a mutable slice of packed bytes as a mutable slice of ENIDs,
+    /// without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidSliceError`] if the length of the slice is not a
+    /// multiple of 5.
+    #[inline]
+    pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Self], EnidSliceError> {
+        check_len::<5>(bytes)?;
+        Ok(bytemuck::cast_slice_mut(bytes))
+    }
+
+    /// Views a slice of ENIDs as a slice of packed bytes, without copying.
+    #[must_use]
+    #[inline]
+    pub fn slice_as_bytes(enids: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(enids)
+    }
+}
+
+impl Enid80 {
+    /// Views a slice of packed bytes as a slice of ENIDs, without copying.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// let bytes = [0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69];
+    /// let enids = Enid80::slice_from_bytes(&bytes)?;
+    ///
+    /// assert_eq!(enids, [enid80!("y3gx5gxm-mpb8ey39")]);
+    /// assert_eq!(Enid80::slice_as_bytes(enids), bytes);
+    /// # Ok::<(), enid::EnidSliceError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidSliceError`] if the length of the slice is not a
+    /// multiple of 10.
+    #[inline]
+    pub fn slice_from_bytes(bytes: &[u8]) -> Result<&[Self], EnidSliceError> {
+        check_len::<10>(bytes)?;
+        Ok(bytemuck::cast_slice(bytes))
+    }
+
+    /// Views a mutable slice of packed bytes as a mutable slice of ENIDs,
+    /// without copying.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidSliceError`] if the length of the slice is not a
+    /// multiple of 10.
+    #[inline]
+    pub fn slice_from_bytes_mut(bytes: &mut [u8]) -> Result<&mut [Self], EnidSliceError> {
+        check_len::<10>(bytes)?;
+        Ok(bytemuck::cast_slice_mut(bytes))
+    }
+
+    /// Views a slice of ENIDs as a slice of packed bytes, without copying.
+    #[must_use]
+    #[inline]
+    pub fn slice_as_bytes(enids: &[Self]) -> &[u8] {
+        bytemuck::cast_slice(enids)
+    }
+}
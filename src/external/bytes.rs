@@ -0,0 +1,65 @@
+#![cfg(feature = "bytes")]
+
+use crate::enid::EnidN;
+use bytes::{Buf, BufMut, Bytes};
+
+impl<const N: usize> EnidN<N> {
+    /// Reads the bytes of an ENID from the front of a buffer, advancing it.
+    ///
+    /// Returns `None`, without advancing the buffer, if fewer than `N` bytes
+    /// remain.
+    ///
+    /// This requires the `bytes` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let mut buf: &[u8] = &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5, 0xff];
+    ///
+    /// assert_eq!(Enid40::get_from(&mut buf), Some(enid40!("m6sc7n75")));
+    /// assert_eq!(Enid40::get_from(&mut buf), None);
+    /// assert_eq!(buf, [0xff]);
+    /// ```
+    #[must_use]
+    pub fn get_from<B: Buf + ?Sized>(buf: &mut B) -> Option<Self> {
+        if buf.remaining() < N {
+            return None;
+        }
+
+        let mut bytes = [0; N];
+        buf.copy_to_slice(&mut bytes);
+
+        Some(Self::from_bytes(bytes))
+    }
+
+    /// Writes the bytes of the ENID to the end of a buffer.
+    ///
+    /// This requires the `bytes` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let mut buf = Vec::new();
+    /// enid40!("m6sc7n75").put_into(&mut buf);
+    ///
+    /// assert_eq!(buf, [0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer does not have room for `N` bytes, as
+    /// [`BufMut::put_slice`] does.
+    #[inline]
+    pub fn put_into<B: BufMut + ?Sized>(&self, buf: &mut B) {
+        buf.put_slice(self.as_bytes());
+    }
+}
+
+impl<const N: usize> From<EnidN<N>> for Bytes {
+    #[inline]
+    fn from(enid: EnidN<N>) -> Self {
+        Bytes::copy_from_slice(enid.as_bytes())
+    }
+}
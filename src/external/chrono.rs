@@ -0,0 +1,91 @@
+#![cfg(feature = "chrono")]
+
+use crate::enid::Enid80;
+use chrono::{DateTime, Utc};
+
+const MAX_MILLIS: i64 = (1 << 48) - 1;
+
+impl Enid80 {
+    /// Creates a time-ordered ENID from a date and time and 32 random bits,
+    /// using the layout described in [`TimeOrdered`](crate::TimeOrdered).
+    ///
+    /// The time is truncated to the millisecond. This is useful for
+    /// backfilling historical records with IDs that sort in the order the
+    /// records were created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use chrono::DateTime;
+    /// let created = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+    /// let enid = Enid80::from_datetime(created, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid, Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is before the Unix epoch or after the year 10889.
+    #[must_use]
+    #[track_caller]
+    pub fn from_datetime(datetime: DateTime<Utc>, random: [u8; 4]) -> Self {
+        let millis = datetime.timestamp_millis();
+        assert!(
+            (0..=MAX_MILLIS).contains(&millis),
+            "time is out of range for a time-ordered ENID"
+        );
+
+        Self::from_timestamp_millis(millis as u64, random)
+    }
+
+    /// Creates a time-ordered ENID from a date and time, with random bytes
+    /// from the operating system's random number generator.
+    ///
+    /// This requires the `getrandom` feature. See
+    /// [`from_datetime`](Self::from_datetime) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use chrono::DateTime;
+    /// let earlier = DateTime::from_timestamp_millis(1_700_000_000_000).unwrap();
+    /// let later = DateTime::from_timestamp_millis(1_700_000_000_001).unwrap();
+    ///
+    /// assert!(Enid80::from_datetime_random(earlier) < Enid80::from_datetime_random(later));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is out of range, or if the random number generator
+    /// fails.
+    #[cfg(feature = "getrandom")]
+    #[must_use]
+    #[track_caller]
+    pub fn from_datetime_random(datetime: DateTime<Utc>) -> Self {
+        let mut random = [0; 4];
+        getrandom::fill(&mut random).expect("failed to generate random ENID");
+
+        Self::from_datetime(datetime, random)
+    }
+
+    /// Returns the timestamp stored in a time-ordered ENID as a date and time.
+    ///
+    /// See [`timestamp_millis`](Self::timestamp_millis) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let enid = Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid.datetime().timestamp(), 1_700_000_000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn datetime(&self) -> DateTime<Utc> {
+        // 48 bits of milliseconds are always within chrono's range.
+        DateTime::from_timestamp_millis(self.timestamp_millis() as i64).unwrap_or_default()
+    }
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "digest")]
+
+use crate::enid::{Enid40, Enid80};
+use digest::Digest;
+
+impl Enid40 {
+    /// Creates an ENID from the output of a hash function, truncated to 5
+    /// bytes.
+    ///
+    /// Any hasher implementing [`Digest`] can be used, such as those in the
+    /// `sha2` and `sha3` crates, or `blake3` with its `traits-preview` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid40;
+    /// # use sha2::{Digest, Sha256};
+    /// let enid = Enid40::from_digest(Sha256::new().chain_update(b"hello world"));
+    ///
+    /// assert_eq!(enid.as_bytes(), &[0xb9, 0x4d, 0x27, 0xb9, 0x93]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hash function's output is shorter than 5 bytes.
+    #[must_use]
+    pub fn from_digest<D: Digest>(hasher: D) -> Self {
+        let output = hasher.finalize();
+        let bytes = output
+            .first_chunk()
+            .expect("digest output is shorter than an ENID");
+
+        Self::from_bytes(*bytes)
+    }
+}
+
+impl Enid80 {
+    /// Creates an ENID from the output of a hash function, truncated to 10
+    /// bytes.
+    ///
+    /// Any hasher implementing [`Digest`] can be used, such as those in the
+    /// `sha2` and `sha3` crates, or `blake3` with its `traits-preview` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use sha2::{Digest, Sha256};
+    /// let enid = Enid80::from_digest(Sha256::new().chain_update(b"hello world"));
+    ///
+    /// assert_eq!(enid.to_string(), "q56jfeck-9mz0h99e");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the hash function's output is shorter than 10 bytes.
+    #[must_use]
+    pub fn from_digest<D: Digest>(hasher: D) -> Self {
+        let output = hasher.finalize();
+        let bytes = output
+            .first_chunk()
+            .expect("digest output is shorter than an ENID");
+
+        Self::from_bytes(*bytes)
+    }
+}
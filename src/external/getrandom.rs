@@ -0,0 +1,75 @@
+#![cfg(feature = "getrandom")]
+
+use crate::enid::{Enid40, Enid80};
+
+impl Enid40 {
+    /// Generates a random ENID using the operating system's random number
+    /// generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid40;
+    /// assert_ne!(Enid40::new(), Enid40::new());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number generator fails. See
+    /// [`try_new`](Self::try_new) for a version that returns an error instead.
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub fn new() -> Self {
+        Self::try_new().expect("failed to generate random ENID")
+    }
+
+    /// Generates a random ENID using the operating system's random number
+    /// generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the random number generator fails.
+    #[inline]
+    pub fn try_new() -> Result<Self, getrandom::Error> {
+        let mut bytes = [0; 5];
+        getrandom::fill(&mut bytes)?;
+        Ok(Self::from_bytes(bytes))
+    }
+}
+
+impl Enid80 {
+    /// Generates a random ENID using the operating system's random number
+    /// generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// assert_ne!(Enid80::new(), Enid80::new());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number generator fails. See
+    /// [`try_new`](Self::try_new) for a version that returns an error instead.
+    #[must_use]
+    #[inline]
+    #[track_caller]
+    pub fn new() -> Self {
+        Self::try_new().expect("failed to generate random ENID")
+    }
+
+    /// Generates a random ENID using the operating system's random number
+    /// generator.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the random number generator fails.
+    #[inline]
+    pub fn try_new() -> Result<Self, getrandom::Error> {
+        let mut bytes = [0; 10];
+        getrandom::fill(&mut bytes)?;
+        Ok(Self::from_bytes(bytes))
+    }
+}
@@ -0,0 +1,141 @@
+#![cfg(feature = "heapless")]
+
+use crate::ascii;
+use crate::enid::{BufferTooSmallError, Enid, Enid40, Enid80, EnidParseError};
+use heapless::String;
+
+/// Copies a string into a `heapless::String` with a capacity of `M`.
+fn to_heapless<const M: usize>(s: &str) -> Result<String<M>, BufferTooSmallError> {
+    let mut string = String::new();
+
+    match string.push_str(s) {
+        Ok(()) => Ok(string),
+        Err(_) => Err(BufferTooSmallError::new(s.len(), M)),
+    }
+}
+
+impl Enid40 {
+    /// Returns the string form of the ENID in a [`heapless::String`] with a
+    /// capacity of `M`, such as a fixed-size message buffer.
+    ///
+    /// This requires the `heapless` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let s = enid40!("m6sc7n75").to_heapless::<32>()?;
+    ///
+    /// assert_eq!(s, "m6sc7n75");
+    /// assert!(enid40!("m6sc7n75").to_heapless::<4>().is_err());
+    /// # Ok::<(), enid::BufferTooSmallError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferTooSmallError`] if `M` is less than 8.
+    #[inline]
+    pub fn to_heapless<const M: usize>(&self) -> Result<String<M>, BufferTooSmallError> {
+        to_heapless(self.write_to_buffer(&mut [0; 8]))
+    }
+}
+
+impl Enid80 {
+    /// Returns the string form of the ENID in a [`heapless::String`] with a
+    /// capacity of `M`, such as a fixed-size message buffer.
+    ///
+    /// This requires the `heapless` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let s = enid80!("y3gx5gxm-mpb8ey39").to_heapless::<32>()?;
+    ///
+    /// assert_eq!(s, "y3gx5gxm-mpb8ey39");
+    /// # Ok::<(), enid::BufferTooSmallError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferTooSmallError`] if `M` is less than 17.
+    #[inline]
+    pub fn to_heapless<const M: usize>(&self) -> Result<String<M>, BufferTooSmallError> {
+        to_heapless(self.write_to_buffer(&mut [0; 17]))
+    }
+}
+
+impl Enid {
+    /// Returns the string form of the ENID in a [`heapless::String`] with a
+    /// capacity of `M`, such as a fixed-size message buffer.
+    ///
+    /// This requires the `heapless` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid;
+    /// let s = enid!("m6sc7n75").to_heapless::<8>()?;
+    ///
+    /// assert_eq!(s, "m6sc7n75");
+    /// assert!(enid!("y3gx5gxm-mpb8ey39").to_heapless::<8>().is_err());
+    /// # Ok::<(), enid::BufferTooSmallError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`BufferTooSmallError`] if `M` is less than the length of
+    /// the string form of the ENID.
+    #[inline]
+    pub fn to_heapless<const M: usize>(&self) -> Result<String<M>, BufferTooSmallError> {
+        to_heapless(ascii::to_str(self.write_ascii(&mut [0; 17])))
+    }
+}
+
+impl From<Enid40> for String<8> {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        enid.to_heapless().unwrap()
+    }
+}
+
+impl From<Enid80> for String<17> {
+    #[inline]
+    fn from(enid: Enid80) -> Self {
+        enid.to_heapless().unwrap()
+    }
+}
+
+impl From<Enid> for String<17> {
+    #[inline]
+    fn from(enid: Enid) -> Self {
+        enid.to_heapless().unwrap()
+    }
+}
+
+impl<const M: usize> TryFrom<&String<M>> for Enid40 {
+    type Error = EnidParseError;
+
+    #[inline]
+    fn try_from(s: &String<M>) -> Result<Self, Self::Error> {
+        Self::parse_str(s)
+    }
+}
+
+impl<const M: usize> TryFrom<&String<M>> for Enid80 {
+    type Error = EnidParseError;
+
+    #[inline]
+    fn try_from(s: &String<M>) -> Result<Self, Self::Error> {
+        Self::parse_str(s)
+    }
+}
+
+impl<const M: usize> TryFrom<&String<M>> for Enid {
+    type Error = EnidParseError;
+
+    #[inline]
+    fn try_from(s: &String<M>) -> Result<Self, Self::Error> {
+        Self::parse_str(s)
+    }
+}
@@ -0,0 +1,81 @@
+#![cfg(feature = "jiff")]
+
+use crate::enid::Enid80;
+use jiff::Timestamp;
+
+const MAX_MILLIS: i64 = (1 << 48) - 1;
+
+impl Enid80 {
+    /// Creates a time-ordered ENID from a [`Timestamp`] and 32 random bits,
+    /// using the layout described in [`TimeOrdered`](crate::TimeOrdered).
+    ///
+    /// The timestamp is truncated to the millisecond. This is useful for
+    /// backfilling historical records with IDs that sort in the order the
+    /// records were created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use jiff::Timestamp;
+    /// let created = Timestamp::from_second(1_700_000_000).unwrap();
+    /// let enid = Enid80::from_jiff_timestamp(created, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid, Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp is before the Unix epoch.
+    #[must_use]
+    #[track_caller]
+    pub fn from_jiff_timestamp(timestamp: Timestamp, random: [u8; 4]) -> Self {
+        let millis = timestamp.as_millisecond();
+        assert!(
+            (0..=MAX_MILLIS).contains(&millis),
+            "time is out of range for a time-ordered ENID"
+        );
+
+        Self::from_timestamp_millis(millis as u64, random)
+    }
+
+    /// Creates a time-ordered ENID from a [`Timestamp`], with random bytes
+    /// from the operating system's random number generator.
+    ///
+    /// This requires the `getrandom` feature. See
+    /// [`from_jiff_timestamp`](Self::from_jiff_timestamp) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the timestamp is before the Unix epoch, or if the random
+    /// number generator fails.
+    #[cfg(feature = "getrandom")]
+    #[must_use]
+    #[track_caller]
+    pub fn from_jiff_timestamp_random(timestamp: Timestamp) -> Self {
+        let mut random = [0; 4];
+        getrandom::fill(&mut random).expect("failed to generate random ENID");
+
+        Self::from_jiff_timestamp(timestamp, random)
+    }
+
+    /// Returns the timestamp stored in a time-ordered ENID as a [`Timestamp`].
+    ///
+    /// `jiff` only supports times until the end of the year 9999, so later
+    /// times are clamped to [`Timestamp::MAX`]. See
+    /// [`timestamp_millis`](Self::timestamp_millis) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let enid = Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid.jiff_timestamp().to_string(), "2023-11-14T22:13:20Z");
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn jiff_timestamp(&self) -> Timestamp {
+        Timestamp::from_millisecond(self.timestamp_millis() as i64).unwrap_or(Timestamp::MAX)
+    }
+}
@@ -0,0 +1,126 @@
+#![cfg(feature = "js")]
+
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+use js_sys::Uint8Array;
+use wasm_bindgen::{JsCast, JsValue};
+
+impl From<Enid40> for JsValue {
+    fn from(enid: Enid40) -> Self {
+        JsValue::from_str(enid.write_to_buffer(&mut [0; 8]))
+    }
+}
+
+impl From<Enid80> for JsValue {
+    fn from(enid: Enid80) -> Self {
+        JsValue::from_str(enid.write_to_buffer(&mut [0; 17]))
+    }
+}
+
+impl From<Enid> for JsValue {
+    fn from(enid: Enid) -> Self {
+        match enid {
+            Enid::Enid40(enid) => enid.into(),
+            Enid::Enid80(enid) => enid.into(),
+        }
+    }
+}
+
+impl From<Enid40> for Uint8Array {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        Uint8Array::from(enid.as_bytes().as_slice())
+    }
+}
+
+impl From<Enid80> for Uint8Array {
+    #[inline]
+    fn from(enid: Enid80) -> Self {
+        Uint8Array::from(enid.as_bytes().as_slice())
+    }
+}
+
+impl From<Enid> for Uint8Array {
+    #[inline]
+    fn from(enid: Enid) -> Self {
+        Uint8Array::from(enid.as_bytes())
+    }
+}
+
+impl TryFrom<&JsValue> for Enid40 {
+    type Error = EnidParseError;
+
+    fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+        if let Some(s) = value.as_string() {
+            s.parse()
+        } else {
+            bytes_from_js(value).map(Self::from_bytes)
+        }
+    }
+}
+
+impl TryFrom<&JsValue> for Enid80 {
+    type Error = EnidParseError;
+
+    fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+        if let Some(s) = value.as_string() {
+            s.parse()
+        } else {
+            bytes_from_js(value).map(Self::from_bytes)
+        }
+    }
+}
+
+impl TryFrom<&JsValue> for Enid {
+    type Error = EnidParseError;
+
+    fn try_from(value: &JsValue) -> Result<Self, Self::Error> {
+        if let Some(s) = value.as_string() {
+            return s.parse();
+        }
+
+        match value.dyn_ref::<Uint8Array>().map(Uint8Array::length) {
+            Some(5) => Enid40::try_from(value).map(Self::Enid40),
+            _ => Enid80::try_from(value).map(Self::Enid80),
+        }
+    }
+}
+
+impl TryFrom<JsValue> for Enid40 {
+    type Error = EnidParseError;
+
+    #[inline]
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<JsValue> for Enid80 {
+    type Error = EnidParseError;
+
+    #[inline]
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+impl TryFrom<JsValue> for Enid {
+    type Error = EnidParseError;
+
+    #[inline]
+    fn try_from(value: JsValue) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+fn bytes_from_js<const N: usize>(value: &JsValue) -> Result<[u8; N], EnidParseError> {
+    let array = value.dyn_ref::<Uint8Array>().ok_or(EnidParseError)?;
+
+    if array.length() as usize != N {
+        return Err(EnidParseError);
+    }
+
+    let mut bytes = [0; N];
+    array.copy_to(&mut bytes);
+
+    Ok(bytes)
+}
@@ -1,6 +1,26 @@
 mod arbitrary;
+mod arrayvec;
+mod base64;
 mod borsh;
 mod bytemuck;
+mod bytes;
+mod chrono;
+mod digest;
+mod getrandom;
+mod heapless;
+mod jiff;
+mod js;
 mod quickcheck;
+mod rand;
+mod sea_query;
 mod serde;
+mod sha2;
 mod slog;
+mod time;
+mod unicode_normalization;
+mod zvariant;
+
+#[cfg(feature = "bytemuck")]
+pub use self::bytemuck::EnidSliceError;
+#[cfg(feature = "serde")]
+pub(crate) use self::serde::seq_bytes;
@@ -1,39 +1,18 @@
 #![cfg(feature = "quickcheck")]
 
 use crate::enid::{Enid, Enid40, Enid80};
-use core::mem::{self, MaybeUninit};
+use core::array;
 use quickcheck::{Arbitrary, Gen};
 
-// TODO: Use `MaybeUninit::array_assume_init()` once it's stable.
-// https://github.com/rust-lang/rust/issues/96097
-
 impl Arbitrary for Enid40 {
     fn arbitrary(g: &mut Gen) -> Self {
-        let mut bytes = [const { MaybeUninit::uninit() }; 5];
-
-        for byte in &mut bytes {
-            byte.write(u8::arbitrary(g));
-        }
-
-        // SAFETY: All elements have been initialised.
-        let bytes = unsafe { mem::transmute::<[MaybeUninit<u8>; 5], [u8; 5]>(bytes) };
-
-        Self::from_bytes(bytes)
+        Self::from_bytes(array::from_fn(|_| u8::arbitrary(g)))
     }
 }
 
 impl Arbitrary for Enid80 {
     fn arbitrary(g: &mut Gen) -> Self {
-        let mut bytes = [const { MaybeUninit::uninit() }; 10];
-
-        for byte in &mut bytes {
-            byte.write(u8::arbitrary(g));
-        }
-
-        // SAFETY: All elements have been initialised.
-        let bytes = unsafe { mem::transmute::<[MaybeUninit<u8>; 10], [u8; 10]>(bytes) };
-
-        Self::from_bytes(bytes)
+        Self::from_bytes(array::from_fn(|_| u8::arbitrary(g)))
     }
 }
 
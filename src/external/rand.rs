@@ -0,0 +1,273 @@
+#![cfg(feature = "rand")]
+
+use crate::enid::{Enid, Enid40, Enid80};
+use core::ops::RangeInclusive;
+use rand::distr::{Distribution, StandardUniform};
+use rand::{Rng, RngExt};
+
+// The number of ENIDs filled from each batch of random bytes.
+const FILL_BATCH: usize = 64;
+
+impl Distribution<Enid40> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Enid40 {
+        Enid40::from_bytes(rng.random())
+    }
+}
+
+impl Distribution<Enid80> for StandardUniform {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Enid80 {
+        Enid80::from_bytes(rng.random())
+    }
+}
+
+impl Enid40 {
+    /// Generates a random ENID using the thread-local random number generator.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid40;
+    /// let enid = Enid40::random();
+    ///
+    /// assert_ne!(enid, Enid40::random());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn random() -> Self {
+        rand::random()
+    }
+
+    /// Generates a random ENID using the given random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid40;
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::SmallRng;
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    ///
+    /// assert_ne!(Enid40::random_with(&mut rng), Enid40::random_with(&mut rng));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.random()
+    }
+
+    /// Fills a slice with random ENIDs using the given random number generator.
+    ///
+    /// The random bytes are drawn in batches, which is faster than generating
+    /// each ENID separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid40;
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::SmallRng;
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let mut enids = [Enid40::ZERO; 100];
+    ///
+    /// Enid40::fill(&mut enids, &mut rng);
+    ///
+    /// assert!(enids.iter().all(|enid| !enid.is_zero()));
+    /// ```
+    pub fn fill<R: Rng + ?Sized>(enids: &mut [Self], rng: &mut R) {
+        let mut buf = [0; FILL_BATCH * 5];
+
+        for chunk in enids.chunks_mut(FILL_BATCH) {
+            let buf = &mut buf[..chunk.len() * 5];
+            rng.fill_bytes(buf);
+
+            for (enid, bytes) in chunk.iter_mut().zip(buf.chunks_exact(5)) {
+                *enid = Self::from_bytes(bytes.try_into().unwrap());
+            }
+        }
+    }
+
+    /// Generates a random ENID that is uniformly distributed within the given
+    /// range.
+    ///
+    /// ENIDs are ordered as big-endian integers, matching the [`Ord`]
+    /// implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::SmallRng;
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let range = enid40!("m0000000")..=enid40!("mzzzzzzz");
+    ///
+    /// let enid = Enid40::random_in(range.clone(), &mut rng);
+    ///
+    /// assert!(range.contains(&enid));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    #[must_use]
+    pub fn random_in<R: Rng + ?Sized>(range: RangeInclusive<Self>, rng: &mut R) -> Self {
+        let (lo, hi) = range.into_inner();
+
+        let value = rng.random_range(lo.to_u64()..=hi.to_u64());
+
+        Self::from_u64(value).unwrap()
+    }
+}
+
+impl Enid80 {
+    /// Generates a random ENID using the thread-local random number generator.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let enid = Enid80::random();
+    ///
+    /// assert_ne!(enid, Enid80::random());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn random() -> Self {
+        rand::random()
+    }
+
+    /// Generates a random ENID using the given random number generator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::SmallRng;
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    ///
+    /// assert_ne!(Enid80::random_with(&mut rng), Enid80::random_with(&mut rng));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn random_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        rng.random()
+    }
+
+    /// Fills a slice with random ENIDs using the given random number generator.
+    ///
+    /// The random bytes are drawn in batches, which is faster than generating
+    /// each ENID separately.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::SmallRng;
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let mut enids = [Enid80::ZERO; 100];
+    ///
+    /// Enid80::fill(&mut enids, &mut rng);
+    ///
+    /// assert!(enids.iter().all(|enid| !enid.is_zero()));
+    /// ```
+    pub fn fill<R: Rng + ?Sized>(enids: &mut [Self], rng: &mut R) {
+        let mut buf = [0; FILL_BATCH * 10];
+
+        for chunk in enids.chunks_mut(FILL_BATCH) {
+            let buf = &mut buf[..chunk.len() * 10];
+            rng.fill_bytes(buf);
+
+            for (enid, bytes) in chunk.iter_mut().zip(buf.chunks_exact(10)) {
+                *enid = Self::from_bytes(bytes.try_into().unwrap());
+            }
+        }
+    }
+
+    /// Generates a random ENID that is uniformly distributed within the given
+    /// range.
+    ///
+    /// ENIDs are ordered as big-endian integers, matching the [`Ord`]
+    /// implementation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// # use rand::SeedableRng;
+    /// # use rand::rngs::SmallRng;
+    /// let mut rng = SmallRng::seed_from_u64(42);
+    /// let range = enid80!("y3gx5gxm-00000000")..=enid80!("y3gx5gxm-zzzzzzzz");
+    ///
+    /// let enid = Enid80::random_in(range.clone(), &mut rng);
+    ///
+    /// assert!(range.contains(&enid));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is empty.
+    #[must_use]
+    pub fn random_in<R: Rng + ?Sized>(range: RangeInclusive<Self>, rng: &mut R) -> Self {
+        let (lo, hi) = range.into_inner();
+
+        let value = rng.random_range(lo.to_u128()..=hi.to_u128());
+
+        Self::from_u128(value).unwrap()
+    }
+}
+
+impl Enid {
+    /// Generates a random 40-bit ENID using the thread-local random number
+    /// generator.
+    ///
+    /// This requires the `std` feature.
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn random_40() -> Self {
+        Self::Enid40(Enid40::random())
+    }
+
+    /// Generates a random 80-bit ENID using the thread-local random number
+    /// generator.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid;
+    /// assert!(Enid::random_40().is_enid40());
+    /// assert!(Enid::random_80().is_enid80());
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn random_80() -> Self {
+        Self::Enid80(Enid80::random())
+    }
+
+    /// Generates a random 40-bit ENID using the given random number generator.
+    #[must_use]
+    #[inline]
+    pub fn random_40_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::Enid40(Enid40::random_with(rng))
+    }
+
+    /// Generates a random 80-bit ENID using the given random number generator.
+    #[must_use]
+    #[inline]
+    pub fn random_80_with<R: Rng + ?Sized>(rng: &mut R) -> Self {
+        Self::Enid80(Enid80::random_with(rng))
+    }
+}
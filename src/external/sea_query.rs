@@ -0,0 +1,122 @@
+#![cfg(feature = "sea-query")]
+
+extern crate alloc;
+
+use crate::enid::{Enid, Enid40, Enid80};
+use alloc::borrow::ToOwned;
+use alloc::boxed::Box;
+use alloc::string::String;
+use sea_query::{ArrayType, ColumnType, Nullable, StringLen, Value, ValueType, ValueTypeErr};
+
+impl From<Enid40> for Value {
+    #[inline]
+    fn from(enid: Enid40) -> Self {
+        Value::Bytes(Some(Box::new(enid.as_bytes().to_vec())))
+    }
+}
+
+impl From<Enid80> for Value {
+    #[inline]
+    fn from(enid: Enid80) -> Self {
+        Value::Bytes(Some(Box::new(enid.as_bytes().to_vec())))
+    }
+}
+
+impl From<Enid> for Value {
+    #[inline]
+    fn from(enid: Enid) -> Self {
+        Value::Bytes(Some(Box::new(enid.as_bytes().to_vec())))
+    }
+}
+
+impl Nullable for Enid40 {
+    #[inline]
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+impl Nullable for Enid80 {
+    #[inline]
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+impl Nullable for Enid {
+    #[inline]
+    fn null() -> Value {
+        Value::Bytes(None)
+    }
+}
+
+impl ValueType for Enid40 {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bytes(Some(bytes)) => <[u8; 5]>::try_from(bytes.as_slice())
+                .map(Self::from_bytes)
+                .map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Enid40".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Binary(5)
+    }
+}
+
+impl ValueType for Enid80 {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bytes(Some(bytes)) => <[u8; 10]>::try_from(bytes.as_slice())
+                .map(Self::from_bytes)
+                .map_err(|_| ValueTypeErr),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Enid80".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::Binary(10)
+    }
+}
+
+impl ValueType for Enid {
+    fn try_from(v: Value) -> Result<Self, ValueTypeErr> {
+        match v {
+            Value::Bytes(Some(bytes)) => match bytes.len() {
+                5 => <Enid40 as ValueType>::try_from(Value::Bytes(Some(bytes))).map(Self::Enid40),
+                10 => <Enid80 as ValueType>::try_from(Value::Bytes(Some(bytes))).map(Self::Enid80),
+                _ => Err(ValueTypeErr),
+            },
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Enid".to_owned()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::Bytes
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::VarBinary(StringLen::N(10))
+    }
+}
@@ -1,24 +1,47 @@
 #![cfg(feature = "serde")]
 
-use crate::enid::{Enid, Enid40, Enid80};
+use crate::enid::{Enid, Enid40, Enid80, Enid160, EnidN};
+use crate::prefixed::{Prefix, Prefixed};
+use crate::{EnidString, NonNilEnidN, OptionEnidN};
 use core::fmt::{self, Formatter};
-use serde::de::{self, Unexpected, Visitor};
+use core::marker::PhantomData;
+use serde::de::{self, Expected, SeqAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-impl Serialize for Enid40 {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-        if serializer.is_human_readable() {
-            serializer.serialize_str(self.write_to_buffer(&mut [0; 8]))
-        } else {
-            serializer.serialize_bytes(self.as_bytes())
+/// Reads the elements of a sequence as bytes, for formats that represent byte
+/// arrays as sequences of integers. Sequences longer than `M` bytes are
+/// rejected without reading the remaining elements.
+pub(crate) fn seq_bytes<'de, A: SeqAccess<'de>, const M: usize>(
+    mut seq: A,
+    expected: &dyn Expected,
+) -> Result<([u8; M], usize), A::Error> {
+    let mut bytes = [0; M];
+    let mut len = 0;
+
+    while let Some(byte) = seq.next_element()? {
+        if len == bytes.len() {
+            return Err(de::Error::invalid_length(len + 1, expected));
         }
+
+        bytes[len] = byte;
+        len += 1;
     }
+
+    Ok((bytes, len))
 }
 
-impl Serialize for Enid80 {
+impl<const N: usize> Serialize for EnidN<N> {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         if serializer.is_human_readable() {
-            serializer.serialize_str(self.write_to_buffer(&mut [0; 17]))
+            // Strings up to the length of an `Enid160` are formatted on the
+            // stack; wider ENIDs are written through `Display`.
+            let mut buf = [0; Enid160::STR_LEN];
+
+            if Self::STR_LEN <= buf.len() {
+                serializer.serialize_str(self.write_to_buffer(&mut buf))
+            } else {
+                serializer.collect_str(self)
+            }
         } else {
             serializer.serialize_bytes(self.as_bytes())
         }
@@ -34,84 +57,55 @@ impl Serialize for Enid {
     }
 }
 
-impl<'de> Deserialize<'de> for Enid40 {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        if deserializer.is_human_readable() {
-            struct EnidVisitor;
+/// Deserializes an [`EnidN`] from its string form.
+struct StrVisitor<const N: usize>;
 
-            impl Visitor<'_> for EnidVisitor {
-                type Value = Enid40;
+impl<const N: usize> Visitor<'_> for StrVisitor<N> {
+    type Value = EnidN<N>;
 
-                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-                    f.write_str("a 40-bit ENID")
-                }
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "an ENID of {} bits", N * 8)
+    }
 
-                fn visit_str<E: de::Error>(self, s: &str) -> Result<Enid40, E> {
-                    s.parse()
-                        .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
-                }
-            }
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<EnidN<N>, E> {
+        s.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+    }
+}
 
-            deserializer.deserialize_str(EnidVisitor)
-        } else {
-            struct EnidVisitor;
+/// Deserializes an [`EnidN`] from its bytes, or from its string form in
+/// formats that write map keys as strings.
+struct BytesVisitor<const N: usize>;
 
-            impl Visitor<'_> for EnidVisitor {
-                type Value = Enid40;
+impl<'de, const N: usize> Visitor<'de> for BytesVisitor<N> {
+    type Value = EnidN<N>;
 
-                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-                    f.write_str("a byte array of length 5")
-                }
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "a byte array of length {N}")
+    }
 
-                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Enid40, E> {
-                    v.try_into()
-                        .map(Enid40::from_bytes)
-                        .map_err(|_| E::invalid_length(v.len(), &self))
-                }
-            }
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<EnidN<N>, E> {
+        v.try_into()
+            .map(EnidN::from_bytes)
+            .map_err(|_| E::invalid_length(v.len(), &self))
+    }
 
-            deserializer.deserialize_bytes(EnidVisitor)
-        }
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<EnidN<N>, A::Error> {
+        let (bytes, len) = seq_bytes::<A, N>(seq, &self)?;
+        self.visit_bytes(&bytes[..len])
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<EnidN<N>, E> {
+        StrVisitor.visit_str(s)
     }
 }
 
-impl<'de> Deserialize<'de> for Enid80 {
+impl<'de, const N: usize> Deserialize<'de> for EnidN<N> {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         if deserializer.is_human_readable() {
-            struct EnidVisitor;
-
-            impl Visitor<'_> for EnidVisitor {
-                type Value = Enid80;
-
-                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-                    f.write_str("an 80-bit ENID")
-                }
-
-                fn visit_str<E: de::Error>(self, s: &str) -> Result<Enid80, E> {
-                    s.parse()
-                        .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
-                }
-            }
-
-            deserializer.deserialize_str(EnidVisitor)
+            deserializer.deserialize_str(StrVisitor)
         } else {
-            struct EnidVisitor;
-
-            impl Visitor<'_> for EnidVisitor {
-                type Value = Enid80;
-
-                fn expecting(&self, f: &mut Formatter) -> fmt::Result {
-                    f.write_str("a byte array of length 10")
-                }
-
-                fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Enid80, E> {
-                    v.try_into()
-                        .map(Enid80::from_bytes)
-                        .map_err(|_| E::invalid_length(v.len(), &self))
-                }
-            }
-
-            deserializer.deserialize_bytes(EnidVisitor)
+            deserializer.deserialize_bytes(BytesVisitor)
         }
     }
 }
@@ -138,7 +132,7 @@ impl<'de> Deserialize<'de> for Enid {
         } else {
             struct EnidVisitor;
 
-            impl Visitor<'_> for EnidVisitor {
+            impl<'de> Visitor<'de> for EnidVisitor {
                 type Value = Enid;
 
                 fn expecting(&self, f: &mut Formatter) -> fmt::Result {
@@ -158,9 +152,232 @@ impl<'de> Deserialize<'de> for Enid {
                         n => Err(E::invalid_length(n, &self)),
                     }
                 }
+
+                fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<Enid, A::Error> {
+                    let (bytes, len) = seq_bytes::<A, 10>(seq, &self)?;
+                    self.visit_bytes(&bytes[..len])
+                }
+
+                fn visit_str<E: de::Error>(self, s: &str) -> Result<Enid, E> {
+                    s.parse()
+                        .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+                }
             }
 
             deserializer.deserialize_bytes(EnidVisitor)
         }
     }
 }
+
+impl<const N: usize> Serialize for NonNilEnidN<N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for NonNilEnidN<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::new(EnidN::deserialize(deserializer)?)
+            .ok_or_else(|| de::Error::custom("ENID is filled with zeros"))
+    }
+}
+
+impl<const N: usize> Serialize for OptionEnidN<N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.get().serialize(serializer)
+    }
+}
+
+impl<'de, const N: usize> Deserialize<'de> for OptionEnidN<N> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Option::<NonNilEnidN<N>>::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl Serialize for EnidString {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EnidString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EnidStringVisitor;
+
+        impl Visitor<'_> for EnidStringVisitor {
+            type Value = EnidString;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                f.write_str("an ENID")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<EnidString, E> {
+                s.parse()
+                    .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+            }
+        }
+
+        deserializer.deserialize_str(EnidStringVisitor)
+    }
+}
+
+impl<P: Prefix, const N: usize> Serialize for Prefixed<P, N> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de, P: Prefix + Default, const N: usize> Deserialize<'de> for Prefixed<P, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct PrefixedVisitor<P, const N: usize>(PhantomData<P>);
+
+        impl<P: Prefix + Default, const N: usize> Visitor<'_> for PrefixedVisitor<P, N> {
+            type Value = Prefixed<P, N>;
+
+            fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+                write!(
+                    f,
+                    "a prefixed ENID starting with `{}_`",
+                    P::default().as_str()
+                )
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<Prefixed<P, N>, E> {
+                s.parse()
+                    .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+            }
+        }
+
+        deserializer.deserialize_str(PrefixedVisitor(PhantomData))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Enid, Enid40, Enid120, Enid160, EnidN, enid, enid40};
+    use serde::de::value::{BorrowedBytesDeserializer, Error, MapDeserializer, SeqDeserializer};
+    use serde::de::{Deserialize, Deserializer, Visitor};
+    use serde::forward_to_deserialize_any;
+    use std::collections::{BTreeMap, HashMap};
+    use std::vec::Vec;
+
+    /// Wraps a deserializer to make it non-human-readable, like a binary format.
+    struct Binary<D>(D);
+
+    impl<'de, D: Deserializer<'de>> Deserializer<'de> for Binary<D> {
+        type Error = D::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, D::Error> {
+            self.0.deserialize_any(visitor)
+        }
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+            bytes byte_buf option unit unit_struct newtype_struct seq tuple
+            tuple_struct map struct enum identifier ignored_any
+        }
+    }
+
+    fn seq<'de, T: Deserialize<'de>>(bytes: &[u8]) -> Result<T, Error> {
+        let seq = SeqDeserializer::<_, Error>::new(bytes.iter().copied());
+        T::deserialize(Binary(seq))
+    }
+
+    fn borrowed<'de, T: Deserialize<'de>>(bytes: &'de [u8]) -> Result<T, Error> {
+        T::deserialize(Binary(BorrowedBytesDeserializer::<Error>::new(bytes)))
+    }
+
+    #[test]
+    fn round_trip() {
+        let enid120: Enid120 = "y3gx5gxm-mpb8ey39-04hmasw9".parse().unwrap();
+        let enid160: Enid160 = "y3gx5gxm-mpb8ey39-04hmasw9-nf6yy00h".parse().unwrap();
+
+        let json = serde_json::to_string(&enid120).unwrap();
+        assert_eq!(json, r#""y3gx5gxm-mpb8ey39-04hmasw9""#);
+        assert_eq!(serde_json::from_str::<Enid120>(&json).unwrap(), enid120);
+
+        let json = serde_json::to_string(&enid160).unwrap();
+        assert_eq!(json, r#""y3gx5gxm-mpb8ey39-04hmasw9-nf6yy00h""#);
+        assert_eq!(serde_json::from_str::<Enid160>(&json).unwrap(), enid160);
+
+        assert!(serde_json::from_str::<Enid120>(r#""y3gx5gxm-mpb8ey39""#).is_err());
+
+        assert_eq!(borrowed::<Enid120>(enid120.as_bytes()), Ok(enid120));
+        assert_eq!(borrowed::<Enid160>(enid160.as_bytes()), Ok(enid160));
+        assert!(borrowed::<Enid120>(enid160.as_bytes()).is_err());
+    }
+
+    #[test]
+    fn custom_width() {
+        // Wider than `Enid160`, so formatted through `Display`.
+        let enid = EnidN::<25>::from_bytes(core::array::from_fn(|i| i as u8 * 10));
+        let json = serde_json::to_string(&enid).unwrap();
+
+        assert_eq!(json, std::format!("\"{enid}\""));
+        assert_eq!(serde_json::from_str::<EnidN<25>>(&json).unwrap(), enid);
+        assert_eq!(seq::<EnidN<25>>(enid.as_bytes()), Ok(enid));
+        assert!(seq::<EnidN<25>>(&[0; 26]).is_err());
+    }
+
+    #[test]
+    fn binary_forms() {
+        let enid40 = enid40!("m6sc7n75");
+        let enid80 = enid!("y3gx5gxm-mpb8ey39");
+
+        assert_eq!(seq::<Enid40>(enid40.as_bytes()), Ok(enid40));
+        assert_eq!(seq::<Enid>(enid80.as_bytes()), Ok(enid80));
+        assert_eq!(borrowed::<Enid40>(enid40.as_bytes()), Ok(enid40));
+        assert_eq!(borrowed::<Enid>(enid80.as_bytes()), Ok(enid80));
+
+        let bytes: Vec<u8> = (0..20).collect();
+
+        assert_eq!(seq::<Enid160>(&bytes).unwrap().as_bytes(), &bytes[..]);
+        assert!(seq::<Enid160>(&[0; 21]).is_err());
+        assert!(seq::<Enid>(&[0; 7]).is_err());
+        assert!(seq::<Enid40>(&[0; 10]).is_err());
+    }
+
+    #[test]
+    fn map_keys() {
+        let enid40 = enid40!("m6sc7n75");
+        let enid80 = enid!("y3gx5gxm-mpb8ey39");
+
+        let map = HashMap::from([(enid40, 1)]);
+        let json = serde_json::to_string(&map).unwrap();
+
+        assert_eq!(json, r#"{"m6sc7n75":1}"#);
+        assert_eq!(
+            serde_json::from_str::<HashMap<Enid40, u32>>(&json).unwrap(),
+            map
+        );
+
+        let map = BTreeMap::from([(enid80, 2)]);
+        let json = serde_json::to_string(&map).unwrap();
+
+        assert_eq!(json, r#"{"y3gx5gxm-mpb8ey39":2}"#);
+        assert_eq!(
+            serde_json::from_str::<BTreeMap<Enid, u32>>(&json).unwrap(),
+            map
+        );
+
+        let entries = [("m6sc7n75", 1), ("y3gx5gxm-mpb8ey39", 2)];
+        let map = MapDeserializer::<_, Error>::new(entries.into_iter());
+        let map = BTreeMap::<Enid, u32>::deserialize(Binary(map)).unwrap();
+
+        assert_eq!(map, BTreeMap::from([(Enid::from(enid40), 1), (enid80, 2)]));
+
+        let entries = [("M6SC7N75", 1)];
+        let map = MapDeserializer::<_, Error>::new(entries.into_iter());
+
+        assert!(BTreeMap::<Enid40, u32>::deserialize(Binary(map)).is_err());
+    }
+}
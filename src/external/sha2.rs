@@ -0,0 +1,61 @@
+#![cfg(feature = "sha2")]
+
+use crate::enid::{Enid40, Enid80};
+use sha2::{Digest, Sha256};
+
+fn hash_name(namespace: &[u8], name: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(namespace)
+        .chain_update(name)
+        .finalize()
+        .into()
+}
+
+impl Enid40 {
+    /// Derives an ENID from a namespace and a name, like a version 5 UUID.
+    ///
+    /// The ENID is the first 5 bytes of the SHA-256 hash of the namespace's
+    /// bytes followed by the name. The same namespace and name always produce
+    /// the same ENID, so IDs can be assigned to externally keyed entities
+    /// without storing a mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let namespace = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(Enid40::from_name(namespace, b"alice"), Enid40::from_name(namespace, b"alice"));
+    /// assert_ne!(Enid40::from_name(namespace, b"alice"), Enid40::from_name(namespace, b"bob"));
+    /// ```
+    #[must_use]
+    pub fn from_name(namespace: Self, name: &[u8]) -> Self {
+        let hash = hash_name(namespace.as_bytes(), name);
+
+        Self::from_bytes(*hash.first_chunk().unwrap())
+    }
+}
+
+impl Enid80 {
+    /// Derives an ENID from a namespace and a name, like a version 5 UUID.
+    ///
+    /// The ENID is the first 10 bytes of the SHA-256 hash of the namespace's
+    /// bytes followed by the name. The same namespace and name always produce
+    /// the same ENID, so IDs can be assigned to externally keyed entities
+    /// without storing a mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// let namespace = enid80!("y3gx5gxm-kbhqr4fs");
+    ///
+    /// assert_eq!(Enid80::from_name(namespace, b"alice").to_string(), "9qm08p7j-2q3c2wrt");
+    /// ```
+    #[must_use]
+    pub fn from_name(namespace: Self, name: &[u8]) -> Self {
+        let hash = hash_name(namespace.as_bytes(), name);
+
+        Self::from_bytes(*hash.first_chunk().unwrap())
+    }
+}
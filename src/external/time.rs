@@ -0,0 +1,85 @@
+#![cfg(feature = "time")]
+
+use crate::enid::Enid80;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+const MAX_NANOS: i128 = ((1 << 48) - 1) * 1_000_000 + 999_999;
+
+impl Enid80 {
+    /// Creates a time-ordered ENID from a date and time and 32 random bits,
+    /// using the layout described in [`TimeOrdered`](crate::TimeOrdered).
+    ///
+    /// The time is truncated to the millisecond. This is useful for
+    /// backfilling historical records with IDs that sort in the order the
+    /// records were created.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use time::{OffsetDateTime, PrimitiveDateTime};
+    /// let created = OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap();
+    /// let enid = Enid80::from_offset_datetime(created, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid, Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is before the Unix epoch or after the year 10889.
+    #[must_use]
+    #[track_caller]
+    pub fn from_offset_datetime(datetime: OffsetDateTime, random: [u8; 4]) -> Self {
+        let nanos = datetime.unix_timestamp_nanos();
+        assert!(
+            (0..=MAX_NANOS).contains(&nanos),
+            "time is out of range for a time-ordered ENID"
+        );
+
+        Self::from_timestamp_millis((nanos / 1_000_000) as u64, random)
+    }
+
+    /// Creates a time-ordered ENID from a date and time, with random bytes
+    /// from the operating system's random number generator.
+    ///
+    /// This requires the `getrandom` feature. See
+    /// [`from_offset_datetime`](Self::from_offset_datetime) for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the time is out of range, or if the random number generator
+    /// fails.
+    #[cfg(feature = "getrandom")]
+    #[must_use]
+    #[track_caller]
+    pub fn from_offset_datetime_random(datetime: OffsetDateTime) -> Self {
+        let mut random = [0; 4];
+        getrandom::fill(&mut random).expect("failed to generate random ENID");
+
+        Self::from_offset_datetime(datetime, random)
+    }
+
+    /// Returns the timestamp stored in a time-ordered ENID as a date and time
+    /// in UTC.
+    ///
+    /// Without the `large-dates` feature of `time`, times after the year 9999
+    /// are clamped to the latest representable time. See [`timestamp_millis`](Self::timestamp_millis) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use time::macros::datetime;
+    /// let enid = Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid.offset_datetime(), datetime!(2023-11-14 22:13:20 UTC));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn offset_datetime(&self) -> OffsetDateTime {
+        let nanos = i128::from(self.timestamp_millis()) * 1_000_000;
+
+        OffsetDateTime::from_unix_timestamp_nanos(nanos)
+            .unwrap_or_else(|_| PrimitiveDateTime::MAX.assume_utc())
+    }
+}
@@ -0,0 +1,181 @@
+#![cfg(feature = "unicode")]
+
+use crate::ascii;
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+use unicode_normalization::UnicodeNormalization;
+
+impl Enid40 {
+    /// Attempts to create an ENID from a string that may contain Unicode
+    /// lookalikes of ENID characters.
+    ///
+    /// The string is NFKC-normalized, which maps characters such as fullwidth
+    /// digits to ASCII. Common homoglyphs, such as Cyrillic and Greek letters
+    /// that look like Latin letters, and typographic hyphens and dashes, are
+    /// then mapped to their ASCII counterparts, and zero-width characters are
+    /// removed. The result is parsed as with
+    /// [`parse_str_lenient`](Self::parse_str_lenient), so letters such as `o`
+    /// and `I` are read as the digits they resemble. This is intended for IDs
+    /// pasted from PDFs and chat apps.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// // Fullwidth "m", Cyrillic "ѕ", and a zero-width space.
+    /// let enid = Enid40::parse_str_unicode_lenient("ｍ6\u{455}c7n\u{200b}75")?;
+    ///
+    /// assert_eq!(enid, enid40!("m6sc7n75"));
+    ///
+    /// // Cyrillic "о" and Latin "I", read as "0" and "1".
+    /// let enid = Enid40::parse_str_unicode_lenient("\u{43e}000000I")?;
+    ///
+    /// assert_eq!(enid, enid40!("00000001"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the normalized string is not a valid
+    /// ENID.
+    pub fn parse_str_unicode_lenient(s: &str) -> Result<Self, EnidParseError> {
+        Self::parse_str_lenient(normalize(s, &mut [0; 17])?)
+    }
+}
+
+impl Enid80 {
+    /// Attempts to create an ENID from a string that may contain Unicode
+    /// lookalikes of ENID characters.
+    ///
+    /// See [`Enid40::parse_str_unicode_lenient`] for the normalization that is
+    /// applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// // An en dash in place of the hyphen.
+    /// let enid = Enid80::parse_str_unicode_lenient("y3gx5gxm\u{2013}mpb8ey39")?;
+    ///
+    /// assert_eq!(enid, enid80!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the normalized string is not a valid
+    /// ENID.
+    pub fn parse_str_unicode_lenient(s: &str) -> Result<Self, EnidParseError> {
+        Self::parse_str_lenient(normalize(s, &mut [0; 17])?)
+    }
+}
+
+impl Enid {
+    /// Attempts to create an ENID from a string that may contain Unicode
+    /// lookalikes of ENID characters.
+    ///
+    /// See [`Enid40::parse_str_unicode_lenient`] for the normalization that is
+    /// applied.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = Enid::parse_str_unicode_lenient("Ｍ６ＳＣ７Ｎ７５")?;
+    ///
+    /// assert_eq!(enid, enid!("m6sc7n75"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the normalized string is not a valid
+    /// ENID.
+    pub fn parse_str_unicode_lenient(s: &str) -> Result<Self, EnidParseError> {
+        Self::parse_str_lenient(normalize(s, &mut [0; 17])?)
+    }
+}
+
+/// Normalizes the string into the buffer, and returns the normalized string.
+fn normalize<'a>(s: &str, buf: &'a mut [u8; 17]) -> Result<&'a str, EnidParseError> {
+    let mut len = 0;
+
+    for c in s.nfkc() {
+        let Some(b) = to_ascii(c) else {
+            continue;
+        };
+
+        *buf.get_mut(len).ok_or(EnidParseError)? = b;
+        len += 1;
+    }
+
+    Ok(ascii::to_str(&buf[..len]))
+}
+
+/// Maps a normalized character to an ASCII byte, or `None` if the character
+/// should be removed. Characters without an ASCII counterpart are mapped to a
+/// character that is never valid in an ENID.
+fn to_ascii(c: char) -> Option<u8> {
+    let b = match c {
+        '\u{00ad}' | '\u{200b}' | '\u{200c}' | '\u{200d}' | '\u{2060}' | '\u{feff}' => {
+            return None;
+        }
+        '\u{2010}'..='\u{2015}' | '\u{2212}' => b'-',
+        '\u{0391}' | '\u{03b1}' | '\u{0410}' | '\u{0430}' => b'a',
+        '\u{0392}' | '\u{0412}' => b'b',
+        '\u{0421}' | '\u{0441}' | '\u{03f2}' => b'c',
+        '\u{0395}' | '\u{0415}' | '\u{0435}' => b'e',
+        '\u{0397}' | '\u{041d}' | '\u{04bb}' => b'h',
+        '\u{0399}' | '\u{0406}' | '\u{0456}' => b'i',
+        '\u{0408}' | '\u{0458}' => b'j',
+        '\u{039a}' | '\u{03ba}' | '\u{041a}' | '\u{043a}' => b'k',
+        '\u{039c}' | '\u{041c}' | '\u{043c}' => b'm',
+        '\u{039d}' => b'n',
+        '\u{039f}' | '\u{03bf}' | '\u{041e}' | '\u{043e}' => b'o',
+        '\u{03a1}' | '\u{03c1}' | '\u{0420}' | '\u{0440}' => b'p',
+        '\u{051a}' | '\u{051b}' => b'q',
+        '\u{0405}' | '\u{0455}' => b's',
+        '\u{03a4}' | '\u{0422}' => b't',
+        '\u{03bd}' | '\u{0474}' | '\u{0475}' => b'v',
+        '\u{051c}' | '\u{051d}' => b'w',
+        '\u{03a7}' | '\u{03c7}' | '\u{0425}' | '\u{0445}' => b'x',
+        '\u{03a5}' | '\u{0423}' | '\u{0443}' | '\u{04ae}' | '\u{04af}' => b'y',
+        '\u{0396}' => b'z',
+        '\u{0417}' => b'3',
+        c if c.is_ascii() => c as u8,
+        _ => b'!',
+    };
+
+    Some(b)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Enid, Enid40, Enid80, EnidParseError, enid, enid40};
+
+    #[test]
+    fn confusables() {
+        // Cyrillic "о", Latin "I" and "l", and a Greek "Ο".
+        assert_eq!(
+            Enid40::parse_str_unicode_lenient("\u{43e}0000Il\u{39f}"),
+            Ok(enid40!("00000110"))
+        );
+        assert_eq!(
+            Enid::parse_str_unicode_lenient("y3gx5gxm\u{2014}MPB8EY39"),
+            Ok(enid!("y3gx5gxm-mpb8ey39"))
+        );
+        assert_eq!(
+            Enid80::parse_str_unicode_lenient("y3gx5gxm-mpb8ey3\u{3b2}"),
+            Err(EnidParseError)
+        );
+        assert_eq!(
+            Enid40::parse_str_unicode_lenient("m6sc7n75m"),
+            Err(EnidParseError)
+        );
+    }
+}
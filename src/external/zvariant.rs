@@ -0,0 +1,45 @@
+#![cfg(feature = "zbus")]
+
+use crate::enid::{Enid, Enid40, Enid80};
+use zvariant::Type;
+use zvariant::signature::{Child, Signature};
+
+// D-Bus has no fixed-size arrays, so all three types are sent as byte arrays
+// (`ay`), using the binary form of their serde implementations.
+const BYTE_ARRAY: &Signature = &Signature::Array(Child::Static {
+    child: &Signature::U8,
+});
+
+impl Type for Enid40 {
+    const SIGNATURE: &'static Signature = BYTE_ARRAY;
+}
+
+impl Type for Enid80 {
+    const SIGNATURE: &'static Signature = BYTE_ARRAY;
+}
+
+impl Type for Enid {
+    const SIGNATURE: &'static Signature = BYTE_ARRAY;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enid40, enid80};
+    use zvariant::LE;
+    use zvariant::serialized::Context;
+
+    #[test]
+    fn round_trip() {
+        let ctxt = Context::new_dbus(LE, 0);
+
+        let enid = enid40!("m6sc7n75");
+        let data = zvariant::to_bytes(ctxt, &enid).unwrap();
+        assert_eq!(&data[..], &[5, 0, 0, 0, 0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+        assert_eq!(data.deserialize::<Enid40>().unwrap().0, enid);
+
+        let enid = Enid::from(enid80!("y3gx5gxm-kbhqr4fs"));
+        let data = zvariant::to_bytes(ctxt, &enid).unwrap();
+        assert_eq!(data.deserialize::<Enid>().unwrap().0, enid);
+    }
+}
@@ -0,0 +1,264 @@
+//! Alternative string forms of ENIDs.
+//!
+//! The adapters in this module are returned by methods such as
+//! [`EnidN::compact`], and implement [`Display`] for their form, so they can be
+//! used with `format!` and `to_string` like the ENID itself.
+
+use crate::base32;
+use crate::enid::{Enid, Enid40, EnidN, EnidParseError};
+use core::fmt::{self, Alignment, Debug, Display, Formatter, Write};
+use core::str::FromStr;
+
+/// An adapter that formats an ENID without hyphens between groups, such as
+/// `"y3gx5gxmmpb8ey39"`.
+///
+/// This is returned by [`EnidN::compact`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Compact<const N: usize>(pub(crate) EnidN<N>);
+
+impl<const N: usize> Compact<N> {
+    /// Returns the ENID being formatted.
+    #[must_use]
+    #[inline]
+    pub const fn get(&self) -> EnidN<N> {
+        self.0
+    }
+}
+
+impl<const N: usize> Debug for Compact<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for Compact<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write_padded(f, N / 5 * 8, compact_chars(&self.0))
+    }
+}
+
+/// An adapter that formats an ENID in uppercase, such as
+/// `"Y3GX5GXM-MPB8EY39"`.
+///
+/// This is returned by [`EnidN::uppercase`], and can be parsed from an
+/// uppercase string with [`FromStr`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Uppercase<const N: usize>(pub(crate) EnidN<N>);
+
+impl<const N: usize> Uppercase<N> {
+    /// Returns the ENID being formatted.
+    #[must_use]
+    #[inline]
+    pub const fn get(&self) -> EnidN<N> {
+        self.0
+    }
+}
+
+impl<const N: usize> Debug for Uppercase<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for Uppercase<N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let chars = chars(&self.0).map(|c| char::from(c.to_ascii_uppercase()));
+
+        write_padded(f, EnidN::<N>::STR_LEN, chars)
+    }
+}
+
+impl<const N: usize> FromStr for Uppercase<N> {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        EnidN::parse_str_uppercase(s).map(Self)
+    }
+}
+
+/// An adapter that formats an [`Enid40`] with a hyphen after the fourth
+/// character, such as `"m6sc-7n75"`.
+///
+/// This is returned by [`Enid40::grouped`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Grouped(pub(crate) Enid40);
+
+impl Grouped {
+    /// Returns the ENID being formatted.
+    #[must_use]
+    #[inline]
+    pub const fn get(&self) -> Enid40 {
+        self.0
+    }
+}
+
+impl Debug for Grouped {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl Display for Grouped {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let chars = base32::encode(self.0.into_bytes());
+        let (hi, lo) = chars.split_at(4);
+        let chars = hi.iter().chain(&[base32::SEPARATOR]).chain(lo);
+
+        write_padded(f, 9, chars.map(|&c| char::from(c)))
+    }
+}
+
+/// The prefix of an ENID's URN.
+pub(crate) const URN_PREFIX: &str = "urn:enid:";
+
+/// An adapter that formats an ENID as a URN, such as `"urn:enid:m6sc7n75"`.
+///
+/// This is returned by [`EnidN::urn`] and [`Enid::urn`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Urn<T>(pub(crate) T);
+
+impl<T: Copy> Urn<T> {
+    /// Returns the ENID being formatted.
+    #[must_use]
+    #[inline]
+    pub const fn get(&self) -> T {
+        self.0
+    }
+}
+
+impl<T> Debug for Urn<T>
+where
+    Self: Display,
+{
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<const N: usize> Display for Urn<EnidN<N>> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let chars = URN_PREFIX.bytes().chain(chars(&self.0));
+
+        write_padded(
+            f,
+            URN_PREFIX.len() + EnidN::<N>::STR_LEN,
+            chars.map(char::from),
+        )
+    }
+}
+
+impl Display for Urn<Enid> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.0 {
+            Enid::Enid40(enid) => Display::fmt(&Urn(enid), f),
+            Enid::Enid80(enid) => Display::fmt(&Urn(enid), f),
+        }
+    }
+}
+
+/// Returns the part of a URN after the `urn:enid:` prefix, which is matched
+/// case-insensitively as RFC 8141 requires.
+pub(crate) const fn strip_urn_prefix(s: &[u8]) -> Option<&[u8]> {
+    let prefix = URN_PREFIX.as_bytes();
+
+    if s.len() < prefix.len() {
+        return None;
+    }
+
+    let (head, tail) = s.split_at(prefix.len());
+    let mut i = 0;
+
+    while i < prefix.len() {
+        if head[i].to_ascii_lowercase() != prefix[i] {
+            return None;
+        }
+
+        i += 1;
+    }
+
+    Some(tail)
+}
+
+/// Returns the characters of an ENID's string form.
+pub(crate) fn chars<const N: usize>(enid: &EnidN<N>) -> impl Iterator<Item = u8> + '_ {
+    enid.as_bytes()
+        .chunks_exact(5)
+        .enumerate()
+        .flat_map(|(i, bytes)| {
+            let separator = (i > 0).then_some(base32::SEPARATOR);
+            separator
+                .into_iter()
+                .chain(base32::encode(*bytes.first_chunk().unwrap()))
+        })
+}
+
+/// Returns the characters of an ENID's groups, without separators.
+fn compact_chars<const N: usize>(enid: &EnidN<N>) -> impl Iterator<Item = char> + '_ {
+    enid.as_bytes()
+        .chunks_exact(5)
+        .flat_map(|bytes| base32::encode(*bytes.first_chunk().unwrap()))
+        .map(char::from)
+}
+
+/// Writes `len` characters, respecting the formatter's width, fill, alignment,
+/// and precision, without first collecting them into a string.
+pub(crate) fn write_padded(
+    f: &mut Formatter,
+    len: usize,
+    chars: impl Iterator<Item = char>,
+) -> fmt::Result {
+    let len = f.precision().map_or(len, |p| p.min(len));
+    let padding = f.width().map_or(0, |width| width.saturating_sub(len));
+    let (before, after) = match f.align() {
+        Some(Alignment::Right) => (padding, 0),
+        Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(Alignment::Left) | None => (0, padding),
+    };
+    let fill = f.fill();
+
+    for _ in 0..before {
+        f.write_char(fill)?;
+    }
+
+    for c in chars.take(len) {
+        f.write_char(c)?;
+    }
+
+    for _ in 0..after {
+        f.write_char(fill)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{enid40, enid80};
+    use std::format;
+
+    #[test]
+    fn padding() {
+        let compact = enid80!("y3gx5gxm-mpb8ey39").compact();
+        let grouped = enid40!("m6sc7n75").grouped();
+
+        assert_eq!(format!("{compact:>18}"), "  y3gx5gxmmpb8ey39");
+        assert_eq!(format!("{compact:.10}"), "y3gx5gxmmp");
+        assert_eq!(format!("{grouped:*^11}"), "*m6sc-7n75*");
+        assert_eq!(format!("{grouped:<6.5}"), "m6sc- ");
+
+        let uppercase = enid80!("y3gx5gxm-mpb8ey39").uppercase();
+
+        assert_eq!(format!("{uppercase:>18}"), " Y3GX5GXM-MPB8EY39");
+        assert_eq!(format!("{uppercase:.9}"), "Y3GX5GXM-");
+
+        let urn = crate::enid!("m6sc7n75").urn();
+
+        assert_eq!(format!("{urn:>18}"), " urn:enid:m6sc7n75");
+        assert_eq!(format!("{urn:.12}"), "urn:enid:m6s");
+    }
+}
@@ -0,0 +1,701 @@
+//! Generators of ENIDs.
+//!
+//! (This module would be named `gen`, but that is a reserved keyword in Rust
+//! 2024.)
+
+use crate::clock::EnidClock;
+use crate::enid::{Enid, Enid40, Enid80};
+use core::iter::{self, FromFn};
+
+/// A source of new ENIDs.
+///
+/// Code that mints IDs can be generic over this trait, so that the way IDs are
+/// generated can be chosen by the application, and replaced with a predictable
+/// generator in tests, such as [`SequentialGenerator`] or [`MockGenerator`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid80;
+/// # use enid::generator::{EnidGenerator, SequentialGenerator};
+/// fn create_user(ids: &mut impl EnidGenerator) -> Enid80 {
+///     ids.next80()
+/// }
+///
+/// let mut ids = SequentialGenerator::new();
+///
+/// assert_eq!(create_user(&mut ids).to_string(), "00000000-00000000");
+/// assert_eq!(create_user(&mut ids).to_string(), "00000000-00000001");
+/// ```
+pub trait EnidGenerator {
+    /// Returns a new 40-bit ENID.
+    fn next40(&mut self) -> Enid40;
+
+    /// Returns a new 80-bit ENID.
+    fn next80(&mut self) -> Enid80;
+}
+
+impl<G: EnidGenerator + ?Sized> EnidGenerator for &mut G {
+    #[inline]
+    fn next40(&mut self) -> Enid40 {
+        (**self).next40()
+    }
+
+    #[inline]
+    fn next80(&mut self) -> Enid80 {
+        (**self).next80()
+    }
+}
+
+/// A generator of random ENIDs, using the operating system's random number
+/// generator.
+///
+/// This requires the `getrandom` feature.
+///
+/// # Panics
+///
+/// The generator methods panic if the random number generator fails.
+#[cfg(feature = "getrandom")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OsRandomGenerator;
+
+#[cfg(feature = "getrandom")]
+impl EnidGenerator for OsRandomGenerator {
+    #[inline]
+    fn next40(&mut self) -> Enid40 {
+        Enid40::new()
+    }
+
+    #[inline]
+    fn next80(&mut self) -> Enid80 {
+        Enid80::new()
+    }
+}
+
+/// A generator of random ENIDs, using the given random number generator.
+///
+/// This requires the `rand` feature.
+#[cfg(feature = "rand")]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct RandomGenerator<R>(pub R);
+
+#[cfg(feature = "rand")]
+impl<R: rand::Rng> EnidGenerator for RandomGenerator<R> {
+    #[inline]
+    fn next40(&mut self) -> Enid40 {
+        Enid40::random_with(&mut self.0)
+    }
+
+    #[inline]
+    fn next80(&mut self) -> Enid80 {
+        Enid80::random_with(&mut self.0)
+    }
+}
+
+/// A generator of sequential ENIDs, counting up from a starting value.
+///
+/// Both sizes of ENID share one counter, which is formatted as a big-endian
+/// integer, truncated to 40 or 80 bits, and wraps on overflow. This is mostly
+/// useful for tests, where predictable IDs make failures easier to read.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct SequentialGenerator {
+    next: u128,
+}
+
+impl SequentialGenerator {
+    /// Creates a generator that starts counting from zero.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self::starting_at(0)
+    }
+
+    /// Creates a generator that starts counting from the given value.
+    #[must_use]
+    #[inline]
+    pub const fn starting_at(next: u128) -> Self {
+        Self { next }
+    }
+
+    fn next_bytes(&mut self) -> [u8; 16] {
+        let bytes = self.next.to_be_bytes();
+        self.next = self.next.wrapping_add(1);
+        bytes
+    }
+}
+
+impl EnidGenerator for SequentialGenerator {
+    #[inline]
+    fn next40(&mut self) -> Enid40 {
+        Enid40::from_bytes(*self.next_bytes().last_chunk().unwrap())
+    }
+
+    #[inline]
+    fn next80(&mut self) -> Enid80 {
+        Enid80::from_bytes(*self.next_bytes().last_chunk().unwrap())
+    }
+}
+
+/// A generator that returns a predefined sequence of ENIDs, and records how
+/// many it has returned.
+///
+/// The sequence can be any iterator of ENIDs, such as the elements of a slice,
+/// or the results of calling a closure until it returns `None`.
+///
+/// # Panics
+///
+/// The generator methods panic if the sequence is exhausted, or the next ENID
+/// is the wrong size.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, enid80, Enid};
+/// # use enid::generator::{EnidGenerator, MockGenerator};
+/// let ids = [Enid::from(enid80!("y3gx5gxm-kbhqr4fs")), Enid::from(enid40!("m6sc7n75"))];
+/// let mut generator = MockGenerator::new(ids);
+///
+/// assert_eq!(generator.next80(), enid80!("y3gx5gxm-kbhqr4fs"));
+/// assert_eq!(generator.next40(), enid40!("m6sc7n75"));
+/// assert_eq!(generator.issued(), 2);
+///
+/// let mut generator = MockGenerator::from_fn(|| Some(Enid::from(enid40!("m6sc7n75"))));
+///
+/// assert_eq!(generator.next40(), generator.next40());
+/// ```
+#[derive(Debug, Clone)]
+pub struct MockGenerator<I> {
+    ids: I,
+    issued: usize,
+}
+
+impl<I: Iterator<Item = Enid>> MockGenerator<I> {
+    /// Creates a generator that returns the given ENIDs, in order.
+    #[must_use]
+    #[inline]
+    pub fn new<T>(ids: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            ids: ids.into_iter(),
+            issued: 0,
+        }
+    }
+
+    /// Returns the number of ENIDs returned so far.
+    #[must_use]
+    #[inline]
+    pub const fn issued(&self) -> usize {
+        self.issued
+    }
+
+    #[track_caller]
+    fn next_enid(&mut self) -> Enid {
+        let enid = self.ids.next().expect("MockGenerator has no more ENIDs");
+        self.issued += 1;
+        enid
+    }
+}
+
+impl<F: FnMut() -> Option<Enid>> MockGenerator<FromFn<F>> {
+    /// Creates a generator that returns the results of calling the given
+    /// closure, until it returns `None`.
+    #[must_use]
+    #[inline]
+    pub fn from_fn(f: F) -> Self {
+        Self::new(iter::from_fn(f))
+    }
+}
+
+impl<I: Iterator<Item = Enid>> EnidGenerator for MockGenerator<I> {
+    #[track_caller]
+    fn next40(&mut self) -> Enid40 {
+        match self.next_enid() {
+            Enid::Enid40(enid) => enid,
+            Enid::Enid80(enid) => panic!("MockGenerator expected an Enid40, found {enid}"),
+        }
+    }
+
+    #[track_caller]
+    fn next80(&mut self) -> Enid80 {
+        match self.next_enid() {
+            Enid::Enid80(enid) => enid,
+            Enid::Enid40(enid) => panic!("MockGenerator expected an Enid80, found {enid}"),
+        }
+    }
+}
+
+/// A generator of time-ordered 80-bit ENIDs that are strictly increasing.
+///
+/// ENIDs use the same layout as [`TimeOrdered`](crate::TimeOrdered): a 48-bit
+/// millisecond timestamp followed by 32 random bits. When an ENID is generated
+/// in the same millisecond as the previous one, or the clock has gone
+/// backwards, the previous timestamp is reused and the random bits are
+/// incremented instead of being drawn again. If the random bits would
+/// overflow, the timestamp is bumped forward by one millisecond, so the
+/// generator never waits for the clock.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::clock::FixedClock;
+/// # use enid::generator::MonotonicGenerator;
+/// let mut generator = MonotonicGenerator::new(FixedClock(1_700_000_000_000));
+///
+/// let a = generator.generate_with_random([0xff; 4]);
+/// let b = generator.generate_with_random([0x00; 4]);
+/// let c = generator.generate_with_random([0x00; 4]);
+///
+/// assert!(a < b && b < c);
+/// assert_eq!(c.as_bytes()[..6], [0x01, 0x8b, 0xcf, 0xe5, 0x68, 0x01]);
+/// assert_eq!(c.as_bytes()[6..], [0x00, 0x00, 0x00, 0x01]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct MonotonicGenerator<C> {
+    clock: C,
+    last: Option<(u64, u32)>,
+}
+
+impl<C: EnidClock> MonotonicGenerator<C> {
+    /// Creates a generator that reads the time from the given clock.
+    #[must_use]
+    #[inline]
+    pub const fn new(clock: C) -> Self {
+        Self { clock, last: None }
+    }
+
+    /// Returns the clock used by the generator.
+    #[must_use]
+    #[inline]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Generates an ENID using the given random bytes, if the time has advanced
+    /// since the previous ENID.
+    pub fn generate_with_random(&mut self, random: [u8; 4]) -> Enid80 {
+        let now = self.clock.now_millis() & 0xffff_ffff_ffff;
+
+        let (millis, tail) = match self.last {
+            Some((last, tail)) if now <= last => match tail.checked_add(1) {
+                Some(tail) => (last, tail),
+                None => (last + 1, u32::from_be_bytes(random)),
+            },
+            _ => (now, u32::from_be_bytes(random)),
+        };
+
+        self.last = Some((millis, tail));
+        Enid80::from_timestamp_millis(millis, tail.to_be_bytes())
+    }
+
+    /// Generates an ENID, drawing random bytes from the operating system's
+    /// random number generator when needed.
+    ///
+    /// This requires the `getrandom` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number generator fails.
+    #[cfg(feature = "getrandom")]
+    #[track_caller]
+    pub fn generate(&mut self) -> Enid80 {
+        let mut random = [0; 4];
+        getrandom::fill(&mut random).expect("failed to generate random ENID");
+
+        self.generate_with_random(random)
+    }
+
+    /// Generates an ENID, drawing random bytes from the given random number
+    /// generator when needed.
+    ///
+    /// This requires the `rand` feature.
+    #[cfg(feature = "rand")]
+    #[inline]
+    pub fn generate_with_rng<R: rand::Rng + ?Sized>(&mut self, rng: &mut R) -> Enid80 {
+        let mut random = [0; 4];
+        rng.fill_bytes(&mut random);
+
+        self.generate_with_random(random)
+    }
+}
+
+/// Generates time-ordered 80-bit ENIDs, and random 40-bit ENIDs, since 40 bits
+/// is too short to hold a useful timestamp as well as random bits.
+///
+/// This requires the `getrandom` feature.
+#[cfg(feature = "getrandom")]
+impl<C: EnidClock> EnidGenerator for MonotonicGenerator<C> {
+    #[inline]
+    fn next40(&mut self) -> Enid40 {
+        Enid40::new()
+    }
+
+    #[inline]
+    fn next80(&mut self) -> Enid80 {
+        self.generate()
+    }
+}
+
+/// The layout of the fields in a Snowflake-style ENID.
+///
+/// An 80-bit ENID is divided into a timestamp, a node ID, and a sequence
+/// number, in that order from the most significant bit. The timestamp counts
+/// milliseconds from a configurable epoch, and fills the bits that are not used
+/// by the node ID and sequence number.
+///
+/// The [`DEFAULT`](Self::DEFAULT) layout has a 48-bit timestamp from the Unix
+/// epoch, a 16-bit node ID, and a 16-bit sequence number, so the timestamp is
+/// in the same place as in ENIDs created by [`TimeOrdered`](crate::TimeOrdered).
+///
+/// # Examples
+///
+/// ```
+/// # use enid::generator::SnowflakeLayout;
+/// let layout = SnowflakeLayout::new(10, 12).with_epoch_millis(1_600_000_000_000);
+/// let enid = layout.pack(1_700_000_000_000, 513, 7);
+///
+/// assert_eq!(layout.timestamp_millis(enid), 1_700_000_000_000);
+/// assert_eq!(layout.node_id(enid), 513);
+/// assert_eq!(layout.sequence(enid), 7);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct SnowflakeLayout {
+    node_bits: u32,
+    sequence_bits: u32,
+    epoch_millis: u64,
+}
+
+impl SnowflakeLayout {
+    /// A layout with a 48-bit timestamp from the Unix epoch, a 16-bit node ID,
+    /// and a 16-bit sequence number.
+    pub const DEFAULT: Self = Self::new(16, 16);
+
+    /// Creates a layout with the given numbers of bits for the node ID and
+    /// sequence number, and a timestamp from the Unix epoch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node ID and sequence number use more than 40 bits
+    /// together, which would leave too few bits for the timestamp.
+    #[must_use]
+    #[inline]
+    pub const fn new(node_bits: u32, sequence_bits: u32) -> Self {
+        assert!(
+            node_bits + sequence_bits <= 40,
+            "node ID and sequence number must fit in 40 bits"
+        );
+
+        Self {
+            node_bits,
+            sequence_bits,
+            epoch_millis: 0,
+        }
+    }
+
+    /// Returns a copy of the layout, with timestamps counted from the given
+    /// Unix time in milliseconds.
+    #[must_use]
+    #[inline]
+    pub const fn with_epoch_millis(self, epoch_millis: u64) -> Self {
+        Self {
+            epoch_millis,
+            ..self
+        }
+    }
+
+    /// Returns the number of bits used for the timestamp.
+    #[must_use]
+    #[inline]
+    pub const fn timestamp_bits(&self) -> u32 {
+        80 - self.node_bits - self.sequence_bits
+    }
+
+    /// Returns the number of bits used for the node ID.
+    #[must_use]
+    #[inline]
+    pub const fn node_bits(&self) -> u32 {
+        self.node_bits
+    }
+
+    /// Returns the number of bits used for the sequence number.
+    #[must_use]
+    #[inline]
+    pub const fn sequence_bits(&self) -> u32 {
+        self.sequence_bits
+    }
+
+    /// Returns the Unix time in milliseconds from which timestamps are counted.
+    #[must_use]
+    #[inline]
+    pub const fn epoch_millis(&self) -> u64 {
+        self.epoch_millis
+    }
+
+    /// Creates an ENID from a Unix time in milliseconds, a node ID, and a
+    /// sequence number.
+    ///
+    /// Each field is truncated to the number of bits available for it.
+    #[must_use]
+    #[inline]
+    pub const fn pack(&self, millis: u64, node_id: u64, sequence: u64) -> Enid80 {
+        let timestamp =
+            millis.wrapping_sub(self.epoch_millis) as u128 & mask(self.timestamp_bits());
+        let node_id = node_id as u128 & mask(self.node_bits);
+        let sequence = sequence as u128 & mask(self.sequence_bits);
+
+        let value = timestamp << (self.node_bits + self.sequence_bits)
+            | node_id << self.sequence_bits
+            | sequence;
+
+        let [_, _, _, _, _, _, bytes @ ..] = value.to_be_bytes();
+        Enid80::from_bytes(bytes)
+    }
+
+    /// Returns the Unix time in milliseconds stored in an ENID.
+    #[must_use]
+    #[inline]
+    pub const fn timestamp_millis(&self, enid: Enid80) -> u64 {
+        let timestamp = enid.to_u128() >> (self.node_bits + self.sequence_bits);
+
+        (timestamp as u64).wrapping_add(self.epoch_millis)
+    }
+
+    /// Returns the node ID stored in an ENID.
+    #[must_use]
+    #[inline]
+    pub const fn node_id(&self, enid: Enid80) -> u64 {
+        (enid.to_u128() >> self.sequence_bits & mask(self.node_bits)) as u64
+    }
+
+    /// Returns the sequence number stored in an ENID.
+    #[must_use]
+    #[inline]
+    pub const fn sequence(&self, enid: Enid80) -> u64 {
+        (enid.to_u128() & mask(self.sequence_bits)) as u64
+    }
+}
+
+impl Default for SnowflakeLayout {
+    #[inline]
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+const fn mask(bits: u32) -> u128 {
+    (1 << bits) - 1
+}
+
+/// A generator of Snowflake-style ENIDs, which combine a timestamp, the ID of
+/// the node that generated them, and a sequence number.
+///
+/// As long as each node has a unique ID, nodes can generate ENIDs without
+/// coordinating, and without relying on randomness. The sequence number is
+/// reset every millisecond, and incremented for each ENID generated within the
+/// same millisecond. If it would overflow, or the clock goes backwards, the
+/// timestamp of the previous ENID is bumped forward instead, so ENIDs from one
+/// generator are always strictly increasing.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::clock::FixedClock;
+/// # use enid::generator::{SnowflakeGenerator, SnowflakeLayout};
+/// let layout = SnowflakeLayout::DEFAULT;
+/// let mut generator = SnowflakeGenerator::new(FixedClock(1_700_000_000_000), layout, 42);
+///
+/// let a = generator.generate();
+/// let b = generator.generate();
+///
+/// assert!(a < b);
+/// assert_eq!(layout.timestamp_millis(b), 1_700_000_000_000);
+/// assert_eq!(layout.node_id(b), 42);
+/// assert_eq!(layout.sequence(b), 1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SnowflakeGenerator<C> {
+    clock: C,
+    layout: SnowflakeLayout,
+    node_id: u64,
+    last: Option<(u64, u64)>,
+}
+
+impl<C: EnidClock> SnowflakeGenerator<C> {
+    /// Creates a generator with the given clock, layout, and node ID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node ID does not fit in the number of bits given by the
+    /// layout.
+    #[must_use]
+    #[inline]
+    pub const fn new(clock: C, layout: SnowflakeLayout, node_id: u64) -> Self {
+        assert!(
+            node_id as u128 <= mask(layout.node_bits),
+            "node ID does not fit in the layout"
+        );
+
+        Self {
+            clock,
+            layout,
+            node_id,
+            last: None,
+        }
+    }
+
+    /// Returns the layout used by the generator.
+    #[must_use]
+    #[inline]
+    pub const fn layout(&self) -> SnowflakeLayout {
+        self.layout
+    }
+
+    /// Returns the node ID used by the generator.
+    #[must_use]
+    #[inline]
+    pub const fn node_id(&self) -> u64 {
+        self.node_id
+    }
+
+    /// Generates an ENID.
+    pub fn generate(&mut self) -> Enid80 {
+        let now = self.clock.now_millis();
+        let max_sequence = mask(self.layout.sequence_bits) as u64;
+
+        let (millis, sequence) = match self.last {
+            Some((last, sequence)) if now <= last => {
+                if sequence < max_sequence {
+                    (last, sequence + 1)
+                } else {
+                    (last + 1, 0)
+                }
+            }
+            _ => (now, 0),
+        };
+
+        self.last = Some((millis, sequence));
+        self.layout.pack(millis, self.node_id, sequence)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clock::FixedClock;
+    use core::cell::Cell;
+
+    struct TestClock<'a>(&'a Cell<u64>);
+
+    impl EnidClock for TestClock<'_> {
+        fn now_millis(&self) -> u64 {
+            self.0.get()
+        }
+    }
+
+    #[test]
+    fn overflow_bumps_timestamp() {
+        let mut generator = MonotonicGenerator::new(FixedClock(1000));
+
+        let a = generator.generate_with_random([0xff; 4]);
+        let b = generator.generate_with_random([0x12, 0x34, 0x56, 0x78]);
+
+        assert_eq!(a, Enid80::from_timestamp_millis(1000, [0xff; 4]));
+        assert_eq!(
+            b,
+            Enid80::from_timestamp_millis(1001, [0x12, 0x34, 0x56, 0x78])
+        );
+    }
+
+    #[test]
+    fn clock_going_backwards() {
+        let now = Cell::new(1000);
+        let mut generator = MonotonicGenerator::new(TestClock(&now));
+
+        let a = generator.generate_with_random([0x80; 4]);
+        now.set(900);
+        let b = generator.generate_with_random([0x00; 4]);
+        now.set(1001);
+        let c = generator.generate_with_random([0x00; 4]);
+
+        assert_eq!(
+            b,
+            Enid80::from_timestamp_millis(1000, [0x80, 0x80, 0x80, 0x81])
+        );
+        assert_eq!(c, Enid80::from_timestamp_millis(1001, [0x00; 4]));
+        assert!(a < b && b < c);
+    }
+
+    #[test]
+    fn snowflake_sequence_overflow() {
+        let layout = SnowflakeLayout::new(8, 1);
+        let mut generator = SnowflakeGenerator::new(FixedClock(1000), layout, 255);
+
+        let ids = [
+            generator.generate(),
+            generator.generate(),
+            generator.generate(),
+        ];
+
+        assert_eq!(
+            ids.map(|id| layout.timestamp_millis(id)),
+            [1000, 1000, 1001]
+        );
+        assert_eq!(ids.map(|id| layout.sequence(id)), [0, 1, 0]);
+        assert_eq!(ids.map(|id| layout.node_id(id)), [255; 3]);
+    }
+
+    #[test]
+    fn snowflake_layout_full_width() {
+        let layout = SnowflakeLayout::new(20, 20);
+        let enid = layout.pack(u64::MAX, u64::MAX, u64::MAX);
+
+        assert_eq!(enid, Enid80::from_bytes([0xff; 10]));
+        assert_eq!(layout.timestamp_millis(enid), (1 << 40) - 1);
+        assert_eq!(layout.node_id(enid), (1 << 20) - 1);
+        assert_eq!(layout.sequence(enid), (1 << 20) - 1);
+    }
+
+    #[test]
+    fn sequential() {
+        let mut generator = SequentialGenerator::starting_at((1 << 40) - 1);
+
+        assert_eq!(generator.next40(), Enid40::MAX);
+        assert_eq!(generator.next80(), Enid80::from_words(0, 1 << 40));
+        assert_eq!(generator.next40(), Enid40::from_u64(1).unwrap());
+
+        let mut generator = SequentialGenerator::starting_at(u128::MAX);
+
+        assert_eq!(generator.next80(), Enid80::MAX);
+        assert_eq!(generator.next80(), Enid80::NIL);
+    }
+
+    #[test]
+    fn mock_from_fn() {
+        let mut next = 0;
+        let mut generator = MockGenerator::from_fn(|| {
+            next += 1;
+            (next <= 2).then(|| Enid::from(Enid40::from_u64(next).unwrap()))
+        });
+
+        assert_eq!(generator.next40(), Enid40::from_u64(1).unwrap());
+        assert_eq!(generator.next40(), Enid40::from_u64(2).unwrap());
+        assert_eq!(generator.issued(), 2);
+    }
+
+    #[test]
+    #[should_panic = "MockGenerator has no more ENIDs"]
+    fn mock_exhausted() {
+        let mut generator = MockGenerator::from_fn(|| None);
+
+        generator.next80();
+    }
+
+    #[test]
+    #[should_panic = "MockGenerator expected an Enid80"]
+    fn mock_wrong_size() {
+        let mut generator = MockGenerator::new([Enid::from(Enid40::NIL)]);
+
+        generator.next80();
+    }
+}
@@ -0,0 +1,183 @@
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+pub(crate) const FNV_OFFSET_BASIS_128: u128 = 0x6c62_272e_07bb_0142_62b8_2175_6295_c58d;
+const FNV_PRIME_128: u128 = 0x0000_0000_0100_0000_0000_0000_0000_013b;
+
+/// Computes the 64-bit FNV-1a hash of the given bytes.
+pub(crate) const fn fnv1a_64(bytes: &[u8]) -> u64 {
+    fnv1a_64_update(FNV_OFFSET_BASIS, bytes)
+}
+
+/// Continues a 64-bit FNV-1a hash with the given bytes.
+pub(crate) const fn fnv1a_64_update(mut hash: u64, bytes: &[u8]) -> u64 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Applies the SplitMix64 finalizer, which mixes every input bit into every
+/// output bit.
+pub(crate) const fn mix64(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    x ^ (x >> 31)
+}
+
+/// Advances a SplitMix64 generator, returning the next output. This is simple
+/// and fast, but not suitable where the outputs must be unpredictable.
+pub(crate) const fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    mix64(*state)
+}
+
+/// Maps a key to one of `buckets` buckets with jump consistent hashing, as
+/// described by Lamping and Veach. When the number of buckets grows from `n` to
+/// `n + 1`, only `1 / (n + 1)` of the keys move, all to the new bucket.
+pub(crate) const fn jump_consistent_hash(mut key: u64, buckets: u32) -> u32 {
+    let mut b = -1_i64;
+    let mut j = 0_i64;
+
+    while j < buckets as i64 {
+        b = j;
+        key = key.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        j = ((b + 1) as f64 * ((1_u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as i64;
+    }
+
+    b as u32
+}
+
+/// Continues a 128-bit FNV-1a hash with the given bytes.
+pub(crate) const fn fnv1a_128_update(mut hash: u128, bytes: &[u8]) -> u128 {
+    let mut i = 0;
+    while i < bytes.len() {
+        hash ^= bytes[i] as u128;
+        hash = hash.wrapping_mul(FNV_PRIME_128);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Computes the 128-bit SipHash-2-4 hash of the given bytes, in the
+/// little-endian byte order used by the reference implementation.
+pub(crate) const fn siphash_2_4_128(key: &[u8; 16], bytes: &[u8]) -> [u8; 16] {
+    let (k0, k1) = key.split_at(8);
+    let k0 = u64::from_le_bytes(*k0.first_chunk().unwrap());
+    let k1 = u64::from_le_bytes(*k1.first_chunk().unwrap());
+
+    let mut v = [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d ^ 0xee,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+
+    let mut i = 0;
+    while i + 8 <= bytes.len() {
+        let (_, rest) = bytes.split_at(i);
+        let m = u64::from_le_bytes(*rest.first_chunk().unwrap());
+
+        v[3] ^= m;
+        sip_rounds(&mut v, 2);
+        v[0] ^= m;
+
+        i += 8;
+    }
+
+    let mut last = (bytes.len() as u64) << 56;
+    let mut j = 0;
+    while i + j < bytes.len() {
+        last |= (bytes[i + j] as u64) << (8 * j);
+        j += 1;
+    }
+
+    v[3] ^= last;
+    sip_rounds(&mut v, 2);
+    v[0] ^= last;
+
+    v[2] ^= 0xee;
+    sip_rounds(&mut v, 4);
+    let lo = v[0] ^ v[1] ^ v[2] ^ v[3];
+
+    v[1] ^= 0xdd;
+    sip_rounds(&mut v, 4);
+    let hi = v[0] ^ v[1] ^ v[2] ^ v[3];
+
+    (((hi as u128) << 64) | lo as u128).to_le_bytes()
+}
+
+const fn sip_rounds(v: &mut [u64; 4], rounds: usize) {
+    let mut i = 0;
+    while i < rounds {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13);
+        v[1] ^= v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16);
+        v[3] ^= v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21);
+        v[3] ^= v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17);
+        v[1] ^= v[2];
+        v[2] = v[2].rotate_left(32);
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn jump_consistent_hash_moves_keys_to_new_buckets() {
+        for key in (0..1000).map(|i| fnv1a_64(&[i as u8, (i >> 8) as u8])) {
+            let mut previous = jump_consistent_hash(key, 1);
+            assert_eq!(previous, 0);
+
+            for buckets in 2..50 {
+                let bucket = jump_consistent_hash(key, buckets);
+                assert!(bucket < buckets);
+                assert!(bucket == previous || bucket == buckets - 1);
+                previous = bucket;
+            }
+        }
+    }
+
+    #[test]
+    fn siphash_reference_vectors() {
+        let key = core::array::from_fn(|i| i as u8);
+        let input: [u8; 17] = core::array::from_fn(|i| i as u8);
+
+        assert_eq!(
+            siphash_2_4_128(&key, &[]),
+            [
+                0xa3, 0x81, 0x7f, 0x04, 0xba, 0x25, 0xa8, 0xe6, 0x6d, 0xf6, 0x72, 0x14, 0xc7, 0x55,
+                0x02, 0x93,
+            ],
+        );
+        assert_eq!(
+            siphash_2_4_128(&key, &input[..15]),
+            [
+                0x54, 0x93, 0xe9, 0x99, 0x33, 0xb0, 0xa8, 0x11, 0x7e, 0x08, 0xec, 0x0f, 0x97, 0xcf,
+                0xc3, 0xd9,
+            ],
+        );
+        assert_eq!(
+            siphash_2_4_128(&key, &input),
+            [
+                0x47, 0x3d, 0x06, 0xe8, 0x73, 0x8d, 0xb8, 0x98, 0x54, 0xc0, 0x66, 0xc4, 0x7a, 0xe4,
+                0x77, 0x40,
+            ],
+        );
+    }
+}
@@ -0,0 +1,116 @@
+use crate::enid::{Enid40, Enid80};
+use crate::hash::{self, FNV_OFFSET_BASIS_128};
+
+/// A streaming hasher that produces content-addressed ENIDs.
+///
+/// The hash is the 128-bit FNV-1a hash of all the bytes passed to
+/// [`update`](Self::update), and the ENID is made from its most significant
+/// bytes, in big-endian order. The result is stable across platforms and
+/// versions of this crate, but FNV-1a is not a cryptographic hash, so it should
+/// not be used where collisions could be chosen by an attacker.
+///
+/// With the `std` feature, `EnidHasher` implements [`Write`](std::io::Write),
+/// so a file can be hashed without buffering it in memory by copying it into
+/// the hasher with [`std::io::copy`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::EnidHasher;
+/// let mut hasher = EnidHasher::new();
+/// hasher.update(b"hello ");
+/// hasher.update(b"world");
+///
+/// assert_eq!(hasher.finish80().to_string(), "dganf6fx-s3qc9e8n");
+/// assert_eq!(hasher.finish40(), EnidHasher::new().update(b"hello world").finish40());
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EnidHasher {
+    state: u128,
+}
+
+impl EnidHasher {
+    /// Creates a hasher that has not been given any bytes.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            state: FNV_OFFSET_BASIS_128,
+        }
+    }
+
+    /// Adds the given bytes to the hash.
+    #[inline]
+    pub const fn update(&mut self, bytes: &[u8]) -> &mut Self {
+        self.state = hash::fnv1a_128_update(self.state, bytes);
+        self
+    }
+
+    /// Returns a 40-bit ENID made from the hash of the bytes so far.
+    #[must_use]
+    #[inline]
+    pub const fn finish40(&self) -> Enid40 {
+        let h = self.state.to_be_bytes();
+
+        Enid40::from_bytes([h[0], h[1], h[2], h[3], h[4]])
+    }
+
+    /// Returns an 80-bit ENID made from the hash of the bytes so far.
+    #[must_use]
+    #[inline]
+    pub const fn finish80(&self) -> Enid80 {
+        let h = self.state.to_be_bytes();
+
+        Enid80::from_bytes([h[0], h[1], h[2], h[3], h[4], h[5], h[6], h[7], h[8], h[9]])
+    }
+}
+
+impl Default for EnidHasher {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::io::Write for EnidHasher {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        self.update(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_values() {
+        assert_eq!(
+            EnidHasher::new().finish80(),
+            Enid80::from_bytes([0x6c, 0x62, 0x27, 0x2e, 0x07, 0xbb, 0x01, 0x42, 0x62, 0xb8]),
+        );
+        assert_eq!(
+            EnidHasher::new().update(b"hello world").finish80(),
+            Enid80::from_bytes([0x6c, 0x15, 0x57, 0x99, 0xfd, 0xc8, 0xee, 0xc4, 0xb9, 0x15]),
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn io_copy() {
+        let mut hasher = EnidHasher::new();
+        std::io::copy(&mut &b"hello world"[..], &mut hasher).unwrap();
+
+        assert_eq!(
+            hasher.finish40(),
+            Enid40::from_bytes([0x6c, 0x15, 0x57, 0x99, 0xfd]),
+        );
+    }
+}
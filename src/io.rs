@@ -0,0 +1,740 @@
+//! Reading and writing newline-delimited ENIDs.
+//!
+//! Each line holds a single ENID of either size. When reading, ASCII
+//! whitespace around the ENID, including a trailing `\r`, is ignored, as are
+//! blank lines. Lines that are not valid UTF-8 are reported as parse errors.
+//!
+//! ENIDs can also be read and written as bytes, with methods such as
+//! [`Enid80::read_from`] and [`Enid80::write_to`].
+
+use crate::enid::{Enid, Enid40, Enid80, EnidN, EnidParseError};
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+use std::io::{self, BufRead, Read, Write};
+use std::vec::Vec;
+
+/// An error that occurred while reading a line of ENIDs.
+#[derive(Debug)]
+pub struct LineError {
+    line: u64,
+    kind: LineErrorKind,
+}
+
+/// The reason a line of ENIDs could not be read.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum LineErrorKind {
+    /// Reading from the underlying reader failed.
+    Io(io::Error),
+
+    /// The line does not contain a valid ENID.
+    Parse(EnidParseError),
+}
+
+impl LineError {
+    /// Returns the line number at which the error occurred, starting from 1.
+    #[must_use]
+    #[inline]
+    pub const fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the reason for the error.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> &LineErrorKind {
+        &self.kind
+    }
+
+    /// Returns the reason for the error, consuming the error.
+    #[must_use]
+    #[inline]
+    pub fn into_kind(self) -> LineErrorKind {
+        self.kind
+    }
+}
+
+impl Display for LineError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match &self.kind {
+            LineErrorKind::Io(err) => write!(f, "line {}: {err}", self.line),
+            LineErrorKind::Parse(err) => write!(f, "line {}: {err}", self.line),
+        }
+    }
+}
+
+impl Error for LineError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.kind {
+            LineErrorKind::Io(err) => Some(err),
+            LineErrorKind::Parse(err) => Some(err),
+        }
+    }
+}
+
+/// Returns an iterator over the ENIDs in the lines of a reader.
+///
+/// Lines are read one at a time, so the whole input is never held in memory.
+/// A line that cannot be parsed produces an error, and iteration continues with
+/// the next line. An I/O error ends the iteration.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, enid80, Enid};
+/// # use enid::io::parse_lines;
+/// let input = "m6sc7n75\r\n\ny3gx5gxm-kbhqr4fs\nnot an enid\n";
+/// let mut lines = parse_lines(input.as_bytes());
+///
+/// assert_eq!(lines.next().unwrap().unwrap(), Enid::from(enid40!("m6sc7n75")));
+/// assert_eq!(lines.next().unwrap().unwrap(), Enid::from(enid80!("y3gx5gxm-kbhqr4fs")));
+/// assert_eq!(lines.next().unwrap().unwrap_err().line(), 4);
+/// assert!(lines.next().is_none());
+/// ```
+#[inline]
+pub fn parse_lines<R: BufRead>(reader: R) -> ParseLines<R> {
+    ParseLines {
+        reader,
+        buf: Vec::new(),
+        line: 0,
+        done: false,
+    }
+}
+
+/// An iterator over the ENIDs in the lines of a reader.
+///
+/// This is created by [`parse_lines`].
+#[derive(Debug)]
+pub struct ParseLines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    line: u64,
+    done: bool,
+}
+
+impl<R> ParseLines<R> {
+    /// Returns the number of lines read so far.
+    #[must_use]
+    #[inline]
+    pub const fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Iterator for ParseLines<R> {
+    type Item = Result<Enid, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.buf.clear();
+            self.line += 1;
+
+            match self.reader.read_until(b'\n', &mut self.buf) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    if let Some(result) = parse_line(&self.buf, self.line) {
+                        return Some(result);
+                    }
+                }
+                Err(err) => {
+                    self.done = true;
+
+                    return Some(Err(LineError {
+                        line: self.line,
+                        kind: LineErrorKind::Io(err),
+                    }));
+                }
+            }
+        }
+
+        None
+    }
+}
+
+/// Returns a reader over the ENIDs in the lines of an asynchronous reader.
+///
+/// This behaves like [`parse_lines`], with ENIDs read by calling
+/// [`AsyncParseLines::next_enid`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid};
+/// # use enid::io::parse_lines_async;
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut lines = parse_lines_async("m6sc7n75\n".as_bytes());
+///
+/// assert_eq!(lines.next_enid().await.unwrap().unwrap(), Enid::from(enid40!("m6sc7n75")));
+/// assert!(lines.next_enid().await.is_none());
+/// # });
+/// ```
+#[cfg(feature = "tokio")]
+#[inline]
+pub fn parse_lines_async<R: tokio::io::AsyncBufRead + Unpin>(reader: R) -> AsyncParseLines<R> {
+    AsyncParseLines {
+        reader,
+        buf: Vec::new(),
+        line: 0,
+        done: false,
+    }
+}
+
+/// A reader over the ENIDs in the lines of an asynchronous reader.
+///
+/// This is created by [`parse_lines_async`].
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct AsyncParseLines<R> {
+    reader: R,
+    buf: Vec<u8>,
+    line: u64,
+    done: bool,
+}
+
+#[cfg(feature = "tokio")]
+impl<R: tokio::io::AsyncBufRead + Unpin> AsyncParseLines<R> {
+    /// Reads the next ENID, returning `None` at the end of the input.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LineError`] if a line cannot be read or parsed.
+    pub async fn next_enid(&mut self) -> Option<Result<Enid, LineError>> {
+        use tokio::io::AsyncBufReadExt;
+
+        while !self.done {
+            self.buf.clear();
+            self.line += 1;
+
+            match self.reader.read_until(b'\n', &mut self.buf).await {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    if let Some(result) = parse_line(&self.buf, self.line) {
+                        return Some(result);
+                    }
+                }
+                Err(err) => {
+                    self.done = true;
+
+                    return Some(Err(LineError {
+                        line: self.line,
+                        kind: LineErrorKind::Io(err),
+                    }));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the number of lines read so far.
+    #[must_use]
+    #[inline]
+    pub const fn line(&self) -> u64 {
+        self.line
+    }
+
+    /// Returns the underlying reader.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+/// Writes ENIDs to a writer, each on its own line, and returns the number of
+/// ENIDs written.
+///
+/// The lines are collected in a buffer and written in large chunks, so the
+/// writer does not need to be buffered. The output can be read back with
+/// [`parse_lines`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, enid80};
+/// # use enid::io::write_lines;
+/// let enids = [enid40!("m6sc7n75").into(), enid80!("y3gx5gxm-kbhqr4fs").into()];
+/// let mut out = Vec::new();
+/// let count = write_lines(&mut out, enids)?;
+///
+/// assert_eq!(count, 2);
+/// assert_eq!(out, b"m6sc7n75\ny3gx5gxm-kbhqr4fs\n");
+/// # Ok::<(), std::io::Error>(())
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if writing fails. Some of the ENIDs may have been written.
+pub fn write_lines<W: Write>(
+    mut writer: W,
+    enids: impl IntoIterator<Item = Enid>,
+) -> io::Result<u64> {
+    let mut lines = LineBuffer::new();
+    let mut count = 0;
+
+    for enid in enids {
+        if lines.is_full() {
+            writer.write_all(lines.take())?;
+        }
+
+        lines.push(enid);
+        count += 1;
+    }
+
+    writer.write_all(lines.take())?;
+    writer.flush()?;
+
+    Ok(count)
+}
+
+/// Writes ENIDs to an asynchronous writer, each on its own line, and returns
+/// the number of ENIDs written.
+///
+/// This behaves like [`write_lines`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid40;
+/// # use enid::io::write_lines_async;
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut out = Vec::new();
+///
+/// assert_eq!(write_lines_async(&mut out, [enid40!("m6sc7n75").into()]).await?, 1);
+/// assert_eq!(out, b"m6sc7n75\n");
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if writing fails. Some of the ENIDs may have been written.
+#[cfg(feature = "tokio")]
+pub async fn write_lines_async<W: tokio::io::AsyncWrite + Unpin>(
+    mut writer: W,
+    enids: impl IntoIterator<Item = Enid>,
+) -> io::Result<u64> {
+    use tokio::io::AsyncWriteExt;
+
+    let mut lines = LineBuffer::new();
+    let mut count = 0;
+
+    for enid in enids {
+        if lines.is_full() {
+            writer.write_all(lines.take()).await?;
+        }
+
+        lines.push(enid);
+        count += 1;
+    }
+
+    writer.write_all(lines.take()).await?;
+    writer.flush().await?;
+
+    Ok(count)
+}
+
+/// A buffer of ENIDs formatted as lines, so they can be written in large
+/// chunks by [`write_lines`] and [`write_lines_async`].
+struct LineBuffer {
+    buf: [u8; 4096],
+    len: usize,
+}
+
+impl LineBuffer {
+    /// The length of the longest line, an [`Enid80`] and a newline.
+    const MAX_LINE_LEN: usize = Enid80::STR_LEN + 1;
+
+    const fn new() -> Self {
+        Self {
+            buf: [0; 4096],
+            len: 0,
+        }
+    }
+
+    /// Returns whether another line might not fit in the buffer.
+    const fn is_full(&self) -> bool {
+        self.len + Self::MAX_LINE_LEN > self.buf.len()
+    }
+
+    /// Appends an ENID and a newline, which must fit in the buffer.
+    fn push(&mut self, enid: Enid) {
+        let chars = self.buf[self.len..].first_chunk_mut().unwrap();
+        self.len += enid.write_ascii(chars).len();
+        self.buf[self.len] = b'\n';
+        self.len += 1;
+    }
+
+    /// Returns the buffered lines, and empties the buffer.
+    fn take(&mut self) -> &[u8] {
+        let len = core::mem::take(&mut self.len);
+        &self.buf[..len]
+    }
+}
+
+/// Reads the 5 bytes of an [`Enid40`] from an asynchronous reader.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid40;
+/// # use enid::io::read_enid40;
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut input: &[u8] = &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5];
+///
+/// assert_eq!(read_enid40(&mut input).await?, enid40!("m6sc7n75"));
+/// assert!(read_enid40(&mut input).await.is_err());
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if reading fails, including an error of kind
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the reader ends before 5
+/// bytes are read.
+#[cfg(feature = "tokio")]
+#[inline]
+pub async fn read_enid40<R: tokio::io::AsyncRead + Unpin>(reader: &mut R) -> io::Result<Enid40> {
+    read_bytes(reader).await.map(Enid40::from_bytes)
+}
+
+/// Reads the 10 bytes of an [`Enid80`] from an asynchronous reader.
+///
+/// # Errors
+///
+/// Returns an error if reading fails, including an error of kind
+/// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the reader ends before
+/// 10 bytes are read.
+#[cfg(feature = "tokio")]
+#[inline]
+pub async fn read_enid80<R: tokio::io::AsyncRead + Unpin>(reader: &mut R) -> io::Result<Enid80> {
+    read_bytes(reader).await.map(Enid80::from_bytes)
+}
+
+/// Writes the 5 bytes of an [`Enid40`] to an asynchronous writer.
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+#[cfg(feature = "tokio")]
+#[inline]
+pub async fn write_enid40<W: tokio::io::AsyncWrite + Unpin>(
+    writer: &mut W,
+    enid: Enid40,
+) -> io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    writer.write_all(enid.as_bytes()).await
+}
+
+/// Writes the 10 bytes of an [`Enid80`] to an asynchronous writer.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid80;
+/// # use enid::io::{read_enid80, write_enid80};
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let enid = enid80!("y3gx5gxm-mpb8ey39");
+/// let mut buf = Vec::new();
+/// write_enid80(&mut buf, enid).await?;
+///
+/// assert_eq!(read_enid80(&mut buf.as_slice()).await?, enid);
+/// # Ok::<(), std::io::Error>(())
+/// # }).unwrap();
+/// ```
+///
+/// # Errors
+///
+/// Returns an error if writing fails.
+#[cfg(feature = "tokio")]
+#[inline]
+pub async fn write_enid80<W: tokio::io::AsyncWrite + Unpin>(
+    writer: &mut W,
+    enid: Enid80,
+) -> io::Result<()> {
+    use tokio::io::AsyncWriteExt;
+
+    writer.write_all(enid.as_bytes()).await
+}
+
+#[cfg(feature = "tokio")]
+async fn read_bytes<R: tokio::io::AsyncRead + Unpin, const N: usize>(
+    reader: &mut R,
+) -> io::Result<[u8; N]> {
+    use tokio::io::AsyncReadExt;
+
+    let mut bytes = [0; N];
+    reader.read_exact(&mut bytes).await?;
+
+    Ok(bytes)
+}
+
+impl<const N: usize> EnidN<N> {
+    /// Reads the bytes of an ENID from a reader.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let mut input: &[u8] = &[0xa1, 0xb2, 0xc3, 0xd4, 0xe5];
+    ///
+    /// assert_eq!(Enid40::read_from(&mut input)?, enid40!("m6sc7n75"));
+    /// assert!(Enid40::read_from(&mut input).is_err());
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, including an error of kind
+    /// [`UnexpectedEof`](io::ErrorKind::UnexpectedEof) if the reader ends before
+    /// `N` bytes are read.
+    #[inline]
+    pub fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = [0; N];
+        reader.read_exact(&mut bytes)?;
+
+        Ok(Self::from_bytes(bytes))
+    }
+
+    /// Writes the bytes of the ENID to a writer.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let mut out = Vec::new();
+    /// enid80!("y3gx5gxm-mpb8ey39").write_to(&mut out)?;
+    ///
+    /// assert_eq!(out, [0xf0, 0xe1, 0xd2, 0xc3, 0xb4, 0xa5, 0x96, 0x87, 0x78, 0x69]);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    #[inline]
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+}
+
+impl Enid {
+    /// Reads an ENID of either size from a reader, as a tag byte followed by
+    /// the bytes of the ENID.
+    ///
+    /// The tag is `0` for an [`Enid40`] and `1` for an [`Enid80`], which is the
+    /// same layout as the `borsh` encoding and the `EnidCodec` of the
+    /// `tokio-util` feature.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// let mut input: &[u8] = &[0, 0xa1, 0xb2, 0xc3, 0xd4, 0xe5];
+    ///
+    /// assert_eq!(Enid::read_from(&mut input)?, enid!("m6sc7n75"));
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, or an error of kind
+    /// [`InvalidData`](io::ErrorKind::InvalidData) if the tag is not `0` or
+    /// `1`.
+    pub fn read_from<R: Read + ?Sized>(reader: &mut R) -> io::Result<Self> {
+        let mut tag = 0;
+        reader.read_exact(core::slice::from_mut(&mut tag))?;
+
+        match tag {
+            0 => Enid40::read_from(reader).map(Self::Enid40),
+            1 => Enid80::read_from(reader).map(Self::Enid80),
+            tag => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                std::format!("invalid Enid tag: {tag}"),
+            )),
+        }
+    }
+
+    /// Writes the ENID to a writer, as a tag byte followed by the bytes of
+    /// the ENID.
+    ///
+    /// See [`read_from`](Self::read_from) for the layout.
+    ///
+    /// This requires the `std` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// let enid = enid!("y3gx5gxm-mpb8ey39");
+    /// let mut out = Vec::new();
+    /// enid.write_to(&mut out)?;
+    ///
+    /// assert_eq!(out.len(), 11);
+    /// assert_eq!(Enid::read_from(&mut out.as_slice())?, enid);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn write_to<W: Write + ?Sized>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Self::Enid40(enid) => {
+                writer.write_all(&[0])?;
+                enid.write_to(writer)
+            }
+            Self::Enid80(enid) => {
+                writer.write_all(&[1])?;
+                enid.write_to(writer)
+            }
+        }
+    }
+}
+
+fn parse_line(line: &[u8], line_number: u64) -> Option<Result<Enid, LineError>> {
+    let line = line.trim_ascii();
+
+    if line.is_empty() {
+        return None;
+    }
+
+    Some(Enid::parse_str_ascii(line).map_err(|err| LineError {
+        line: line_number,
+        kind: LineErrorKind::Parse(err),
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enid40;
+
+    struct FailingReader;
+
+    impl io::Read for FailingReader {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("failed"))
+        }
+    }
+
+    #[test]
+    fn parse_text_lines() {
+        let input = "  m6sc7n75  \n\nm6sc7n7u\nm6sc7n75";
+        let results: std::vec::Vec<_> = parse_lines(input.as_bytes()).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &Enid::from(enid40!("m6sc7n75"))
+        );
+        assert_eq!(results[1].as_ref().unwrap_err().line(), 3);
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &Enid::from(enid40!("m6sc7n75"))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_utf8() {
+        let input = b"m6sc7n75\n\xff\xfe\ny3gx5gxm-mpb8ey39\n";
+        let results: std::vec::Vec<_> = parse_lines(input.as_slice()).collect();
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &Enid::from(enid40!("m6sc7n75"))
+        );
+
+        let err = results[1].as_ref().unwrap_err();
+        assert_eq!(err.line(), 2);
+        assert!(matches!(err.kind(), LineErrorKind::Parse(_)));
+        assert!(matches!(results[2], Ok(Enid::Enid80(_))));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn parse_invalid_utf8_async() {
+        let input = b"m6sc7n75\n\xff\xfe\ny3gx5gxm-mpb8ey39\n";
+        let mut lines = parse_lines_async(input.as_slice());
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+
+        runtime.block_on(async {
+            assert!(lines.next_enid().await.unwrap().is_ok());
+
+            let err = lines.next_enid().await.unwrap().unwrap_err();
+            assert_eq!(err.line(), 2);
+            assert!(matches!(err.kind(), LineErrorKind::Parse(_)));
+            assert!(lines.next_enid().await.unwrap().is_ok());
+            assert!(lines.next_enid().await.is_none());
+        });
+    }
+
+    #[test]
+    fn write_text_lines() {
+        let enids: std::vec::Vec<Enid> = (0..1000u64)
+            .map(|i| {
+                if i % 2 == 0 {
+                    Enid40::from_u64(i).unwrap().into()
+                } else {
+                    Enid80::from_words(1, i).into()
+                }
+            })
+            .collect();
+        let mut out = std::vec::Vec::new();
+
+        assert_eq!(write_lines(&mut out, enids.iter().copied()).unwrap(), 1000);
+        assert_eq!(out.len(), 500 * 9 + 500 * 18);
+        assert!(parse_lines(out.as_slice()).map(Result::unwrap).eq(enids));
+    }
+
+    #[test]
+    fn binary() {
+        let mut buf = std::vec::Vec::new();
+        let enids = [
+            Enid::from(enid40!("m6sc7n75")),
+            Enid::from(Enid80::from_words(1, 2)),
+        ];
+
+        for enid in enids {
+            enid.write_to(&mut buf).unwrap();
+        }
+
+        let mut input = buf.as_slice();
+
+        assert_eq!(Enid::read_from(&mut input).unwrap(), enids[0]);
+        assert_eq!(Enid::read_from(&mut input).unwrap(), enids[1]);
+        assert!(input.is_empty());
+
+        let err = Enid::read_from(&mut [2, 0, 0, 0, 0, 0].as_slice()).unwrap_err();
+
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn io_error() {
+        let mut lines = parse_lines(io::BufReader::new(FailingReader));
+
+        let err = lines.next().unwrap().unwrap_err();
+        assert_eq!(err.line(), 1);
+        assert!(matches!(err.kind(), LineErrorKind::Io(_)));
+        assert!(lines.next().is_none());
+    }
+}
@@ -0,0 +1,104 @@
+use crate::enid::{Enid40, Enid80};
+use crate::hash;
+use core::fmt::{self, Debug, Formatter};
+
+/// Derives ENIDs from external identifiers using a secret key.
+///
+/// The ENID is made from the first bytes of the 128-bit SipHash-2-4 hash of the
+/// input, keyed with a 128-bit secret. The same input and key always produce
+/// the same ENID, but without the key, the input cannot be recovered or
+/// confirmed by hashing guesses. This is useful for pseudonymizing user
+/// identifiers in analytics, where records must be linked without exposing the
+/// original IDs.
+///
+/// SipHash is a pseudo-random function, not a general-purpose cryptographic
+/// hash, so the key should be random and kept secret. The derivation will never
+/// change, so ENIDs derived with the same key remain stable across versions of
+/// this crate.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::KeyedDeriver;
+/// let deriver = KeyedDeriver::new([0x42; 16]);
+///
+/// assert_eq!(deriver.derive80(b"user@example.com").to_string(), "1q6qvndw-dz2j4gjy");
+/// assert_eq!(deriver.derive40(b"user@example.com"), deriver.derive40(b"user@example.com"));
+/// assert_ne!(deriver.derive40(b"user@example.com"), deriver.derive40(b"user@example.org"));
+/// ```
+#[derive(Clone)]
+pub struct KeyedDeriver {
+    key: [u8; 16],
+}
+
+impl KeyedDeriver {
+    /// Creates a deriver with the given secret key.
+    #[must_use]
+    #[inline]
+    pub const fn new(key: [u8; 16]) -> Self {
+        Self { key }
+    }
+
+    /// Derives a 40-bit ENID from the input.
+    #[must_use]
+    #[inline]
+    pub const fn derive40(&self, input: &[u8]) -> Enid40 {
+        let hash = hash::siphash_2_4_128(&self.key, input);
+
+        Enid40::from_bytes(*hash.first_chunk().unwrap())
+    }
+
+    /// Derives an 80-bit ENID from the input.
+    #[must_use]
+    #[inline]
+    pub const fn derive80(&self, input: &[u8]) -> Enid80 {
+        let hash = hash::siphash_2_4_128(&self.key, input);
+
+        Enid80::from_bytes(*hash.first_chunk().unwrap())
+    }
+}
+
+impl Debug for KeyedDeriver {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("KeyedDeriver").finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KeyedDeriver;
+    use crate::enid40;
+    use std::format;
+
+    #[test]
+    fn derive() {
+        let deriver = KeyedDeriver::new([0x42; 16]);
+        let enid40 = deriver.derive40(b"user@example.com");
+        let enid80 = deriver.derive80(b"user@example.com");
+
+        assert_eq!(enid40, enid40!("1q6qvndw"));
+        assert_eq!(enid40.as_bytes(), enid80.as_bytes().first_chunk().unwrap());
+        assert_eq!(deriver.derive40(b""), enid40!("14erwbqq"));
+    }
+
+    #[test]
+    fn key_changes_output() {
+        let a = KeyedDeriver::new([0x42; 16]);
+        let mut key = [0x42; 16];
+        key[15] ^= 1;
+        let b = KeyedDeriver::new(key);
+
+        assert_ne!(
+            a.derive80(b"user@example.com"),
+            b.derive80(b"user@example.com")
+        );
+        assert_eq!(a.clone().derive80(b"x"), a.derive80(b"x"));
+    }
+
+    #[test]
+    fn debug_redacts_key() {
+        let deriver = KeyedDeriver::new([0x42; 16]);
+
+        assert_eq!(format!("{deriver:?}"), "KeyedDeriver { .. }");
+    }
+}
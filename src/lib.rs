@@ -18,8 +18,13 @@
 //! (Crockford's Base32) that excludes the letters `i`, `l`, `o`, and `u`. Each
 //! group of 40 bits is represented by 8 characters and separated by a hyphen.
 //!
-//! This crate does not yet include a method for generating ENIDs, which will be
-//! added in a future version.
+//! Longer ENIDs are also available: [`Enid120`] holds 120 bits as three
+//! groups of 8 characters, and, where more collision resistance is needed,
+//! [`Enid160`] holds 160 bits as four groups. All of these are aliases of
+//! [`EnidN`], which can be used to define ENIDs of other sizes.
+//!
+//! Random ENIDs can be generated with the `getrandom` or `rand` features, and
+//! ENIDs that sort by creation time can be generated with [`TimeOrdered`].
 //!
 //! Some features of ENIDs:
 //!
@@ -30,27 +35,164 @@
 //!
 //! # Crate features
 //!
+//! * `alloc` - adds functionality that depends on heap allocation, such as the
+//!   [`prefix`] module.
 //! * `arbitrary` - adds [`Arbitrary`](arbitrary::Arbitrary) implementations for
 //!   fuzzing.
+//! * `arrayvec` - adds conversions to [`ArrayString`](arrayvec::ArrayString), such
+//!   as [`Enid80::to_array_string`].
+//! * `base64` - adds conversions to and from unpadded base64url, such as
+//!   [`Enid80::write_base64url`].
 //! * `borsh` - adds serialization and deserialization via [`borsh`].
 //! * `bytemuck` - adds [`Pod`](bytemuck::Pod) implementations for byte
-//!   manipulation.
+//!   manipulation, and zero-copy views of packed byte slices, such as
+//!   [`Enid40::slice_from_bytes`].
+//! * `bytes` - adds reading and writing with [`Buf`](bytes::Buf) and
+//!   [`BufMut`](bytes::BufMut), such as [`Enid80::get_from`].
+//! * `chrono` - adds conversions between time-ordered ENIDs and
+//!   [`chrono::DateTime`], such as [`Enid80::from_datetime`].
+//! * `cli` - builds the `enid` command-line tool, for generating, inspecting,
+//!   and converting ENIDs.
+//! * `derive` - adds [`EnidId`], a derive macro for structs wrapping an ENID.
+//! * `digest` - adds [`Enid40::from_digest`] and [`Enid80::from_digest`], for
+//!   creating content-addressed ENIDs with any [`Digest`](digest::Digest)
+//!   hasher.
+//! * `embedded-time` - adds
+//!   [`EmbeddedTimeClock`](clock::EmbeddedTimeClock), which reads the time from
+//!   an [`embedded_time::Clock`].
+//! * `getrandom` - adds [`Enid40::new`] and [`Enid80::new`], which generate
+//!   random ENIDs using the operating system's random number generator via
+//!   [`getrandom`], without the rest of `rand`.
+//! * `heapless` - adds conversions to and from [`heapless::String`], such as
+//!   [`Enid80::to_heapless`].
+//! * `jiff` - adds conversions between time-ordered ENIDs and
+//!   [`jiff::Timestamp`], such as [`Enid80::from_jiff_timestamp`].
+//! * `js` - adds conversions to and from [`JsValue`](wasm_bindgen::JsValue) and
+//!   [`Uint8Array`](js_sys::Uint8Array), compatible with `serde-wasm-bindgen`.
+//! * `nom` - adds the [`nom`] module, with parsers for use with
+//!   [`nom`](::nom).
 //! * `quickcheck` - adds [`Arbitrary`](quickcheck::Arbitrary) implementations
 //!   for fuzzing.
-//! * `serde` - adds serialization and deserialization via [`serde`].
+//! * `rand` - adds methods for generating random ENIDs with [`rand`], such as
+//!   [`Enid80::random_with`]. With the `std` feature, this also adds methods
+//!   that use the thread-local generator, such as [`Enid80::random`].
+//! * `safe` - removes all `unsafe` code from the crate, other than the trait
+//!   implementations required by `bytemuck`. Without `bytemuck`, this is
+//!   enforced with `forbid(unsafe_code)`. Each string returned or written when
+//!   formatting an ENID is validated as UTF-8, which can double the time taken
+//!   to format an ENID; `benches/format.rs` compares the two builds.
+//! * `sea-query` - adds conversions to and from [`sea_query::Value`], for
+//!   binding ENIDs in dynamically built queries.
+//! * `serde` - adds serialization and deserialization via [`serde`](::serde),
+//!   and the helpers in the [`serde`] module.
+//! * `sha2` - adds [`Enid40::from_name`] and [`Enid80::from_name`], which
+//!   derive ENIDs from names by hashing them with SHA-256.
 //! * `slog` - adds [`Value`](slog::Value) implementations for serialization.
+//! * `std` - adds functionality that depends on the standard library, such as
+//!   [`SystemClock`](clock::SystemClock), [`Enid::from_env`], and the [`io`] and
+//!   [`pg_copy`] modules.
+//! * `testing` - adds the [`testing`] module, with utilities for testing code
+//!   that handles ENIDs.
+//! * `time` - adds conversions between time-ordered ENIDs and
+//!   [`time::OffsetDateTime`], such as [`Enid80::from_offset_datetime`].
+//! * `tokio` - adds asynchronous reading and writing with [`tokio`], such as
+//!   [`parse_lines_async`](io::parse_lines_async) and
+//!   [`read_enid40`](io::read_enid40).
+//! * `tokio-util` - adds the [`codec`] module, with codecs for framing streams
+//!   of ENIDs.
+//! * `tower` - adds the [`tower`] module, with middleware for
+//!   assigning request IDs.
+//! * `unicode` - adds parsing of strings containing Unicode lookalikes of ENID
+//!   characters, such as [`Enid40::parse_str_unicode_lenient`].
+//! * `zbus` - adds [`Type`](zvariant::Type) implementations, for sending ENIDs
+//!   over D-Bus with `zbus`.
 
 #![no_std]
 #![cfg_attr(docsrs, feature(doc_cfg))]
+#![cfg_attr(all(feature = "safe", not(feature = "bytemuck")), forbid(unsafe_code))]
+// `forbid` can't be overridden, and the `bytemuck` module needs to allow it.
+#![cfg_attr(all(feature = "safe", feature = "bytemuck"), deny(unsafe_code))]
 
-#[cfg(test)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(any(test, feature = "std"))]
 extern crate std;
 
-mod base32;
+mod ascii;
+#[cfg(target_has_atomic = "64")]
+mod atomic;
+pub mod base32;
+mod base58;
+mod bits;
+mod cached;
+mod checked;
+pub mod clock;
+#[cfg(feature = "tokio-util")]
+pub mod codec;
 mod enid;
+mod enid_prefix;
+mod enid_string;
+#[cfg(feature = "std")]
+mod env;
+mod expiring;
 mod external;
+pub mod format;
+pub mod generator;
+mod hash;
+mod hasher;
+#[cfg(feature = "std")]
+pub mod io;
+mod keyed;
+mod list;
+#[cfg(feature = "alloc")]
+mod many;
+#[cfg(feature = "nom")]
+pub mod nom;
+mod non_nil;
+pub mod obfuscate;
+pub mod permutation;
+#[cfg(feature = "std")]
+pub mod pg_copy;
+#[cfg(feature = "alloc")]
+pub mod prefix;
+pub mod prefixed;
+mod range;
+pub mod routing;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "testing")]
+pub mod testing;
+mod time_ordered;
+#[cfg(feature = "tower")]
+pub mod tower;
+mod varint;
+mod z85;
 
-pub use self::enid::{Enid, Enid40, Enid80, EnidParseError};
+#[cfg(target_has_atomic = "64")]
+pub use self::atomic::AtomicEnid40;
+pub use self::cached::CachedEnid;
+pub use self::checked::{CheckedEnid40, CheckedEnid80, CheckedEnidN};
+pub use self::enid::{
+    BufferTooSmallError, Enid, Enid40, Enid80, Enid120, Enid160, EnidLengthError, EnidN,
+    EnidParseError, EnidParseErrorDetailed, EnidSizeError,
+};
+pub use self::enid_prefix::EnidPrefix;
+pub use self::enid_string::EnidString;
+#[cfg(feature = "std")]
+pub use self::env::{EnvError, EnvErrorKind};
+pub use self::expiring::ExpiringEnid80;
+#[cfg(feature = "bytemuck")]
+pub use self::external::EnidSliceError;
+pub use self::hasher::EnidHasher;
+pub use self::keyed::KeyedDeriver;
+pub use self::list::{ParseList, parse_list};
+pub use self::non_nil::{
+    NilEnidError, NonNilEnid40, NonNilEnid80, NonNilEnidN, OptionEnid40, OptionEnid80, OptionEnidN,
+};
+pub use self::range::{EnidRange, EnidRangeInclusive};
+pub use self::time_ordered::TimeOrdered;
+#[cfg(feature = "derive")]
+pub use enid_derive::EnidId;
 
 /// Creates an [`Enid40`] by parsing the given string at compile-time.
 ///
@@ -112,6 +254,66 @@ macro_rules! enid80 {
     }};
 }
 
+/// Creates an [`Enid120`] by parsing the given string at compile-time.
+///
+/// An invalid ENID string will cause a compilation error.
+///
+/// # Examples
+///
+/// Parsing an ENID at compile-time:
+///
+/// ```
+/// # use enid::{enid120, Enid120};
+/// const ENID: Enid120 = enid120!("y3gx5gxm-mpb8ey39-04hmasw9");
+/// ```
+///
+/// An invalid ENID will not compile:
+///
+/// ```compile_fail
+/// # use enid::{enid120, Enid120};
+/// const ENID: Enid120 = enid120!("y3gx5gxm-mpb8ey39");
+/// ```
+#[macro_export]
+macro_rules! enid120 {
+    ($s:expr) => {{
+        const ENID: $crate::Enid120 = match $crate::Enid120::parse_str($s) {
+            Ok(enid) => enid,
+            Err(_) => panic!("invalid ENID"),
+        };
+        ENID
+    }};
+}
+
+/// Creates an [`Enid160`] by parsing the given string at compile-time.
+///
+/// An invalid ENID string will cause a compilation error.
+///
+/// # Examples
+///
+/// Parsing an ENID at compile-time:
+///
+/// ```
+/// # use enid::{enid160, Enid160};
+/// const ENID: Enid160 = enid160!("y3gx5gxm-mpb8ey39-04hmasw9-nf6yy00h");
+/// ```
+///
+/// An invalid ENID will not compile:
+///
+/// ```compile_fail
+/// # use enid::{enid160, Enid160};
+/// const ENID: Enid160 = enid160!("y3gx5gxm-mpb8ey39");
+/// ```
+#[macro_export]
+macro_rules! enid160 {
+    ($s:expr) => {{
+        const ENID: $crate::Enid160 = match $crate::Enid160::parse_str($s) {
+            Ok(enid) => enid,
+            Err(_) => panic!("invalid ENID"),
+        };
+        ENID
+    }};
+}
+
 /// Creates an [`Enid`] by parsing the given string at compile-time.
 ///
 /// An invalid ENID string will cause a compilation error.
@@ -142,3 +344,255 @@ macro_rules! enid {
         ENID
     }};
 }
+
+/// Defines a newtype around an ENID, for IDs of a particular kind of entity.
+///
+/// The newtype implements [`Display`](core::fmt::Display),
+/// [`FromStr`](core::str::FromStr), [`Debug`], [`Hash`], ordering, and
+/// conversions to and from the ENID. With the `serde` feature, it also
+/// implements [`Serialize`](::serde::Serialize) and
+/// [`Deserialize`](::serde::Deserialize).
+///
+/// The ENID type is one of [`Enid40`], [`Enid80`], [`Enid120`], [`Enid160`], or
+/// [`Enid`], and does not need to be imported. An optional prefix adds a
+/// type prefix to the string form, as with [`Prefixed`](prefixed::Prefixed),
+/// which is checked when parsing. An invalid prefix will not compile.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{define_enid_type, enid80};
+/// define_enid_type! {
+///     /// The ID of a user.
+///     pub UserId: Enid80, prefix = "usr"
+/// }
+///
+/// define_enid_type!(OrderId: Enid40);
+///
+/// let user = UserId::new(enid80!("y3gx5gxm-mpb8ey39"));
+///
+/// assert_eq!(user.to_string(), "usr_y3gx5gxm-mpb8ey39");
+/// assert_eq!("usr_y3gx5gxm-mpb8ey39".parse(), Ok(user));
+/// assert!("y3gx5gxm-mpb8ey39".parse::<UserId>().is_err());
+///
+/// let order: OrderId = "m6sc7n75".parse()?;
+///
+/// assert_eq!(order.to_string(), "m6sc7n75");
+/// # Ok::<(), enid::EnidParseError>(())
+/// ```
+///
+/// An invalid prefix will not compile:
+///
+/// ```compile_fail
+/// # use enid::define_enid_type;
+/// define_enid_type!(UserId: Enid80, prefix = "User");
+/// ```
+#[macro_export]
+macro_rules! define_enid_type {
+    ($(#[$meta:meta])* $vis:vis $name:ident: $enid:ident $(,)?) => {
+        $crate::define_enid_type!(@common $(#[$meta])* $vis $name: $enid);
+
+        impl ::core::fmt::Display for $name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::EnidParseError;
+
+            #[inline]
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                s.parse().map(Self)
+            }
+        }
+
+        $crate::__define_enid_type_serde!($name, $crate::$enid);
+    };
+    ($(#[$meta:meta])* $vis:vis $name:ident: $enid:ident, prefix = $prefix:literal $(,)?) => {
+        $crate::define_enid_type!(@common $(#[$meta])* $vis $name: $enid);
+        $crate::__define_enid_type_prefixed!($name, $crate::$enid, $prefix);
+        $crate::__define_enid_type_serde!($name, prefixed);
+    };
+    (@common $(#[$meta:meta])* $vis:vis $name:ident: $enid:ident) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        #[repr(transparent)]
+        $vis struct $name($crate::$enid);
+
+        impl $name {
+            /// Wraps the ENID.
+            #[must_use]
+            #[inline]
+            pub const fn new(enid: $crate::$enid) -> Self {
+                Self(enid)
+            }
+
+            /// Returns the ENID.
+            #[must_use]
+            #[inline]
+            pub const fn get(self) -> $crate::$enid {
+                self.0
+            }
+        }
+
+        impl ::core::fmt::Debug for $name {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::fmt::Display::fmt(self, f)
+            }
+        }
+
+        impl ::core::convert::From<$crate::$enid> for $name {
+            #[inline]
+            fn from(enid: $crate::$enid) -> Self {
+                Self(enid)
+            }
+        }
+
+        impl ::core::convert::From<$name> for $crate::$enid {
+            #[inline]
+            fn from(id: $name) -> Self {
+                id.0
+            }
+        }
+
+        impl ::core::convert::AsRef<$crate::$enid> for $name {
+            #[inline]
+            fn as_ref(&self) -> &$crate::$enid {
+                &self.0
+            }
+        }
+    };
+}
+
+/// Implements `PREFIX`, `Display`, and `FromStr` for a prefixed ENID newtype,
+/// which must implement `From` and `AsRef` for the ENID type. This is shared
+/// by `define_enid_type!` and the `EnidId` derive.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_enid_type_prefixed {
+    ($name:ident, $enid:ty, $prefix:expr) => {
+        const _: () = ::core::assert!(
+            $crate::prefixed::is_valid_prefix($prefix),
+            "invalid ENID prefix",
+        );
+
+        impl $name {
+            /// The prefix of the string form.
+            pub const PREFIX: &'static str = $prefix;
+        }
+
+        impl ::core::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                ::core::write!(
+                    f,
+                    "{}{}{}",
+                    Self::PREFIX,
+                    $crate::prefixed::SEPARATOR,
+                    ::core::convert::AsRef::<$enid>::as_ref(self),
+                )
+            }
+        }
+
+        impl ::core::str::FromStr for $name {
+            type Err = $crate::prefixed::PrefixedParseError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                use $crate::prefixed::PrefixedParseError;
+
+                let (prefix, enid) = s
+                    .rsplit_once($crate::prefixed::SEPARATOR)
+                    .ok_or(PrefixedParseError::MissingPrefix)?;
+
+                if prefix != Self::PREFIX {
+                    return ::core::result::Result::Err(PrefixedParseError::WrongPrefix);
+                }
+
+                <$enid as ::core::str::FromStr>::from_str(enid)
+                    .map(<Self as ::core::convert::From<$enid>>::from)
+                    .map_err(PrefixedParseError::InvalidEnid)
+            }
+        }
+    };
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_enid_type_serde {
+    ($name:ident, prefixed) => {
+        impl $crate::__private::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.collect_str(self)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                use $crate::__private::serde::de::{Error, Unexpected, Visitor};
+
+                struct IdVisitor;
+
+                impl Visitor<'_> for IdVisitor {
+                    type Value = $name;
+
+                    fn expecting(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        ::core::write!(f, "a prefixed ENID starting with `{}_`", $name::PREFIX)
+                    }
+
+                    fn visit_str<E: Error>(self, s: &str) -> ::core::result::Result<$name, E> {
+                        s.parse()
+                            .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+                    }
+                }
+
+                deserializer.deserialize_str(IdVisitor)
+            }
+        }
+    };
+    ($name:ident, $enid:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            #[inline]
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                let enid: &$enid = ::core::convert::AsRef::as_ref(self);
+                $crate::__private::serde::Serialize::serialize(enid, serializer)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            #[inline]
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                <$enid as $crate::__private::serde::Deserialize>::deserialize(deserializer)
+                    .map(<$name as ::core::convert::From<$enid>>::from)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __define_enid_type_serde {
+    ($($tt:tt)*) => {};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
@@ -0,0 +1,87 @@
+use crate::enid::{Enid, EnidParseError};
+use core::iter::FusedIterator;
+use core::str::Split;
+
+/// Parses a list of ENIDs separated by `sep`, such as the value of a query
+/// parameter like `ids=m6sc7n75,y3gx5gxm-mpb8ey39`.
+///
+/// Whitespace around each ENID is ignored. A string that is empty or only
+/// whitespace is an empty list, but an empty item within a list, such as from
+/// a trailing separator, is an error.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid, parse_list};
+/// let mut enids = parse_list("m6sc7n75, y3gx5gxm-mpb8ey39 ,bad", ',');
+///
+/// assert_eq!(enids.next(), Some(Ok(enid!("m6sc7n75"))));
+/// assert_eq!(enids.next(), Some(Ok(enid!("y3gx5gxm-mpb8ey39"))));
+/// assert!(enids.next().unwrap().is_err());
+/// assert_eq!(enids.next(), None);
+///
+/// assert_eq!(parse_list(" ", ',').count(), 0);
+/// ```
+#[inline]
+pub fn parse_list(s: &str, sep: char) -> ParseList<'_> {
+    let s = s.trim();
+    let mut items = s.split(sep);
+
+    if s.is_empty() {
+        items.next();
+    }
+
+    ParseList { items }
+}
+
+/// An iterator over the ENIDs in a separated list.
+///
+/// This is created by [`parse_list`].
+#[derive(Debug, Clone)]
+pub struct ParseList<'a> {
+    items: Split<'a, char>,
+}
+
+impl Iterator for ParseList<'_> {
+    type Item = Result<Enid, EnidParseError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.items.next().map(|item| Enid::parse_str(item.trim()))
+    }
+}
+
+impl DoubleEndedIterator for ParseList<'_> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.items
+            .next_back()
+            .map(|item| Enid::parse_str(item.trim()))
+    }
+}
+
+impl FusedIterator for ParseList<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_list;
+    use crate::enid;
+
+    #[test]
+    fn separators() {
+        let enid = enid!("m6sc7n75");
+
+        assert!(parse_list("m6sc7n75 ;\tm6sc7n75", ';').eq([Ok(enid), Ok(enid)]));
+        assert!(parse_list("m6sc7n75 m6sc7n75", ' ').eq([Ok(enid), Ok(enid)]));
+        assert_eq!(parse_list("m6sc7n75,", ',').count(), 2);
+        assert!(parse_list("m6sc7n75,", ',').nth(1).unwrap().is_err());
+        assert_eq!(parse_list("", ',').next(), None);
+        assert_eq!(
+            parse_list("m6sc7n75,,m6sc7n75", ',')
+                .filter(Result::is_err)
+                .count(),
+            1
+        );
+        assert_eq!(parse_list("m6sc7n75", ',').next_back(), Some(Ok(enid)));
+    }
+}
@@ -0,0 +1,175 @@
+use crate::enid::{Enid, Enid80, EnidN, EnidParseError};
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+
+impl<const N: usize> EnidN<N> {
+    /// Parses every string from an iterator, stopping at the first string
+    /// that is not a valid ENID.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40, EnidParseError};
+    /// let enids = Enid40::parse_many(["m6sc7n75", "zzzzzzzz"]);
+    ///
+    /// assert_eq!(enids, Ok(vec![enid40!("m6sc7n75"), enid40!("zzzzzzzz")]));
+    /// assert_eq!(
+    ///     Enid40::parse_many("m6sc7n75,zzzzzzz,0".split(',')),
+    ///     Err((1, EnidParseError)),
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first invalid string, along with an
+    /// [`EnidParseError`].
+    pub fn parse_many<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Vec<Self>, (usize, EnidParseError)> {
+        parse_many(inputs, Self::parse_str)
+    }
+
+    /// Formats ENIDs into a single string, with a separator between each
+    /// one.
+    ///
+    /// The string is allocated once, at the size needed for every ENID, when
+    /// the length of the iterator is known.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let enids = [enid40!("m6sc7n75"), enid40!("zzzzzzzz")];
+    ///
+    /// assert_eq!(Enid40::format_many(&enids, ","), "m6sc7n75,zzzzzzzz");
+    /// ```
+    #[must_use]
+    pub fn format_many<'a>(enids: impl IntoIterator<Item = &'a Self>, separator: &str) -> String {
+        format_many(enids, separator, Self::STR_LEN, Self::format_into)
+    }
+}
+
+impl Enid {
+    /// Parses every string from an iterator as an ENID of either size,
+    /// stopping at the first string that is not a valid ENID.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// let enids = Enid::parse_many(["m6sc7n75", "y3gx5gxm-mpb8ey39"]);
+    ///
+    /// assert_eq!(enids, Ok(vec![enid!("m6sc7n75"), enid!("y3gx5gxm-mpb8ey39")]));
+    /// assert!(Enid::parse_many(["m6sc7n75", ""]).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns the index of the first invalid string, along with an
+    /// [`EnidParseError`].
+    pub fn parse_many<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+    ) -> Result<Vec<Self>, (usize, EnidParseError)> {
+        parse_many(inputs, Self::parse_str)
+    }
+
+    /// Formats ENIDs of either size into a single string, with a separator
+    /// between each one.
+    ///
+    /// This requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// let enids = [enid!("m6sc7n75"), enid!("y3gx5gxm-mpb8ey39")];
+    ///
+    /// assert_eq!(Enid::format_many(&enids, "\n"), "m6sc7n75\ny3gx5gxm-mpb8ey39");
+    /// ```
+    #[must_use]
+    pub fn format_many<'a>(enids: impl IntoIterator<Item = &'a Self>, separator: &str) -> String {
+        // Size for the longest ENID, as the widths in the iterator are unknown
+        // until it is consumed.
+        format_many(enids, separator, Enid80::STR_LEN, Self::format_into)
+    }
+}
+
+fn parse_many<'a, T>(
+    inputs: impl IntoIterator<Item = &'a str>,
+    parse: impl Fn(&str) -> Result<T, EnidParseError>,
+) -> Result<Vec<T>, (usize, EnidParseError)> {
+    let inputs = inputs.into_iter();
+    let mut enids = Vec::with_capacity(inputs.size_hint().0);
+
+    for (i, s) in inputs.enumerate() {
+        match parse(s) {
+            Ok(enid) => enids.push(enid),
+            Err(e) => return Err((i, e)),
+        }
+    }
+
+    Ok(enids)
+}
+
+fn format_many<'a, T: 'a>(
+    enids: impl IntoIterator<Item = &'a T>,
+    separator: &str,
+    max_len: usize,
+    format: impl Fn(&T, &mut String) -> fmt::Result,
+) -> String {
+    let enids = enids.into_iter();
+    let mut s = String::with_capacity(enids.size_hint().0 * (max_len + separator.len()));
+
+    for (i, enid) in enids.enumerate() {
+        if i > 0 {
+            s.push_str(separator);
+        }
+
+        // Writing to a `String` cannot fail.
+        let _ = format(enid, &mut s);
+    }
+
+    s
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Enid, Enid40, Enid80, enid, enid40, enid80};
+
+    #[test]
+    fn format_empty() {
+        assert_eq!(Enid40::format_many(&[], ","), "");
+        assert_eq!(Enid80::format_many(&[], ","), "");
+        assert_eq!(Enid::format_many(&[], ","), "");
+    }
+
+    #[test]
+    fn format_single() {
+        assert_eq!(Enid40::format_many(&[enid40!("m6sc7n75")], ","), "m6sc7n75");
+        assert_eq!(
+            Enid80::format_many(&[enid80!("y3gx5gxm-mpb8ey39")], ","),
+            "y3gx5gxm-mpb8ey39"
+        );
+        assert_eq!(Enid::format_many(&[enid!("m6sc7n75")], ", "), "m6sc7n75");
+    }
+
+    #[test]
+    fn format_mixed_widths() {
+        let enids = [
+            enid!("y3gx5gxm-mpb8ey39"),
+            enid!("m6sc7n75"),
+            enid!("zzzzzzzz-00000000"),
+        ];
+        let s = Enid::format_many(&enids, ", ");
+
+        assert_eq!(s, "y3gx5gxm-mpb8ey39, m6sc7n75, zzzzzzzz-00000000");
+        assert_eq!(Enid::parse_many(s.split(", ")), Ok(enids.to_vec()));
+    }
+}
@@ -0,0 +1,156 @@
+//! Parsers for ENIDs, for use with [`nom`].
+//!
+//! The parsers consume an ENID from the start of the input, and return the
+//! rest of the input. They are complete parsers: input that is too short to
+//! contain an ENID is an error rather than [`Incomplete`](::nom::Err::Incomplete).
+//! Variants for byte slices are suffixed with `_bytes`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::{enid40, Enid40};
+//! # use nom::bytes::complete::tag;
+//! # use nom::sequence::preceded;
+//! # use nom::{IResult, Parser};
+//! fn user(input: &str) -> IResult<&str, Enid40> {
+//!     preceded(tag("user:"), enid::nom::enid40).parse(input)
+//! }
+//!
+//! assert_eq!(user("user:m6sc7n75 logged in"), Ok((" logged in", enid40!("m6sc7n75"))));
+//! assert!(user("user:m6sc").is_err());
+//! ```
+
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+use ::nom::error::{ErrorKind, ParseError};
+use ::nom::{Err, IResult};
+
+/// Parses an [`Enid40`] from the start of a string.
+///
+/// # Errors
+///
+/// Returns an error if the string does not start with a 40-bit ENID.
+#[inline]
+pub fn enid40<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Enid40, E> {
+    parse_str(input, 8, Enid40::parse_str_ascii)
+}
+
+/// Parses an [`Enid80`] from the start of a string.
+///
+/// # Errors
+///
+/// Returns an error if the string does not start with an 80-bit ENID.
+#[inline]
+pub fn enid80<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Enid80, E> {
+    parse_str(input, 17, Enid80::parse_str_ascii)
+}
+
+/// Parses an [`Enid`] from the start of a string.
+///
+/// An 80-bit ENID is parsed if possible, so `"m6sc7n75-kbhqr4fs"` is parsed as
+/// an [`Enid80`], while `"m6sc7n75-x"` is parsed as an [`Enid40`] followed by
+/// `"-x"`.
+///
+/// # Errors
+///
+/// Returns an error if the string does not start with an ENID.
+#[inline]
+pub fn enid<'a, E: ParseError<&'a str>>(input: &'a str) -> IResult<&'a str, Enid, E> {
+    enid80(input)
+        .map(|(rest, enid)| (rest, Enid::Enid80(enid)))
+        .or_else(|_: Err<E>| enid40(input).map(|(rest, enid)| (rest, Enid::Enid40(enid))))
+}
+
+/// Parses an [`Enid40`] from the start of a byte slice of ASCII characters.
+///
+/// # Errors
+///
+/// Returns an error if the input does not start with a 40-bit ENID.
+#[inline]
+pub fn enid40_bytes<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Enid40, E> {
+    parse_bytes(input, 8, Enid40::parse_str_ascii)
+}
+
+/// Parses an [`Enid80`] from the start of a byte slice of ASCII characters.
+///
+/// # Errors
+///
+/// Returns an error if the input does not start with an 80-bit ENID.
+#[inline]
+pub fn enid80_bytes<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Enid80, E> {
+    parse_bytes(input, 17, Enid80::parse_str_ascii)
+}
+
+/// Parses an [`Enid`] from the start of a byte slice of ASCII characters.
+///
+/// As with [`enid`], an 80-bit ENID is parsed if possible.
+///
+/// # Errors
+///
+/// Returns an error if the input does not start with an ENID.
+#[inline]
+pub fn enid_bytes<'a, E: ParseError<&'a [u8]>>(input: &'a [u8]) -> IResult<&'a [u8], Enid, E> {
+    enid80_bytes(input)
+        .map(|(rest, enid)| (rest, Enid::Enid80(enid)))
+        .or_else(|_: Err<E>| enid40_bytes(input).map(|(rest, enid)| (rest, Enid::Enid40(enid))))
+}
+
+fn parse_str<'a, T, E: ParseError<&'a str>>(
+    input: &'a str,
+    len: usize,
+    parse: fn(&[u8]) -> Result<T, EnidParseError>,
+) -> IResult<&'a str, T, E> {
+    match input.as_bytes().get(..len).map(parse) {
+        // The parsed prefix is ASCII, so `len` is on a character boundary.
+        Some(Ok(enid)) => Ok((&input[len..], enid)),
+        _ => Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+    }
+}
+
+fn parse_bytes<'a, T, E: ParseError<&'a [u8]>>(
+    input: &'a [u8],
+    len: usize,
+    parse: fn(&[u8]) -> Result<T, EnidParseError>,
+) -> IResult<&'a [u8], T, E> {
+    match input.get(..len).map(parse) {
+        Some(Ok(enid)) => Ok((&input[len..], enid)),
+        _ => Err(Err::Error(E::from_error_kind(input, ErrorKind::Verify))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enid40, enid80};
+    use ::nom::error::Error;
+
+    #[test]
+    fn either_width() {
+        assert_eq!(
+            enid::<Error<_>>("m6sc7n75-kbhqr4fs,"),
+            Ok((",", Enid::Enid80(enid80!("m6sc7n75-kbhqr4fs")))),
+        );
+        assert_eq!(
+            enid::<Error<_>>("m6sc7n75-x"),
+            Ok(("-x", Enid::Enid40(enid40!("m6sc7n75")))),
+        );
+        assert!(enid::<Error<_>>("m6sc7n7").is_err());
+    }
+
+    #[test]
+    fn fixed_width_bytes() {
+        assert_eq!(
+            enid40_bytes::<Error<_>>(b"m6sc7n75\xff"),
+            Ok((&b"\xff"[..], enid40!("m6sc7n75"))),
+        );
+        assert!(enid80_bytes::<Error<_>>(b"m6sc7n75\xff").is_err());
+    }
+
+    #[test]
+    fn multibyte_input() {
+        assert!(enid40::<Error<_>>("m6sc7n7é").is_err());
+        assert_eq!(
+            enid40::<Error<_>>("m6sc7n75é"),
+            Ok(("é", enid40!("m6sc7n75")))
+        );
+    }
+}
@@ -0,0 +1,372 @@
+use crate::enid::{EnidN, EnidParseError};
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+
+/// An [`EnidN`] that is not filled with zeros.
+///
+/// The all-zero ENID is often used as a placeholder, so this type can be used
+/// where a real ID is required. [`NonNilEnid40`] and [`NonNilEnid80`] are
+/// aliases for the common sizes.
+///
+/// This type does not provide a niche, so `Option<NonNilEnid40>` is one byte
+/// larger than [`Enid40`]. The compiler can only use the zero value of a whole
+/// `NonZero` integer as a niche, and no combination of `NonZero` fields in 5
+/// bytes can hold every non-nil 40-bit ENID. Use [`OptionEnidN`] to store an
+/// optional ID in the size of an ENID.
+///
+/// [`Enid40`]: crate::Enid40
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40, NonNilEnid40};
+/// let id = NonNilEnid40::new(enid40!("m6sc7n75"));
+///
+/// assert!(id.is_some());
+/// assert_eq!(NonNilEnid40::new(Enid40::ZERO), None);
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct NonNilEnidN<const N: usize>(EnidN<N>);
+
+/// A 40-bit ENID that is not filled with zeros.
+pub type NonNilEnid40 = NonNilEnidN<5>;
+
+/// An 80-bit ENID that is not filled with zeros.
+pub type NonNilEnid80 = NonNilEnidN<10>;
+
+impl<const N: usize> NonNilEnidN<N> {
+    /// Creates a non-nil ENID, or returns `None` if the ENID is filled with
+    /// zeros.
+    #[must_use]
+    #[inline]
+    pub const fn new(enid: EnidN<N>) -> Option<Self> {
+        if enid.is_zero() {
+            None
+        } else {
+            Some(Self(enid))
+        }
+    }
+
+    /// Returns the ENID.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> EnidN<N> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying bytes.
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        self.0.as_bytes()
+    }
+
+    /// Attempts to create a non-nil ENID from the given string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::NonNilEnid40;
+    /// assert!(NonNilEnid40::parse_str("m6sc7n75").is_ok());
+    /// assert!(NonNilEnid40::parse_str("00000000").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not a valid ENID, or is
+    /// filled with zeros.
+    #[inline]
+    pub const fn parse_str(s: &str) -> Result<Self, EnidParseError> {
+        match EnidN::parse_str(s) {
+            Ok(enid) => match Self::new(enid) {
+                Some(enid) => Ok(enid),
+                None => Err(EnidParseError),
+            },
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<const N: usize> Debug for NonNilEnidN<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> Display for NonNilEnidN<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl<const N: usize> FromStr for NonNilEnidN<N> {
+    type Err = EnidParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_str(s)
+    }
+}
+
+impl<const N: usize> From<NonNilEnidN<N>> for EnidN<N> {
+    #[inline]
+    fn from(enid: NonNilEnidN<N>) -> Self {
+        enid.get()
+    }
+}
+
+impl<const N: usize> From<Option<NonNilEnidN<N>>> for EnidN<N> {
+    #[inline]
+    fn from(enid: Option<NonNilEnidN<N>>) -> Self {
+        enid.map_or(Self::ZERO, NonNilEnidN::get)
+    }
+}
+
+impl<const N: usize> TryFrom<EnidN<N>> for NonNilEnidN<N> {
+    type Error = NilEnidError;
+
+    #[inline]
+    fn try_from(enid: EnidN<N>) -> Result<Self, Self::Error> {
+        Self::new(enid).ok_or(NilEnidError)
+    }
+}
+
+impl<const N: usize> AsRef<EnidN<N>> for NonNilEnidN<N> {
+    #[inline]
+    fn as_ref(&self) -> &EnidN<N> {
+        &self.0
+    }
+}
+
+/// An error returned when converting an ENID filled with zeros to a
+/// [`NonNilEnidN`].
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct NilEnidError;
+
+impl Display for NilEnidError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("ENID is filled with zeros")
+    }
+}
+
+impl Error for NilEnidError {}
+
+/// An optional [`NonNilEnidN`] with the size of an [`EnidN`].
+///
+/// The ENID filled with zeros is reserved to represent `None`, so
+/// `OptionEnid40` takes 5 bytes where `Option<NonNilEnid40>` takes 6. This
+/// also makes it the same as an [`EnidN`] in serialized forms that store the
+/// raw bytes. [`OptionEnid40`] and [`OptionEnid80`] are aliases for the common
+/// sizes.
+///
+/// The default value is `None`, and `None` sorts before every ID, as with
+/// [`Option`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40, NonNilEnid40, OptionEnid40};
+/// let id = OptionEnid40::from(enid40!("m6sc7n75"));
+///
+/// assert_eq!(id.get(), NonNilEnid40::new(enid40!("m6sc7n75")));
+/// assert!(OptionEnid40::from(Enid40::ZERO).is_none());
+/// assert_eq!(size_of::<OptionEnid40>(), 5);
+/// ```
+#[derive(Copy, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct OptionEnidN<const N: usize>(EnidN<N>);
+
+/// An optional 40-bit ENID, using zero for `None`.
+pub type OptionEnid40 = OptionEnidN<5>;
+
+/// An optional 80-bit ENID, using zero for `None`.
+pub type OptionEnid80 = OptionEnidN<10>;
+
+impl<const N: usize> OptionEnidN<N> {
+    /// The value representing `None`.
+    pub const NONE: Self = Self(EnidN::ZERO);
+
+    /// Creates an optional ENID.
+    #[must_use]
+    #[inline]
+    pub const fn new(enid: Option<NonNilEnidN<N>>) -> Self {
+        match enid {
+            Some(enid) => Self(enid.get()),
+            None => Self::NONE,
+        }
+    }
+
+    /// Returns the ENID, or `None` if it is filled with zeros.
+    #[must_use]
+    #[inline]
+    pub const fn get(self) -> Option<NonNilEnidN<N>> {
+        NonNilEnidN::new(self.0)
+    }
+
+    /// Returns `true` if this holds an ENID.
+    #[must_use]
+    #[inline]
+    pub const fn is_some(&self) -> bool {
+        !self.0.is_zero()
+    }
+
+    /// Returns `true` if this does not hold an ENID.
+    #[must_use]
+    #[inline]
+    pub const fn is_none(&self) -> bool {
+        self.0.is_zero()
+    }
+
+    /// Returns the ENID, with `None` as the ENID filled with zeros.
+    #[must_use]
+    #[inline]
+    pub const fn to_enid(self) -> EnidN<N> {
+        self.0
+    }
+
+    /// Returns a reference to the underlying bytes.
+    #[must_use]
+    #[inline]
+    pub const fn as_bytes(&self) -> &[u8; N] {
+        self.0.as_bytes()
+    }
+}
+
+impl<const N: usize> Debug for OptionEnidN<N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Debug::fmt(&self.get(), f)
+    }
+}
+
+impl<const N: usize> From<EnidN<N>> for OptionEnidN<N> {
+    #[inline]
+    fn from(enid: EnidN<N>) -> Self {
+        Self(enid)
+    }
+}
+
+impl<const N: usize> From<NonNilEnidN<N>> for OptionEnidN<N> {
+    #[inline]
+    fn from(enid: NonNilEnidN<N>) -> Self {
+        Self(enid.get())
+    }
+}
+
+impl<const N: usize> From<Option<NonNilEnidN<N>>> for OptionEnidN<N> {
+    #[inline]
+    fn from(enid: Option<NonNilEnidN<N>>) -> Self {
+        Self::new(enid)
+    }
+}
+
+impl<const N: usize> From<OptionEnidN<N>> for Option<NonNilEnidN<N>> {
+    #[inline]
+    fn from(enid: OptionEnidN<N>) -> Self {
+        enid.get()
+    }
+}
+
+impl<const N: usize> From<OptionEnidN<N>> for EnidN<N> {
+    #[inline]
+    fn from(enid: OptionEnidN<N>) -> Self {
+        enid.to_enid()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Enid40, Enid80, enid40};
+    use core::mem::size_of;
+    use std::format;
+
+    #[test]
+    fn rejects_nil() {
+        assert_eq!(NonNilEnid40::new(Enid40::NIL), None);
+        assert_eq!(NonNilEnid80::try_from(Enid80::NIL), Err(NilEnidError));
+        assert_eq!(NonNilEnid40::parse_str("00000000"), Err(EnidParseError));
+
+        let enid = NonNilEnid40::new(enid40!("m6sc7n75")).unwrap();
+
+        assert_eq!(enid.get(), enid40!("m6sc7n75"));
+        assert_eq!(Enid40::from(Some(enid)), enid40!("m6sc7n75"));
+        assert_eq!(Enid40::from(None::<NonNilEnid40>), Enid40::NIL);
+    }
+
+    #[test]
+    fn layout() {
+        // There is no niche, so `Option` adds a discriminant byte.
+        assert_eq!(size_of::<NonNilEnid40>(), size_of::<Enid40>());
+        assert_eq!(size_of::<Option<NonNilEnid40>>(), 6);
+        assert_eq!(size_of::<NonNilEnid80>(), size_of::<Enid80>());
+
+        assert_eq!(size_of::<OptionEnid40>(), 5);
+        assert_eq!(size_of::<OptionEnid80>(), 10);
+    }
+
+    #[test]
+    fn option() {
+        let enid = NonNilEnid40::new(enid40!("m6sc7n75")).unwrap();
+
+        assert_eq!(OptionEnid40::default(), OptionEnid40::NONE);
+        assert_eq!(OptionEnid40::NONE.get(), None);
+        assert!(OptionEnid40::from(Enid40::NIL).is_none());
+        assert_eq!(OptionEnid40::from(enid).get(), Some(enid));
+        assert_eq!(Enid40::from(OptionEnid40::NONE), Enid40::NIL);
+        assert_eq!(Option::from(OptionEnid40::from(Some(enid))), Some(enid));
+        assert!(OptionEnid40::NONE < OptionEnid40::from(enid40!("00000001")));
+        assert_eq!(format!("{:?}", OptionEnid40::NONE), "None");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serde() {
+        let json = r#""m6sc7n75""#;
+
+        assert_eq!(
+            serde_json::from_str::<NonNilEnid40>(json).unwrap().get(),
+            enid40!("m6sc7n75")
+        );
+        assert_eq!(
+            serde_json::to_string(&serde_json::from_str::<NonNilEnid40>(json).unwrap()).unwrap(),
+            json
+        );
+        assert!(serde_json::from_str::<NonNilEnid40>(r#""00000000""#).is_err());
+
+        let enid = OptionEnid40::from(enid40!("m6sc7n75"));
+
+        assert_eq!(serde_json::to_string(&enid).unwrap(), json);
+        assert_eq!(serde_json::from_str::<OptionEnid40>(json).unwrap(), enid);
+        assert_eq!(serde_json::to_string(&OptionEnid40::NONE).unwrap(), "null");
+        assert!(
+            serde_json::from_str::<OptionEnid40>("null")
+                .unwrap()
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "borsh")]
+    fn borsh() {
+        let enid = NonNilEnid80::try_from(Enid80::MAX).unwrap();
+        let bytes = borsh::to_vec(&enid).unwrap();
+
+        assert_eq!(borsh::from_slice::<NonNilEnid80>(&bytes).unwrap(), enid);
+        assert!(borsh::from_slice::<NonNilEnid80>(&[0; 10]).is_err());
+
+        let enid = OptionEnid80::from(Enid80::MAX);
+
+        assert_eq!(borsh::to_vec(&enid).unwrap(), [0xff; 10]);
+        assert_eq!(borsh::to_vec(&OptionEnid80::NONE).unwrap(), [0; 10]);
+        assert!(
+            borsh::from_slice::<OptionEnid80>(&[0; 10])
+                .unwrap()
+                .is_none()
+        );
+    }
+}
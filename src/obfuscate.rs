@@ -0,0 +1,145 @@
+//! Obfuscating sequential counters as ENIDs.
+//!
+//! IDs allocated from a database sequence are easy to guess, and reveal how
+//! many records exist. An [`Obfuscator`] maps each counter value to a
+//! random-looking [`Enid40`] with a keyed Feistel network, and maps the ENID
+//! back to the counter, so the sequence can stay the source of truth while only
+//! the ENIDs are exposed.
+//!
+//! The mapping is a [`Permutation40`], so no two counters produce the same
+//! ENID. Like the permutation, it obfuscates the counter rather than
+//! encrypting it.
+
+use crate::enid::Enid40;
+use crate::permutation::{Permutation40, SPACE_SIZE};
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+/// A keyed, reversible mapping from counters to [`Enid40`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::obfuscate::Obfuscator;
+/// let obfuscator = Obfuscator::new(0x0123_4567_89ab_cdef);
+///
+/// let a = obfuscator.obfuscate(1)?;
+/// let b = obfuscator.obfuscate(2)?;
+///
+/// assert_ne!(a, b);
+/// assert_eq!(obfuscator.deobfuscate(b), 2);
+/// assert!(obfuscator.obfuscate(1 << 40).is_err());
+/// # Ok::<(), enid::obfuscate::CounterOutOfRange>(())
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Obfuscator {
+    permutation: Permutation40,
+}
+
+impl Obfuscator {
+    /// Creates an obfuscator from the given key.
+    #[must_use]
+    #[inline]
+    pub const fn new(key: u64) -> Self {
+        Self {
+            permutation: Permutation40::new(key),
+        }
+    }
+
+    /// Maps a counter to an ENID.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CounterOutOfRange`] error if the counter is `2^40` or
+    /// greater.
+    #[inline]
+    pub const fn obfuscate(&self, counter: u64) -> Result<Enid40, CounterOutOfRange> {
+        if counter < SPACE_SIZE {
+            Ok(self.permutation.permute(counter))
+        } else {
+            Err(CounterOutOfRange(counter))
+        }
+    }
+
+    /// Maps an ENID back to the counter that produced it.
+    #[must_use]
+    #[inline]
+    pub const fn deobfuscate(&self, enid: Enid40) -> u64 {
+        self.permutation.invert(enid)
+    }
+}
+
+impl Debug for Obfuscator {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Obfuscator").finish_non_exhaustive()
+    }
+}
+
+/// An error returned when a counter is too large to be mapped to an
+/// [`Enid40`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct CounterOutOfRange(u64);
+
+impl CounterOutOfRange {
+    /// Returns the counter that was out of range.
+    #[must_use]
+    #[inline]
+    pub const fn counter(&self) -> u64 {
+        self.0
+    }
+}
+
+impl Display for CounterOutOfRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "counter {} is out of range for a 40-bit ENID", self.0)
+    }
+}
+
+impl Error for CounterOutOfRange {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for key in [0, 1, 0x0123_4567_89ab_cdef, u64::MAX] {
+            let obfuscator = Obfuscator::new(key);
+
+            // Visit a spread of counters across the whole range, including
+            // both ends.
+            let counters = (0..SPACE_SIZE)
+                .step_by(0x10_0001)
+                .chain(0..1000)
+                .chain(SPACE_SIZE - 1000..SPACE_SIZE);
+
+            for counter in counters {
+                let enid = obfuscator.obfuscate(counter).unwrap();
+                assert_eq!(obfuscator.deobfuscate(enid), counter);
+            }
+
+            // Every ENID decodes to a counter that encodes back to it.
+            for value in (0..SPACE_SIZE).step_by(0x10_0003) {
+                let [_, _, _, bytes @ ..] = value.to_be_bytes();
+                let enid = Enid40::from_bytes(bytes);
+                let counter = obfuscator.deobfuscate(enid);
+
+                assert_eq!(obfuscator.obfuscate(counter), Ok(enid));
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let obfuscator = Obfuscator::new(42);
+
+        assert_eq!(
+            obfuscator.obfuscate(SPACE_SIZE),
+            Err(CounterOutOfRange(SPACE_SIZE))
+        );
+        assert_eq!(
+            obfuscator.obfuscate(u64::MAX),
+            Err(CounterOutOfRange(u64::MAX))
+        );
+    }
+}
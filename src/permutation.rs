@@ -0,0 +1,231 @@
+//! Keyed permutations of the 40-bit ENID space.
+//!
+//! A [`Permutation40`] maps each 40-bit integer to a unique [`Enid40`] using a
+//! balanced Feistel network, so every ENID is produced exactly once as the
+//! input counts from `0` to `2^40 - 1`. [`PermutationSequence`] uses this to
+//! assign IDs that are unique without storing the IDs already used, and that
+//! are hard to predict without the key.
+//!
+//! The round function is a fast integer mixer, not a cryptographic primitive.
+//! The order of the IDs should be treated as obfuscated, not encrypted.
+
+use crate::enid::Enid40;
+use crate::hash;
+use core::fmt::{self, Debug, Formatter};
+
+const ROUNDS: usize = 8;
+const HALF_BITS: u32 = 20;
+const HALF_MASK: u64 = (1 << HALF_BITS) - 1;
+
+/// The number of values in the 40-bit ENID space.
+pub const SPACE_SIZE: u64 = 1 << 40;
+
+/// A keyed bijection between 40-bit integers and [`Enid40`]s.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::permutation::Permutation40;
+/// let permutation = Permutation40::new(0x0123_4567_89ab_cdef);
+/// let enid = permutation.permute(42);
+///
+/// assert_eq!(permutation.invert(enid), 42);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Permutation40 {
+    round_keys: [u64; ROUNDS],
+}
+
+impl Permutation40 {
+    /// Creates a permutation from the given key.
+    #[must_use]
+    pub const fn new(key: u64) -> Self {
+        let mut round_keys = [0; ROUNDS];
+        let mut state = key;
+
+        let mut i = 0;
+        while i < ROUNDS {
+            round_keys[i] = hash::splitmix64(&mut state);
+            i += 1;
+        }
+
+        Self { round_keys }
+    }
+
+    /// Maps the given integer to an ENID.
+    ///
+    /// Only the lower 40 bits of `value` are used.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::permutation::Permutation40;
+    /// let permutation = Permutation40::new(0x0123_4567_89ab_cdef);
+    ///
+    /// assert_ne!(permutation.permute(0), permutation.permute(1));
+    /// ```
+    #[must_use]
+    pub const fn permute(&self, value: u64) -> Enid40 {
+        let mut left = (value >> HALF_BITS) & HALF_MASK;
+        let mut right = value & HALF_MASK;
+
+        let mut i = 0;
+        while i < ROUNDS {
+            let next = left ^ self.round(i, right);
+            left = right;
+            right = next;
+            i += 1;
+        }
+
+        let [_, _, _, bytes @ ..] = ((left << HALF_BITS) | right).to_be_bytes();
+        Enid40::from_bytes(bytes)
+    }
+
+    /// Maps the given ENID back to the integer that produced it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::permutation::Permutation40;
+    /// let permutation = Permutation40::new(0x0123_4567_89ab_cdef);
+    ///
+    /// assert_eq!(permutation.invert(permutation.permute(42)), 42);
+    /// ```
+    #[must_use]
+    pub const fn invert(&self, enid: Enid40) -> u64 {
+        let value = enid.to_u64();
+
+        let mut left = value >> HALF_BITS;
+        let mut right = value & HALF_MASK;
+
+        let mut i = ROUNDS;
+        while i > 0 {
+            i -= 1;
+            let prev = right ^ self.round(i, left);
+            right = left;
+            left = prev;
+        }
+
+        (left << HALF_BITS) | right
+    }
+
+    const fn round(&self, i: usize, half: u64) -> u64 {
+        hash::mix64(half ^ self.round_keys[i]) & HALF_MASK
+    }
+}
+
+impl Debug for Permutation40 {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("Permutation40").finish_non_exhaustive()
+    }
+}
+
+/// An iterator that visits every [`Enid40`] exactly once, in an order
+/// determined by a key.
+///
+/// The position in the sequence can be saved with
+/// [`cursor`](Self::cursor), and restored with
+/// [`from_cursor`](Self::from_cursor).
+///
+/// # Examples
+///
+/// ```
+/// # use enid::permutation::PermutationSequence;
+/// let mut ids = PermutationSequence::new(0x0123_4567_89ab_cdef);
+/// let first = ids.next();
+/// let cursor = ids.cursor();
+///
+/// let mut resumed = PermutationSequence::from_cursor(0x0123_4567_89ab_cdef, cursor);
+///
+/// assert_eq!(ids.next(), resumed.next());
+/// assert_ne!(ids.next(), first);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PermutationSequence {
+    permutation: Permutation40,
+    cursor: u64,
+}
+
+impl PermutationSequence {
+    /// Creates a sequence from the given key, starting at the beginning.
+    #[must_use]
+    #[inline]
+    pub const fn new(key: u64) -> Self {
+        Self::from_cursor(key, 0)
+    }
+
+    /// Creates a sequence from the given key, resuming from a cursor returned by
+    /// [`cursor`](Self::cursor).
+    ///
+    /// A cursor of [`SPACE_SIZE`] or greater creates an exhausted sequence.
+    #[must_use]
+    #[inline]
+    pub const fn from_cursor(key: u64, cursor: u64) -> Self {
+        Self {
+            permutation: Permutation40::new(key),
+            cursor,
+        }
+    }
+
+    /// Returns the number of ENIDs produced so far, which can be used to resume
+    /// the sequence later.
+    #[must_use]
+    #[inline]
+    pub const fn cursor(&self) -> u64 {
+        self.cursor
+    }
+}
+
+impl Iterator for PermutationSequence {
+    type Item = Enid40;
+
+    fn next(&mut self) -> Option<Enid40> {
+        if self.cursor >= SPACE_SIZE {
+            return None;
+        }
+
+        let enid = self.permutation.permute(self.cursor);
+        self.cursor += 1;
+
+        Some(enid)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = SPACE_SIZE.saturating_sub(self.cursor);
+
+        match usize::try_from(remaining) {
+            Ok(n) => (n, Some(n)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn round_trip() {
+        let permutation = Permutation40::new(42);
+
+        for value in [0, 1, 2, 0xff_ffff, SPACE_SIZE / 2, SPACE_SIZE - 1] {
+            assert_eq!(permutation.invert(permutation.permute(value)), value);
+        }
+    }
+
+    #[test]
+    fn unique() {
+        let ids: HashSet<_> = PermutationSequence::new(42).take(100_000).collect();
+        assert_eq!(ids.len(), 100_000);
+    }
+
+    #[test]
+    fn exhausted() {
+        let mut ids = PermutationSequence::from_cursor(42, SPACE_SIZE - 1);
+
+        assert!(ids.next().is_some());
+        assert_eq!(ids.next(), None);
+        assert_eq!(ids.cursor(), SPACE_SIZE);
+    }
+}
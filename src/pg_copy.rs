@@ -0,0 +1,220 @@
+//! Bulk loading and unloading ENIDs with PostgreSQL's binary `COPY` format.
+//!
+//! Each row has a single `bytea` column containing the ENID's bytes. The
+//! output of [`CopyWriter`] can be sent to `COPY table (column) FROM STDIN
+//! (FORMAT binary)`, and the output of `COPY table (column) TO STDOUT (FORMAT
+//! binary)` can be read with [`CopyReader`].
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::{enid40, enid80, Enid};
+//! # use enid::pg_copy::{CopyReader, CopyWriter};
+//! let enids: [Enid; 2] = [enid40!("m6sc7n75").into(), enid80!("y3gx5gxm-kbhqr4fs").into()];
+//!
+//! let mut writer = CopyWriter::new(Vec::new())?;
+//! writer.write_all(enids)?;
+//! let buf = writer.finish()?;
+//!
+//! let reader = CopyReader::new(buf.as_slice())?;
+//! let parsed = reader.collect::<Result<Vec<_>, _>>()?;
+//!
+//! assert_eq!(parsed, enids);
+//! # Ok::<(), std::io::Error>(())
+//! ```
+
+use crate::enid::{Enid, Enid40, Enid80};
+use std::io::{self, ErrorKind, Read, Write};
+
+const SIGNATURE: &[u8; 11] = b"PGCOPY\n\xff\r\n\0";
+const HAS_OIDS: u32 = 1 << 16;
+
+/// A writer that encodes ENIDs as rows of a binary `COPY`.
+#[derive(Debug)]
+pub struct CopyWriter<W> {
+    writer: W,
+}
+
+impl<W: Write> CopyWriter<W> {
+    /// Creates a writer, and writes the `COPY` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn new(mut writer: W) -> io::Result<Self> {
+        writer.write_all(SIGNATURE)?;
+        writer.write_all(&0_u32.to_be_bytes())?;
+        writer.write_all(&0_u32.to_be_bytes())?;
+
+        Ok(Self { writer })
+    }
+
+    /// Writes a single ENID as a row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn write(&mut self, enid: impl Into<Enid>) -> io::Result<()> {
+        let enid = enid.into();
+        let bytes = enid.as_bytes();
+
+        self.writer.write_all(&1_u16.to_be_bytes())?;
+        self.writer.write_all(&(bytes.len() as u32).to_be_bytes())?;
+        self.writer.write_all(bytes)
+    }
+
+    /// Writes each ENID as a row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to the underlying writer fails.
+    pub fn write_all<I>(&mut self, enids: I) -> io::Result<()>
+    where
+        I: IntoIterator,
+        I::Item: Into<Enid>,
+    {
+        enids.into_iter().try_for_each(|enid| self.write(enid))
+    }
+
+    /// Writes the `COPY` trailer, and returns the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing to or flushing the underlying writer fails.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(&(-1_i16).to_be_bytes())?;
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
+
+/// An iterator over the ENIDs in the rows of a binary `COPY`.
+///
+/// Each row must have a single non-null `bytea` column of 5 or 10 bytes.
+/// Iteration stops after the trailer, or at the first error.
+#[derive(Debug)]
+pub struct CopyReader<R> {
+    reader: R,
+    done: bool,
+}
+
+impl<R: Read> CopyReader<R> {
+    /// Creates a reader, and reads the `COPY` header.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading from the underlying reader fails, or the
+    /// header is invalid.
+    pub fn new(mut reader: R) -> io::Result<Self> {
+        let mut signature = [0; 11];
+        reader.read_exact(&mut signature)?;
+
+        if &signature != SIGNATURE {
+            return Err(invalid_data("invalid COPY signature"));
+        }
+
+        if read_u32(&mut reader)? & HAS_OIDS != 0 {
+            return Err(invalid_data("COPY data with OIDs is not supported"));
+        }
+
+        let extension_len = read_u32(&mut reader)?;
+        io::copy(
+            &mut (&mut reader).take(extension_len.into()),
+            &mut io::sink(),
+        )?;
+
+        Ok(Self {
+            reader,
+            done: false,
+        })
+    }
+
+    fn read_row(&mut self) -> io::Result<Option<Enid>> {
+        let mut field_count = [0; 2];
+        self.reader.read_exact(&mut field_count)?;
+
+        match i16::from_be_bytes(field_count) {
+            -1 => return Ok(None),
+            1 => {}
+            _ => return Err(invalid_data("expected a single column")),
+        }
+
+        match read_u32(&mut self.reader)? {
+            5 => {
+                let mut bytes = [0; 5];
+                self.reader.read_exact(&mut bytes)?;
+                Ok(Some(Enid40::from_bytes(bytes).into()))
+            }
+            10 => {
+                let mut bytes = [0; 10];
+                self.reader.read_exact(&mut bytes)?;
+                Ok(Some(Enid80::from_bytes(bytes).into()))
+            }
+            u32::MAX => Err(invalid_data("unexpected NULL")),
+            _ => Err(invalid_data("invalid ENID length")),
+        }
+    }
+}
+
+impl<R: Read> Iterator for CopyReader<R> {
+    type Item = io::Result<Enid>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let row = self.read_row().transpose();
+
+        if !matches!(row, Some(Ok(_))) {
+            self.done = true;
+        }
+
+        row
+    }
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    let mut buf = [0; 4];
+    reader.read_exact(&mut buf)?;
+    Ok(u32::from_be_bytes(buf))
+}
+
+fn invalid_data(msg: &str) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, msg)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enid40;
+    use std::vec::Vec;
+
+    #[test]
+    fn encoding() {
+        let mut writer = CopyWriter::new(Vec::new()).unwrap();
+        writer.write(enid40!("m6sc7n75")).unwrap();
+        let buf = writer.finish().unwrap();
+
+        let mut expected = SIGNATURE.to_vec();
+        expected.extend_from_slice(&[0, 0, 0, 0, 0, 0, 0, 0]);
+        expected.extend_from_slice(&[0, 1, 0, 0, 0, 5]);
+        expected.extend_from_slice(enid40!("m6sc7n75").as_bytes());
+        expected.extend_from_slice(&[0xff, 0xff]);
+
+        assert_eq!(buf, expected);
+    }
+
+    #[test]
+    fn truncated() {
+        let mut writer = CopyWriter::new(Vec::new()).unwrap();
+        writer.write(enid40!("m6sc7n75")).unwrap();
+        let buf = writer.finish().unwrap();
+
+        let mut reader = CopyReader::new(&buf[..buf.len() - 4]).unwrap();
+
+        assert!(reader.next().unwrap().is_err());
+        assert!(reader.next().is_none());
+    }
+}
@@ -0,0 +1,197 @@
+//! Abbreviating ENIDs to short prefixes, and resolving prefixes back to ENIDs.
+//!
+//! Like the short hashes used by git, a prefix of an ENID's string form can be
+//! used to refer to it, as long as no other ENID in the same collection starts
+//! with the same prefix.
+
+use crate::base32::{self, SEPARATOR};
+use crate::enid::Enid;
+use alloc::vec::Vec;
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+/// An error returned when a prefix cannot be resolved to a single ENID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ResolveError<T> {
+    /// The prefix is empty, or is not a prefix of any valid ENID string.
+    InvalidPrefix,
+
+    /// No ENIDs start with the prefix.
+    NotFound,
+
+    /// More than one ENID starts with the prefix.
+    Ambiguous(Vec<T>),
+}
+
+impl<T: Display> Display for ResolveError<T> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidPrefix => f.write_str("invalid ENID prefix"),
+            Self::NotFound => f.write_str("no ENID matches the prefix"),
+            Self::Ambiguous(candidates) => {
+                f.write_str("ambiguous ENID prefix, candidates:")?;
+
+                for candidate in candidates {
+                    write!(f, " {candidate}")?;
+                }
+
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<T: Display + fmt::Debug> Error for ResolveError<T> {}
+
+/// Returns the length of the shortest prefix that uniquely identifies each ENID
+/// in the collection, in the same order as the ENIDs.
+///
+/// A 40-bit ENID whose string form is also the start of an 80-bit ENID's string
+/// form requires its full length, as do duplicate ENIDs.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid40;
+/// # use enid::prefix::shortest_prefixes;
+/// let enids = [enid40!("m6sc7n75"), enid40!("m6sd0000"), enid40!("y3gx5gxm")];
+///
+/// assert_eq!(shortest_prefixes(&enids), [4, 4, 1]);
+/// ```
+#[must_use]
+pub fn shortest_prefixes<T: Copy + Into<Enid>>(enids: &[T]) -> Vec<usize> {
+    let mut strings: Vec<([u8; 17], usize, usize)> = enids
+        .iter()
+        .enumerate()
+        .map(|(idx, &enid)| {
+            let mut buf = [0; 17];
+            let len = enid.into().write_ascii(&mut buf).len();
+            (buf, len, idx)
+        })
+        .collect();
+
+    strings.sort_unstable();
+
+    let mut lens = alloc::vec![0; enids.len()];
+
+    for (i, (buf, len, idx)) in strings.iter().enumerate() {
+        let s = &buf[..*len];
+
+        let prev = i
+            .checked_sub(1)
+            .map_or(0, |j| common_prefix_len(s, &strings[j].0[..strings[j].1]));
+        let next = strings
+            .get(i + 1)
+            .map_or(0, |(buf, len, _)| common_prefix_len(s, &buf[..*len]));
+
+        lens[*idx] = (prev.max(next) + 1).min(*len);
+    }
+
+    lens
+}
+
+/// Returns the ENID in the collection that starts with the given prefix.
+///
+/// If the prefix is the full string form of an ENID, that ENID is returned even
+/// if the prefix also matches longer ENIDs.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid40;
+/// # use enid::prefix::{resolve, ResolveError};
+/// let enids = [enid40!("m6sc7n75"), enid40!("m6sd0000"), enid40!("y3gx5gxm")];
+///
+/// assert_eq!(resolve("m6sc", &enids), Ok(enid40!("m6sc7n75")));
+/// assert_eq!(resolve("y", &enids), Ok(enid40!("y3gx5gxm")));
+/// assert_eq!(resolve("0", &enids), Err(ResolveError::NotFound));
+/// assert_eq!(
+///     resolve("m6s", &enids),
+///     Err(ResolveError::Ambiguous(vec![enid40!("m6sc7n75"), enid40!("m6sd0000")])),
+/// );
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`ResolveError`] if the prefix is invalid, or does not match
+/// exactly one ENID.
+pub fn resolve<T: Copy + Into<Enid>>(prefix: &str, enids: &[T]) -> Result<T, ResolveError<T>> {
+    if !is_valid_prefix(prefix.as_bytes()) {
+        return Err(ResolveError::InvalidPrefix);
+    }
+
+    let mut candidates = Vec::new();
+
+    for &enid in enids {
+        let mut buf = [0; 17];
+        let s = enid.into().write_ascii(&mut buf);
+
+        if s == prefix.as_bytes() {
+            return Ok(enid);
+        }
+
+        if s.starts_with(prefix.as_bytes()) {
+            candidates.push(enid);
+        }
+    }
+
+    match candidates.len() {
+        0 => Err(ResolveError::NotFound),
+        1 => Ok(candidates[0]),
+        _ => Err(ResolveError::Ambiguous(candidates)),
+    }
+}
+
+fn is_valid_prefix(prefix: &[u8]) -> bool {
+    !prefix.is_empty()
+        && prefix.len() <= 17
+        && prefix.iter().enumerate().all(|(i, &c)| {
+            if i == 8 {
+                c == SEPARATOR
+            } else {
+                base32::is_enid_char(c)
+            }
+        })
+}
+
+fn common_prefix_len(a: &[u8], b: &[u8]) -> usize {
+    a.iter().zip(b).take_while(|(a, b)| a == b).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{enid40, enid80};
+
+    #[test]
+    fn shortest_prefixes_mixed() {
+        let enids: [Enid; 3] = [
+            enid40!("m6sc7n75").into(),
+            enid80!("m6sc7n75-0000000r").into(),
+            enid80!("y3gx5gxm-0000000r").into(),
+        ];
+
+        assert_eq!(shortest_prefixes(&enids), [8, 9, 1]);
+        assert_eq!(resolve("m6sc7n75", &enids), Ok(enids[0]));
+        assert_eq!(resolve("m6sc7n75-", &enids), Ok(enids[1]));
+    }
+
+    #[test]
+    fn shortest_prefixes_duplicates() {
+        let enids = [enid40!("m6sc7n75"), enid40!("m6sc7n75")];
+
+        assert_eq!(shortest_prefixes(&enids), [8, 8]);
+    }
+
+    #[test]
+    fn resolve_invalid() {
+        let enids = [enid40!("m6sc7n75")];
+
+        assert_eq!(resolve("", &enids), Err(ResolveError::InvalidPrefix));
+        assert_eq!(resolve("m6u", &enids), Err(ResolveError::InvalidPrefix));
+        assert_eq!(
+            resolve("m6sc7n75x", &enids),
+            Err(ResolveError::InvalidPrefix)
+        );
+    }
+}
@@ -0,0 +1,340 @@
+//! Self-describing IDs with a type prefix, such as `"user_m6sc7n75"`.
+//!
+//! Like TypeIDs and Stripe's IDs, a [`Prefixed`] ID is written as a prefix
+//! naming the kind of entity, an underscore, and the ENID. The prefix is
+//! checked when the ID is parsed, so an ID of one kind cannot be mistaken for
+//! another.
+//!
+//! The prefix can be fixed at compile time with a zero-sized type that
+//! implements [`Prefix`], or chosen at runtime with a `&str`. With the `serde`
+//! feature, IDs with a fixed prefix are serialized as strings, and the prefix
+//! is checked when they are deserialized.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::prefixed::{Prefix, Prefixed};
+//! # use enid::enid80;
+//! #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+//! struct User;
+//!
+//! impl Prefix for User {
+//!     fn as_str(&self) -> &str {
+//!         "user"
+//!     }
+//! }
+//!
+//! type UserId = Prefixed<User, 10>;
+//!
+//! let id = UserId::from_enid(enid80!("y3gx5gxm-mpb8ey39"));
+//!
+//! assert_eq!(id.to_string(), "user_y3gx5gxm-mpb8ey39");
+//! assert_eq!("user_y3gx5gxm-mpb8ey39".parse::<UserId>(), Ok(id));
+//! assert!("team_y3gx5gxm-mpb8ey39".parse::<UserId>().is_err());
+//!
+//! // A prefix chosen at runtime.
+//! let id = Prefixed::<_, 5>::parse_with("team", "team_m6sc7n75")?;
+//! assert_eq!(id.prefix(), &"team");
+//! # Ok::<(), enid::prefixed::PrefixedParseError>(())
+//! ```
+
+use crate::enid::{EnidN, EnidParseError};
+use core::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+use core::str::FromStr;
+
+/// The character between the prefix and the ENID.
+pub const SEPARATOR: char = '_';
+
+/// The maximum length of a prefix.
+pub const MAX_PREFIX_LEN: usize = 63;
+
+/// The prefix of a [`Prefixed`] ID.
+///
+/// This is implemented for `&str`, for prefixes chosen at runtime. For a
+/// prefix fixed at compile time, implement it for a zero-sized type that also
+/// implements [`Default`], which allows the ID to be parsed with [`FromStr`].
+///
+/// A valid prefix is 1 to 63 lowercase ASCII letters and underscores, and does
+/// not start or end with an underscore.
+pub trait Prefix {
+    /// Returns the prefix.
+    fn as_str(&self) -> &str;
+}
+
+impl Prefix for &str {
+    #[inline]
+    fn as_str(&self) -> &str {
+        self
+    }
+}
+
+/// Returns `true` if the string is a valid [`Prefix`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::prefixed::is_valid_prefix;
+/// assert!(is_valid_prefix("user"));
+/// assert!(is_valid_prefix("api_key"));
+/// assert!(!is_valid_prefix(""));
+/// assert!(!is_valid_prefix("_user"));
+/// assert!(!is_valid_prefix("User"));
+/// ```
+#[must_use]
+pub const fn is_valid_prefix(prefix: &str) -> bool {
+    let bytes = prefix.as_bytes();
+
+    if bytes.is_empty() || bytes.len() > MAX_PREFIX_LEN {
+        return false;
+    }
+
+    if bytes[0] == SEPARATOR as u8 || bytes[bytes.len() - 1] == SEPARATOR as u8 {
+        return false;
+    }
+
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_lowercase() && bytes[i] != SEPARATOR as u8 {
+            return false;
+        }
+
+        i += 1;
+    }
+
+    true
+}
+
+/// An ENID with a type prefix, written as `"prefix_enid"`.
+///
+/// See the [module documentation](self) for details.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Prefixed<P, const N: usize> {
+    prefix: P,
+    enid: EnidN<N>,
+}
+
+impl<P: Prefix, const N: usize> Prefixed<P, N> {
+    /// Creates a prefixed ID from the given prefix and ENID.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`InvalidPrefixError`] if the prefix is not valid.
+    pub fn new(prefix: P, enid: EnidN<N>) -> Result<Self, InvalidPrefixError> {
+        if is_valid_prefix(prefix.as_str()) {
+            Ok(Self { prefix, enid })
+        } else {
+            Err(InvalidPrefixError)
+        }
+    }
+
+    /// Creates a prefixed ID with a prefix fixed at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the prefix is not valid.
+    #[must_use]
+    #[track_caller]
+    pub fn from_enid(enid: EnidN<N>) -> Self
+    where
+        P: Default,
+    {
+        Self::new(P::default(), enid).expect("invalid ENID prefix")
+    }
+
+    /// Returns the prefix.
+    #[must_use]
+    #[inline]
+    pub const fn prefix(&self) -> &P {
+        &self.prefix
+    }
+
+    /// Returns the ENID.
+    #[must_use]
+    #[inline]
+    pub const fn enid(&self) -> EnidN<N> {
+        self.enid
+    }
+
+    /// Attempts to create a prefixed ID from a string, which must start with
+    /// the given prefix.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`PrefixedParseError`] if the string does not start with the
+    /// prefix and an underscore, or the rest is not a valid ENID.
+    pub fn parse_with(prefix: P, s: &str) -> Result<Self, PrefixedParseError> {
+        let (actual, enid) = s
+            .rsplit_once(SEPARATOR)
+            .ok_or(PrefixedParseError::MissingPrefix)?;
+
+        if actual != prefix.as_str() || !is_valid_prefix(actual) {
+            return Err(PrefixedParseError::WrongPrefix);
+        }
+
+        let enid = EnidN::parse_str(enid).map_err(PrefixedParseError::InvalidEnid)?;
+
+        Ok(Self { prefix, enid })
+    }
+}
+
+impl<P: Prefix, const N: usize> Debug for Prefixed<P, N> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        Display::fmt(self, f)
+    }
+}
+
+impl<P: Prefix, const N: usize> Display for Prefixed<P, N> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}{SEPARATOR}{}", self.prefix.as_str(), self.enid)
+    }
+}
+
+impl<P: Prefix + Default, const N: usize> FromStr for Prefixed<P, N> {
+    type Err = PrefixedParseError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse_with(P::default(), s)
+    }
+}
+
+impl<P, const N: usize> From<Prefixed<P, N>> for EnidN<N> {
+    #[inline]
+    fn from(id: Prefixed<P, N>) -> Self {
+        id.enid
+    }
+}
+
+impl<P, const N: usize> AsRef<EnidN<N>> for Prefixed<P, N> {
+    #[inline]
+    fn as_ref(&self) -> &EnidN<N> {
+        &self.enid
+    }
+}
+
+/// An error returned when a [`Prefix`] is not valid.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct InvalidPrefixError;
+
+impl Display for InvalidPrefixError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("invalid ENID prefix")
+    }
+}
+
+impl Error for InvalidPrefixError {}
+
+/// An error returned when a string cannot be parsed as a [`Prefixed`] ID.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum PrefixedParseError {
+    /// The string has no prefix.
+    MissingPrefix,
+
+    /// The string has a different prefix from the one expected.
+    WrongPrefix,
+
+    /// The part of the string after the prefix is not a valid ENID.
+    InvalidEnid(EnidParseError),
+}
+
+impl Display for PrefixedParseError {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Self::MissingPrefix => f.write_str("missing ENID prefix"),
+            Self::WrongPrefix => f.write_str("wrong ENID prefix"),
+            Self::InvalidEnid(e) => Display::fmt(e, f),
+        }
+    }
+}
+
+impl Error for PrefixedParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::InvalidEnid(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::enid::Enid40;
+    use std::string::ToString;
+
+    #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+    struct ApiKey;
+
+    impl Prefix for ApiKey {
+        fn as_str(&self) -> &str {
+            "api_key"
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let enid = Enid40::from_bytes([0xa1, 0xb2, 0xc3, 0xd4, 0xe5]);
+        let id = Prefixed::<ApiKey, 5>::from_enid(enid);
+
+        assert_eq!(id.to_string(), "api_key_m6sc7n75");
+        assert_eq!("api_key_m6sc7n75".parse(), Ok(id));
+    }
+
+    #[test]
+    fn invalid() {
+        type Id = Prefixed<ApiKey, 5>;
+
+        assert_eq!(
+            "m6sc7n75".parse::<Id>(),
+            Err(PrefixedParseError::MissingPrefix)
+        );
+        assert_eq!(
+            "key_m6sc7n75".parse::<Id>(),
+            Err(PrefixedParseError::WrongPrefix)
+        );
+        assert_eq!(
+            "api_key_".parse::<Id>(),
+            Err(PrefixedParseError::InvalidEnid(EnidParseError))
+        );
+        assert_eq!(
+            Prefixed::<_, 5>::parse_with("", "_m6sc7n75"),
+            Err(PrefixedParseError::WrongPrefix)
+        );
+
+        let enid = Enid40::ZERO;
+        assert_eq!(Prefixed::new("User", enid), Err(InvalidPrefixError));
+        assert_eq!(Prefixed::new("user_", enid), Err(InvalidPrefixError));
+        assert!(Prefixed::new("a".repeat(63).as_str(), enid).is_ok());
+        assert_eq!(
+            Prefixed::new("a".repeat(64).as_str(), enid),
+            Err(InvalidPrefixError)
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[allow(unreachable_pub)]
+    fn define_enid_type_serde() {
+        crate::define_enid_type!(UserId: Enid80, prefix = "usr");
+        crate::define_enid_type!(OrderId: Enid40);
+
+        let user = UserId::new(crate::Enid80::from_bytes([0xff; 10]));
+        let json = serde_json::to_string(&user).unwrap();
+
+        assert_eq!(json, r#""usr_zzzzzzzz-zzzzzzzz""#);
+        assert_eq!(
+            serde_json::from_str::<UserId>(&json).unwrap().get(),
+            user.get()
+        );
+        assert!(serde_json::from_str::<UserId>(r#""zzzzzzzz-zzzzzzzz""#).is_err());
+
+        let order = OrderId::new(Enid40::ZERO);
+        let json = serde_json::to_string(&order).unwrap();
+
+        assert_eq!(json, r#""00000000""#);
+        assert_eq!(serde_json::from_str::<OrderId>(&json).unwrap(), order);
+        assert_eq!(order.get(), Enid40::ZERO);
+    }
+}
@@ -0,0 +1,317 @@
+use crate::enid::Enid40;
+use core::iter::FusedIterator;
+use core::ops::{Range, RangeInclusive};
+
+/// The bounds of a range of ENIDs as integers, with an exclusive end.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+struct Bounds {
+    start: u64,
+    end: u64,
+}
+
+impl Bounds {
+    const fn len(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+
+    const fn contains(&self, enid: Enid40) -> bool {
+        let value = enid.to_u64();
+        self.start <= value && value < self.end
+    }
+
+    fn nth(&mut self, n: u64) -> Option<Enid40> {
+        if n < self.len() {
+            let value = self.start + n;
+            self.start = value + 1;
+            Enid40::from_u64(value)
+        } else {
+            self.start = self.end.max(self.start);
+            None
+        }
+    }
+
+    fn nth_back(&mut self, n: u64) -> Option<Enid40> {
+        if n < self.len() {
+            let value = self.end - n - 1;
+            self.end = value;
+            Enid40::from_u64(value)
+        } else {
+            self.end = self.start.min(self.end);
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.len()) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+}
+
+/// A half-open range of [`Enid40`]s, `start..end`, which iterates over each
+/// ENID in order.
+///
+/// ENIDs are treated as big-endian integers. Skipping through the range, such
+/// as with [`Iterator::step_by`] or [`Iterator::nth`], takes constant time.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, EnidRange};
+/// let range = EnidRange::new(enid40!("0000000y"), enid40!("00000011"));
+///
+/// assert_eq!(range.len(), 3);
+/// assert!(range.contains(&enid40!("0000000z")));
+/// assert_eq!(
+///     range.collect::<Vec<_>>(),
+///     [enid40!("0000000y"), enid40!("0000000z"), enid40!("00000010")],
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnidRange {
+    bounds: Bounds,
+}
+
+impl EnidRange {
+    /// Creates a range from `start` up to, but not including, `end`.
+    ///
+    /// The range is empty if `end` is not after `start`.
+    #[must_use]
+    #[inline]
+    pub const fn new(start: Enid40, end: Enid40) -> Self {
+        Self {
+            bounds: Bounds {
+                start: start.to_u64(),
+                end: end.to_u64(),
+            },
+        }
+    }
+
+    /// Returns `true` if the ENID is in the range.
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, enid: &Enid40) -> bool {
+        self.bounds.contains(*enid)
+    }
+
+    /// Returns the number of ENIDs remaining in the range.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> u64 {
+        self.bounds.len()
+    }
+
+    /// Returns `true` if there are no ENIDs remaining in the range.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Iterator for EnidRange {
+    type Item = Enid40;
+
+    #[inline]
+    fn next(&mut self) -> Option<Enid40> {
+        self.bounds.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Enid40> {
+        self.bounds.nth(n as u64)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bounds.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Enid40> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for EnidRange {
+    #[inline]
+    fn next_back(&mut self) -> Option<Enid40> {
+        self.bounds.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Enid40> {
+        self.bounds.nth_back(n as u64)
+    }
+}
+
+impl FusedIterator for EnidRange {}
+
+impl From<Range<Enid40>> for EnidRange {
+    #[inline]
+    fn from(range: Range<Enid40>) -> Self {
+        Self::new(range.start, range.end)
+    }
+}
+
+/// An inclusive range of [`Enid40`]s, `start..=end`, which iterates over each
+/// ENID in order.
+///
+/// Unlike [`EnidRange`], this can include [`Enid40::MAX`].
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40, EnidRangeInclusive};
+/// let range = EnidRangeInclusive::new(Enid40::NIL, Enid40::MAX);
+///
+/// assert_eq!(range.len(), 1 << 40);
+///
+/// // Split the keyspace into 4 shards.
+/// let shards: Vec<_> = range.step_by(1 << 38).collect();
+/// assert_eq!(shards, [enid40!("00000000"), enid40!("80000000"), enid40!("g0000000"), enid40!("r0000000")]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EnidRangeInclusive {
+    bounds: Bounds,
+}
+
+impl EnidRangeInclusive {
+    /// Creates a range from `start` up to and including `end`.
+    ///
+    /// The range is empty if `end` is before `start`.
+    #[must_use]
+    #[inline]
+    pub const fn new(start: Enid40, end: Enid40) -> Self {
+        Self {
+            bounds: Bounds {
+                start: start.to_u64(),
+                end: end.to_u64() + 1,
+            },
+        }
+    }
+
+    /// Returns `true` if the ENID is in the range.
+    #[must_use]
+    #[inline]
+    pub const fn contains(&self, enid: &Enid40) -> bool {
+        self.bounds.contains(*enid)
+    }
+
+    /// Returns the number of ENIDs remaining in the range.
+    #[must_use]
+    #[inline]
+    pub const fn len(&self) -> u64 {
+        self.bounds.len()
+    }
+
+    /// Returns `true` if there are no ENIDs remaining in the range.
+    #[must_use]
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Iterator for EnidRangeInclusive {
+    type Item = Enid40;
+
+    #[inline]
+    fn next(&mut self) -> Option<Enid40> {
+        self.bounds.nth(0)
+    }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<Enid40> {
+        self.bounds.nth(n as u64)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.bounds.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Enid40> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for EnidRangeInclusive {
+    #[inline]
+    fn next_back(&mut self) -> Option<Enid40> {
+        self.bounds.nth_back(0)
+    }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<Enid40> {
+        self.bounds.nth_back(n as u64)
+    }
+}
+
+impl FusedIterator for EnidRangeInclusive {}
+
+impl From<RangeInclusive<Enid40>> for EnidRangeInclusive {
+    #[inline]
+    fn from(range: RangeInclusive<Enid40>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(start, end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::vec::Vec;
+
+    fn enid(value: u64) -> Enid40 {
+        Enid40::from_u64(value).unwrap()
+    }
+
+    #[test]
+    fn iterates_both_ways() {
+        let range = EnidRange::new(enid(10), enid(15));
+
+        assert_eq!(
+            range.clone().collect::<Vec<_>>(),
+            (10..15).map(enid).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            range.rev().collect::<Vec<_>>(),
+            (10..15).rev().map(enid).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn step_by_and_nth() {
+        let range = EnidRangeInclusive::new(enid(0), enid(10));
+
+        assert_eq!(
+            range.clone().step_by(3).collect::<Vec<_>>(),
+            [enid(0), enid(3), enid(6), enid(9)]
+        );
+
+        let mut range = range;
+        assert_eq!(range.nth(4), Some(enid(4)));
+        assert_eq!(range.nth_back(1), Some(enid(9)));
+        assert_eq!(range.len(), 4);
+        assert_eq!(range.nth(10), None);
+        assert!(range.is_empty());
+        assert_eq!(range.next(), None);
+        assert_eq!(range.next_back(), None);
+    }
+
+    #[test]
+    fn empty_and_max() {
+        assert!(EnidRange::new(enid(5), enid(5)).is_empty());
+        assert!(EnidRange::new(enid(6), enid(5)).is_empty());
+        assert_eq!(EnidRange::new(enid(6), enid(5)).next(), None);
+        assert!(EnidRangeInclusive::new(enid(6), enid(5)).is_empty());
+
+        let mut range = EnidRangeInclusive::new(Enid40::MAX, Enid40::MAX);
+        assert!(range.contains(&Enid40::MAX));
+        assert_eq!(range.next(), Some(Enid40::MAX));
+        assert_eq!(range.next(), None);
+    }
+}
@@ -0,0 +1,226 @@
+//! Rendezvous hashing, for assigning ENIDs to nodes.
+//!
+//! Rendezvous (or highest random weight) hashing scores every node for an ENID,
+//! and assigns the ENID to the node with the highest score. When a node is
+//! added or removed, only the ENIDs assigned to that node move. Unlike
+//! [`Enid40::shard`](crate::Enid40::shard), which numbers shards, nodes are
+//! identified by labels, such as host names, and can be listed in any order.
+//!
+//! With the `std` feature, nodes can also be given weights, so that larger
+//! nodes are assigned proportionally more ENIDs.
+//!
+//! The scores use a stable hash, so every process assigns an ENID to the same
+//! node.
+
+use crate::enid::Enid;
+use crate::hash;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// Returns the score of a node for an ENID.
+///
+/// The score is the 64-bit FNV-1a hash of the label followed by the ENID's
+/// bytes, passed through the SplitMix64 finalizer. The algorithm will never
+/// change.
+#[must_use]
+pub fn score(enid: impl Into<Enid>, label: &[u8]) -> u64 {
+    let enid = enid.into();
+    let hash = hash::fnv1a_64_update(hash::fnv1a_64(label), enid.as_bytes());
+
+    hash::mix64(hash)
+}
+
+/// Returns the node that an ENID is assigned to, or `None` if there are no
+/// nodes.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid80;
+/// # use enid::routing;
+/// let nodes = ["cache-a", "cache-b", "cache-c"];
+/// let enid = enid80!("y3gx5gxm-mpb8ey39");
+///
+/// let node = routing::select(enid, &nodes).unwrap();
+///
+/// // Removing a different node does not change the assignment.
+/// let other = nodes.iter().find(|&n| n != node).unwrap();
+/// let fewer = [*node, *other];
+/// assert_eq!(routing::select(enid, &fewer), Some(node));
+/// ```
+#[must_use]
+pub fn select<N: AsRef<[u8]>>(enid: impl Into<Enid>, nodes: &[N]) -> Option<&N> {
+    let enid = enid.into();
+
+    nodes
+        .iter()
+        .map(|node| (score(enid, node.as_ref()), node))
+        .max_by(|a, b| cmp_scores(a, b))
+        .map(|(_, node)| node)
+}
+
+/// Returns the nodes ordered by their score for an ENID, from highest to
+/// lowest.
+///
+/// The first node is the one returned by [`select`], and the rest can be used
+/// as fallbacks or replicas. This requires the `alloc` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid80;
+/// # use enid::routing;
+/// let nodes = ["cache-a", "cache-b", "cache-c"];
+/// let enid = enid80!("y3gx5gxm-mpb8ey39");
+///
+/// let ranked = routing::rank(enid, &nodes);
+///
+/// assert_eq!(ranked.len(), 3);
+/// assert_eq!(Some(ranked[0]), routing::select(enid, &nodes));
+/// ```
+#[cfg(feature = "alloc")]
+#[must_use]
+pub fn rank<N: AsRef<[u8]>>(enid: impl Into<Enid>, nodes: &[N]) -> Vec<&N> {
+    let enid = enid.into();
+
+    let mut scored: Vec<_> = nodes
+        .iter()
+        .map(|node| (score(enid, node.as_ref()), node))
+        .collect();
+    scored.sort_by(|a, b| cmp_scores(b, a));
+
+    scored.into_iter().map(|(_, node)| node).collect()
+}
+
+/// Returns the weighted score of a node for an ENID.
+///
+/// This uses the logarithmic method, `-weight / ln(h)`, where `h` is the
+/// [`score`] scaled to lie between 0 and 1, so each node wins in proportion to
+/// its weight. This requires the `std` feature.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn weighted_score(enid: impl Into<Enid>, label: &[u8], weight: f64) -> f64 {
+    // Use the top 53 bits, offset so that the value is never 0 or 1.
+    let h = ((score(enid, label) >> 11) as f64 + 0.5) / (1_u64 << 53) as f64;
+
+    -weight / h.ln()
+}
+
+/// Returns the node that an ENID is assigned to, given nodes with weights, or
+/// `None` if there are no nodes.
+///
+/// Nodes with a weight of zero or less are never selected. This requires the
+/// `std` feature.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::enid80;
+/// # use enid::routing;
+/// let nodes = [("small", 1.0), ("large", 4.0), ("drained", 0.0)];
+/// let enid = enid80!("y3gx5gxm-mpb8ey39");
+///
+/// let node = routing::select_weighted(enid, &nodes).unwrap();
+///
+/// assert_ne!(*node, "drained");
+/// ```
+#[cfg(feature = "std")]
+#[must_use]
+pub fn select_weighted<N: AsRef<[u8]>>(enid: impl Into<Enid>, nodes: &[(N, f64)]) -> Option<&N> {
+    let enid = enid.into();
+
+    nodes
+        .iter()
+        .filter(|(_, weight)| *weight > 0.0)
+        .map(|(node, weight)| (weighted_score(enid, node.as_ref(), *weight), node))
+        .max_by(|a, b| cmp_weighted_scores(a, b))
+        .map(|(_, node)| node)
+}
+
+/// Returns the nodes with a weight greater than zero, ordered by their
+/// weighted score for an ENID, from highest to lowest.
+///
+/// This requires the `std` feature.
+#[cfg(feature = "std")]
+#[must_use]
+pub fn rank_weighted<N: AsRef<[u8]>>(enid: impl Into<Enid>, nodes: &[(N, f64)]) -> Vec<&N> {
+    let enid = enid.into();
+
+    let mut scored: Vec<_> = nodes
+        .iter()
+        .filter(|(_, weight)| *weight > 0.0)
+        .map(|(node, weight)| (weighted_score(enid, node.as_ref(), *weight), node))
+        .collect();
+    scored.sort_by(|a, b| cmp_weighted_scores(b, a));
+
+    scored.into_iter().map(|(_, node)| node).collect()
+}
+
+/// Compares scores, breaking ties by label so the result does not depend on
+/// the order of the nodes.
+fn cmp_scores<N: AsRef<[u8]>>(a: &(u64, &N), b: &(u64, &N)) -> Ordering {
+    a.0.cmp(&b.0).then_with(|| a.1.as_ref().cmp(b.1.as_ref()))
+}
+
+#[cfg(feature = "std")]
+fn cmp_weighted_scores<N: AsRef<[u8]>>(a: &(f64, &N), b: &(f64, &N)) -> Ordering {
+    a.0.total_cmp(&b.0)
+        .then_with(|| a.1.as_ref().cmp(b.1.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Enid80;
+
+    fn enids() -> impl Iterator<Item = Enid80> {
+        (0..10_000_u128).map(|i| Enid80::from_u128(i * 0x1_0000_0001).unwrap())
+    }
+
+    #[test]
+    fn removing_a_node_only_moves_its_enids() {
+        let nodes = ["a", "b", "c", "d", "e"];
+        let remaining = ["a", "b", "d", "e"];
+
+        for enid in enids() {
+            let before = select(enid, &nodes).unwrap();
+            let after = select(enid, &remaining).unwrap();
+
+            if *before != "c" {
+                assert_eq!(before, after);
+            }
+        }
+    }
+
+    #[test]
+    fn order_of_nodes_does_not_matter() {
+        let nodes = ["a", "b", "c"];
+        let reversed = ["c", "b", "a"];
+
+        for enid in enids().take(100) {
+            assert_eq!(select(enid, &nodes), select(enid, &reversed));
+            #[cfg(feature = "alloc")]
+            assert_eq!(rank(enid, &nodes), rank(enid, &reversed));
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn weights_are_proportional() {
+        let nodes = [("a", 1.0), ("b", 3.0), ("c", 0.0)];
+        let mut counts = [0; 3];
+
+        for enid in enids() {
+            match *select_weighted(enid, &nodes).unwrap() {
+                "a" => counts[0] += 1,
+                "b" => counts[1] += 1,
+                _ => counts[2] += 1,
+            }
+        }
+
+        assert!((2_000..3_000).contains(&counts[0]), "{counts:?}");
+        assert!((7_000..8_000).contains(&counts[1]), "{counts:?}");
+        assert_eq!(counts[2], 0);
+    }
+}
@@ -0,0 +1,208 @@
+//! Serializes ENIDs as bytes, regardless of the format.
+//!
+//! This can be used with `#[serde(with)]` on a field holding any ENID type, to
+//! write the bytes even in human-readable formats, where ENIDs would otherwise
+//! be written as strings. An [`Enid`](crate::Enid) is written as 5 or 10
+//! bytes, and its size is taken from the number of bytes when deserializing.
+//!
+//! ENIDs are deserialized from either a byte string or a sequence of bytes,
+//! which is how formats such as JSON represent bytes.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::{enid40, Enid40};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Record {
+//!     #[serde(with = "enid::serde::bytes")]
+//!     id: Enid40,
+//! }
+//!
+//! let record = Record { id: enid40!("m6sc7n75") };
+//! let json = serde_json::to_string(&record).unwrap();
+//!
+//! assert_eq!(json, r#"{"id":[161,178,195,212,229]}"#);
+//! assert_eq!(serde_json::from_str::<Record>(&json).unwrap().id, record.id);
+//! ```
+
+use core::fmt::{self, Formatter};
+use core::marker::PhantomData;
+use serde::de::{self, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes an ENID as its bytes.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails to serialize the bytes.
+#[inline]
+pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+    enid: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_bytes(enid.as_ref())
+}
+
+/// Deserializes an ENID from its bytes.
+///
+/// # Errors
+///
+/// Returns an error if the input is not bytes, or is not the size of the
+/// ENID.
+#[inline]
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: for<'a> TryFrom<&'a [u8]>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_bytes(BytesVisitor(PhantomData))
+}
+
+struct BytesVisitor<T>(PhantomData<T>);
+
+impl<'de, T: for<'a> TryFrom<&'a [u8]>> Visitor<'de> for BytesVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("the bytes of an ENID")
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        T::try_from(v).map_err(|_| E::invalid_length(v.len(), &self))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, seq: A) -> Result<T, A::Error> {
+        // No ENID type read by this module is longer than 20 bytes.
+        let (bytes, len) = crate::external::seq_bytes::<A, 20>(seq, &self)?;
+        let bytes = &bytes[..len];
+
+        T::try_from(bytes).map_err(|_| de::Error::invalid_value(Unexpected::Bytes(bytes), &self))
+    }
+}
+
+/// An ENID that is serialized with this module.
+struct Bytes<T>(T);
+
+impl<T: AsRef<[u8]>> Serialize for Bytes<&T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+impl<'de, T: for<'a> TryFrom<&'a [u8]>> Deserialize<'de> for Bytes<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Bytes)
+    }
+}
+
+/// Serializes an `Option` of an ENID as optional bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     #[serde(with = "enid::serde::bytes::option")]
+///     parent: Option<Enid40>,
+/// }
+///
+/// let record = Record { parent: Some(enid40!("m6sc7n75")) };
+/// let json = serde_json::to_string(&record).unwrap();
+///
+/// assert_eq!(json, r#"{"parent":[161,178,195,212,229]}"#);
+/// assert_eq!(serde_json::from_str::<Record>(&json).unwrap().parent, record.parent);
+/// ```
+pub mod option {
+    use super::Bytes;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an optional ENID as its bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the value.
+    #[inline]
+    pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+        enid: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        enid.as_ref().map(Bytes).serialize(serializer)
+    }
+
+    /// Deserializes an optional ENID from its bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not bytes or none, or is not the size
+    /// of the ENID.
+    #[inline]
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: for<'a> TryFrom<&'a [u8]>,
+        D: Deserializer<'de>,
+    {
+        Option::<Bytes<T>>::deserialize(deserializer).map(|enid| enid.map(|Bytes(enid)| enid))
+    }
+}
+
+/// Serializes a collection of ENIDs as a sequence of byte strings.
+///
+/// This can be used with any collection that can be iterated by reference
+/// and collected from an iterator, such as `Vec<T>` or `BTreeSet<T>`.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid, Enid};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Batch {
+///     #[serde(with = "enid::serde::bytes::seq")]
+///     ids: Vec<Enid>,
+/// }
+///
+/// let batch = Batch { ids: vec![enid!("m6sc7n75"), enid!("y3gx5gxm-mpb8ey39")] };
+/// let json = serde_json::to_string(&batch).unwrap();
+///
+/// assert_eq!(serde_json::from_str::<Batch>(&json).unwrap().ids, batch.ids);
+/// ```
+pub mod seq {
+    use super::Bytes;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes each ENID in a collection as its bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the sequence.
+    #[inline]
+    pub fn serialize<'a, C, T, S>(enids: &'a C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a T>,
+        T: AsRef<[u8]> + 'a,
+        S: Serializer,
+    {
+        serializer.collect_seq(enids.into_iter().map(Bytes))
+    }
+
+    /// Deserializes a collection of ENIDs from their bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a sequence, or if any element is
+    /// not the size of an ENID.
+    #[inline]
+    pub fn deserialize<'de, C, T, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: FromIterator<T>,
+        T: for<'a> TryFrom<&'a [u8]>,
+        D: Deserializer<'de>,
+    {
+        crate::serde::collect_seq(deserializer, |Bytes(enid)| enid)
+    }
+}
@@ -0,0 +1,114 @@
+//! Helpers for use with serde's `#[serde(with = "...")]` attribute.
+//!
+//! ENIDs serialize as strings in human-readable formats, and as bytes in
+//! other formats. The [`string`], [`bytes`], and [`u64`](mod@u64) modules
+//! can be used on a field to always use one form, regardless of the format.
+//!
+//! Because `#[serde(with)]` applies to the whole field, each of these modules
+//! has an `option` submodule for fields of type `Option<T>`, and a `seq`
+//! submodule for fields holding a collection, such as `Vec<T>`.
+//!
+//! ENIDs can be used as map keys directly. In formats that are not
+//! human-readable, they are written as byte keys, but are read from either
+//! bytes or strings, so maps written with string keys by another producer can
+//! still be read. To always write string keys, use [`string_keys`] on the map.
+
+pub mod bytes;
+pub mod string;
+pub mod string_keys;
+pub mod u64;
+
+use core::fmt::{self, Formatter};
+use core::iter;
+use core::marker::PhantomData;
+use core::str::{self, FromStr};
+use serde::de::{self, DeserializeSeed, SeqAccess, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes a value with [`FromStr`] from either a string or a UTF-8 byte
+/// string.
+struct FromStrVisitor<T>(PhantomData<T>);
+
+impl<T> FromStrVisitor<T> {
+    const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<'de, T: FromStr> DeserializeSeed<'de> for FromStrVisitor<T> {
+    type Value = T;
+
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<T, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+impl<T: FromStr> Visitor<'_> for FromStrVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an ENID string")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+        s.parse()
+            .map_err(|_| E::invalid_value(Unexpected::Str(s), &self))
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<T, E> {
+        match str::from_utf8(v) {
+            Ok(s) => self.visit_str(s),
+            Err(_) => Err(E::invalid_value(Unexpected::Bytes(v), &self)),
+        }
+    }
+}
+
+/// Deserializes a sequence into any collection, without collecting the
+/// elements into a vector first.
+fn collect_seq<'de, D, W, T, C>(deserializer: D, unwrap: fn(W) -> T) -> Result<C, D::Error>
+where
+    D: Deserializer<'de>,
+    W: Deserialize<'de>,
+    C: FromIterator<T>,
+{
+    struct SeqVisitor<W, T, C> {
+        unwrap: fn(W) -> T,
+        marker: PhantomData<C>,
+    }
+
+    impl<'de, W, T, C> Visitor<'de> for SeqVisitor<W, T, C>
+    where
+        W: Deserialize<'de>,
+        C: FromIterator<T>,
+    {
+        type Value = C;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a sequence of ENIDs")
+        }
+
+        fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+            let mut error = None;
+
+            let collection = iter::from_fn(|| match seq.next_element() {
+                Ok(element) => element.map(self.unwrap),
+                Err(e) => {
+                    error = Some(e);
+                    None
+                }
+            })
+            .collect();
+
+            match error {
+                Some(e) => Err(e),
+                None => Ok(collection),
+            }
+        }
+    }
+
+    deserializer.deserialize_seq(SeqVisitor {
+        unwrap,
+        marker: PhantomData,
+    })
+}
@@ -0,0 +1,179 @@
+//! Serializes ENIDs as strings, regardless of the format.
+//!
+//! This can be used with `#[serde(with)]` on a field holding any ENID type, to
+//! write the string form even in formats that are not human-readable, where
+//! ENIDs would otherwise be written as bytes.
+//!
+//! ENIDs are deserialized from either a string or a UTF-8 byte string.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::{enid80, Enid80};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "enid::serde::string")]
+//!     id: Enid80,
+//! }
+//!
+//! let event = Event { id: enid80!("y3gx5gxm-mpb8ey39") };
+//! let json = serde_json::to_string(&event).unwrap();
+//!
+//! assert_eq!(json, r#"{"id":"y3gx5gxm-mpb8ey39"}"#);
+//! assert_eq!(serde_json::from_str::<Event>(&json).unwrap().id, event.id);
+//! ```
+
+use super::FromStrVisitor;
+use core::fmt::Display;
+use core::str::FromStr;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes an ENID as its string form.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails to serialize the string.
+#[inline]
+pub fn serialize<T: Display, S: Serializer>(enid: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(enid)
+}
+
+/// Deserializes an ENID from its string form.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a string, or is not a valid ENID.
+#[inline]
+pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_str(FromStrVisitor::new())
+}
+
+/// An ENID that is serialized with this module.
+struct Str<T>(T);
+
+impl<T: Display> Serialize for Str<&T> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+impl<'de, T: FromStr> Deserialize<'de> for Str<T> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Str)
+    }
+}
+
+/// Serializes an `Option` of an ENID as an optional string.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Task {
+///     #[serde(with = "enid::serde::string::option")]
+///     parent: Option<Enid40>,
+/// }
+///
+/// let task = Task { parent: Some(enid40!("m6sc7n75")) };
+/// let json = serde_json::to_string(&task).unwrap();
+///
+/// assert_eq!(json, r#"{"parent":"m6sc7n75"}"#);
+/// assert_eq!(serde_json::from_str::<Task>(r#"{"parent":null}"#).unwrap().parent, None);
+/// ```
+pub mod option {
+    use super::Str;
+    use core::fmt::Display;
+    use core::str::FromStr;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an optional ENID as its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the value.
+    #[inline]
+    pub fn serialize<T: Display, S: Serializer>(
+        enid: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        enid.as_ref().map(Str).serialize(serializer)
+    }
+
+    /// Deserializes an optional ENID from its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a string or none, or is not a
+    /// valid ENID.
+    #[inline]
+    pub fn deserialize<'de, T: FromStr, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<T>, D::Error> {
+        Option::<Str<T>>::deserialize(deserializer).map(|enid| enid.map(|Str(enid)| enid))
+    }
+}
+
+/// Serializes a collection of ENIDs as a sequence of strings.
+///
+/// This can be used with any collection that can be iterated by reference
+/// and collected from an iterator, such as `Vec<T>` or `BTreeSet<T>`.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Batch {
+///     #[serde(with = "enid::serde::string::seq")]
+///     ids: Vec<Enid40>,
+/// }
+///
+/// let batch = Batch { ids: vec![enid40!("m6sc7n75"), enid40!("zzzzzzzz")] };
+/// let json = serde_json::to_string(&batch).unwrap();
+///
+/// assert_eq!(json, r#"{"ids":["m6sc7n75","zzzzzzzz"]}"#);
+/// assert_eq!(serde_json::from_str::<Batch>(&json).unwrap().ids, batch.ids);
+/// ```
+pub mod seq {
+    use super::Str;
+    use core::fmt::Display;
+    use core::str::FromStr;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes each ENID in a collection as its string form.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the sequence.
+    #[inline]
+    pub fn serialize<'a, C, T, S>(enids: &'a C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a T>,
+        T: Display + 'a,
+        S: Serializer,
+    {
+        serializer.collect_seq(enids.into_iter().map(Str))
+    }
+
+    /// Deserializes a collection of ENIDs from their string forms.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a sequence, or if any element is
+    /// not a valid ENID.
+    #[inline]
+    pub fn deserialize<'de, C, T, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: FromIterator<T>,
+        T: FromStr,
+        D: Deserializer<'de>,
+    {
+        crate::serde::collect_seq(deserializer, |Str(enid)| enid)
+    }
+}
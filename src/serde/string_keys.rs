@@ -0,0 +1,114 @@
+//! Serializes maps with ENID keys using the string form of each key.
+//!
+//! ENIDs serialize as byte arrays in formats that are not human-readable, but
+//! some of these formats (and the consumers reading them) only support string
+//! map keys. This module can be used with `#[serde(with)]` on a map field to
+//! always write keys as ENID strings, regardless of the format.
+//!
+//! Keys are deserialized from either a string or a UTF-8 byte string.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::{enid40, Enid40};
+//! # use serde::{Deserialize, Serialize};
+//! # use std::collections::BTreeMap;
+//! #[derive(Serialize, Deserialize)]
+//! struct Counts {
+//!     #[serde(with = "enid::serde::string_keys")]
+//!     counts: BTreeMap<Enid40, u32>,
+//! }
+//!
+//! let counts = Counts {
+//!     counts: BTreeMap::from([(enid40!("m6sc7n75"), 1)]),
+//! };
+//!
+//! let json = serde_json::to_string(&counts).unwrap();
+//! assert_eq!(json, r#"{"counts":{"m6sc7n75":1}}"#);
+//!
+//! let counts: Counts = serde_json::from_str(&json).unwrap();
+//! assert_eq!(counts.counts[&enid40!("m6sc7n75")], 1);
+//! ```
+
+use super::FromStrVisitor;
+use core::fmt::{self, Display, Formatter};
+use core::iter;
+use core::marker::PhantomData;
+use core::str::FromStr;
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes a map, writing each key as an ENID string.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails to serialize the map.
+pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Display + 'a,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(map.into_iter().map(|(key, value)| (StrKey(key), value)))
+}
+
+/// Deserializes a map, reading each key from an ENID string.
+///
+/// # Errors
+///
+/// Returns an error if the input is not a map, or if any key is not a valid
+/// ENID.
+pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: FromIterator<(K, V)>,
+    K: FromStr,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    struct MapVisitor<M, K, V>(PhantomData<(M, K, V)>);
+
+    impl<'de, M, K, V> Visitor<'de> for MapVisitor<M, K, V>
+    where
+        M: FromIterator<(K, V)>,
+        K: FromStr,
+        V: Deserialize<'de>,
+    {
+        type Value = M;
+
+        fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+            f.write_str("a map with ENID keys")
+        }
+
+        fn visit_map<A: MapAccess<'de>>(self, mut access: A) -> Result<M, A::Error> {
+            let mut error = None;
+
+            let map = iter::from_fn(|| {
+                match access.next_entry_seed(FromStrVisitor::new(), PhantomData) {
+                    Ok(entry) => entry,
+                    Err(e) => {
+                        error = Some(e);
+                        None
+                    }
+                }
+            })
+            .collect();
+
+            match error {
+                Some(e) => Err(e),
+                None => Ok(map),
+            }
+        }
+    }
+
+    deserializer.deserialize_map(MapVisitor(PhantomData))
+}
+
+struct StrKey<'a, K>(&'a K);
+
+impl<K: Display> Serialize for StrKey<'_, K> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self.0)
+    }
+}
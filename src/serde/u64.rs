@@ -0,0 +1,198 @@
+//! Serializes [`Enid40`] values as integers, regardless of the format.
+//!
+//! This can be used with `#[serde(with)]` on an `Enid40` field, for APIs that
+//! represent IDs as numbers. The integer is the value of
+//! [`Enid40::to_u64`], which is less than 2<sup>40</sup>, so it is represented
+//! exactly by JavaScript numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::{enid40, Enid40};
+//! # use serde::{Deserialize, Serialize};
+//! #[derive(Serialize, Deserialize)]
+//! struct User {
+//!     #[serde(with = "enid::serde::u64")]
+//!     id: Enid40,
+//! }
+//!
+//! let user = User { id: enid40!("m6sc7n75") };
+//! let json = serde_json::to_string(&user).unwrap();
+//!
+//! assert_eq!(json, r#"{"id":694488913125}"#);
+//! assert_eq!(serde_json::from_str::<User>(&json).unwrap().id, user.id);
+//! assert!(serde_json::from_str::<User>(r#"{"id":1099511627776}"#).is_err());
+//! ```
+
+use crate::enid::Enid40;
+use core::fmt::{self, Formatter};
+use serde::de::{self, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serializes an ENID as an integer.
+///
+/// # Errors
+///
+/// Returns an error if the serializer fails to serialize the integer.
+#[inline]
+pub fn serialize<S: Serializer>(enid: &Enid40, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_u64(enid.to_u64())
+}
+
+/// Deserializes an ENID from an integer.
+///
+/// # Errors
+///
+/// Returns an error if the input is not an integer, or is not less than
+/// 2<sup>40</sup>.
+#[inline]
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Enid40, D::Error> {
+    deserializer.deserialize_u64(U64Visitor)
+}
+
+struct U64Visitor;
+
+impl Visitor<'_> for U64Visitor {
+    type Value = Enid40;
+
+    fn expecting(&self, f: &mut Formatter) -> fmt::Result {
+        f.write_str("an integer less than 2^40")
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Enid40, E> {
+        Enid40::from_u64(v).ok_or_else(|| E::invalid_value(Unexpected::Unsigned(v), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Enid40, E> {
+        match u64::try_from(v) {
+            Ok(v) => self.visit_u64(v),
+            Err(_) => Err(E::invalid_value(Unexpected::Signed(v), &self)),
+        }
+    }
+}
+
+/// An ENID that is serialized with this module.
+struct Integer<T>(T);
+
+impl Serialize for Integer<&Enid40> {
+    #[inline]
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Integer<Enid40> {
+    #[inline]
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Integer)
+    }
+}
+
+/// Serializes an `Option<Enid40>` as an optional integer.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct User {
+///     #[serde(with = "enid::serde::u64::option")]
+///     manager: Option<Enid40>,
+/// }
+///
+/// let user = User { manager: None };
+/// let json = serde_json::to_string(&user).unwrap();
+///
+/// assert_eq!(json, r#"{"manager":null}"#);
+/// assert_eq!(serde_json::from_str::<User>(r#"{"manager":1}"#).unwrap().manager, Enid40::from_u64(1));
+/// ```
+pub mod option {
+    use super::Integer;
+    use crate::enid::Enid40;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    /// Serializes an optional ENID as an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the value.
+    #[inline]
+    pub fn serialize<S: Serializer>(
+        enid: &Option<Enid40>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        enid.as_ref().map(Integer).serialize(serializer)
+    }
+
+    /// Deserializes an optional ENID from an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not an integer or none, or is not less
+    /// than 2<sup>40</sup>.
+    #[inline]
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Enid40>, D::Error> {
+        Option::<Integer<Enid40>>::deserialize(deserializer)
+            .map(|enid| enid.map(|Integer(enid)| enid))
+    }
+}
+
+/// Serializes a collection of [`Enid40`] values as a sequence of integers.
+///
+/// This can be used with any collection that can be iterated by reference
+/// and collected from an iterator, such as `Vec<Enid40>` or
+/// `BTreeSet<Enid40>`.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, Enid40};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Serialize, Deserialize)]
+/// struct Team {
+///     #[serde(with = "enid::serde::u64::seq")]
+///     members: Vec<Enid40>,
+/// }
+///
+/// let team: Team = serde_json::from_str(r#"{"members":[1,2]}"#).unwrap();
+///
+/// assert_eq!(team.members, [Enid40::from_u64(1).unwrap(), Enid40::from_u64(2).unwrap()]);
+/// assert_eq!(serde_json::to_string(&team).unwrap(), r#"{"members":[1,2]}"#);
+/// ```
+pub mod seq {
+    use super::Integer;
+    use crate::enid::Enid40;
+    use serde::{Deserializer, Serializer};
+
+    /// Serializes each ENID in a collection as an integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails to serialize the sequence.
+    #[inline]
+    pub fn serialize<'a, C, S>(enids: &'a C, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        &'a C: IntoIterator<Item = &'a Enid40>,
+        S: Serializer,
+    {
+        serializer.collect_seq(enids.into_iter().map(Integer))
+    }
+
+    /// Deserializes a collection of ENIDs from integers.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is not a sequence, or if any element is
+    /// not an integer less than 2<sup>40</sup>.
+    #[inline]
+    pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+    where
+        C: FromIterator<Enid40>,
+        D: Deserializer<'de>,
+    {
+        crate::serde::collect_seq(deserializer, |Integer(enid)| enid)
+    }
+}
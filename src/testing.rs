@@ -0,0 +1,418 @@
+//! Utilities for testing code that handles ENIDs.
+
+use crate::ascii;
+use crate::base32::{ALPHABET, SEPARATOR};
+use crate::enid::{Enid, Enid40, Enid80};
+use crate::generator::{EnidGenerator, SequentialGenerator};
+use crate::hash;
+use core::fmt::{self, Debug, Display, Formatter};
+
+const INVALID_CHARS: [u8; 15] = *b"ilouILOU!_.+/~ ";
+
+/// The way in which a [`NearMiss`] differs from a valid ENID string.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NearMissKind {
+    /// One character has been removed.
+    TooShort,
+
+    /// One extra character has been inserted.
+    TooLong,
+
+    /// One character has been replaced with a character outside the alphabet.
+    InvalidChar,
+
+    /// The separator is in the wrong position, or is present in a 40-bit ENID.
+    MisplacedSeparator,
+
+    /// One character has been replaced with an uppercase letter.
+    Uppercase,
+}
+
+impl NearMissKind {
+    const ALL: [Self; 5] = [
+        Self::TooShort,
+        Self::TooLong,
+        Self::InvalidChar,
+        Self::MisplacedSeparator,
+        Self::Uppercase,
+    ];
+}
+
+/// A string that is almost, but not quite, a valid ENID.
+///
+/// Created by [`NearMisses`].
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+pub struct NearMiss {
+    buf: [u8; 18],
+    len: usize,
+    kind: NearMissKind,
+}
+
+impl NearMiss {
+    /// Returns the string.
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        ascii::to_str(&self.buf[..self.len])
+    }
+
+    /// Returns the way in which the string differs from a valid ENID.
+    #[must_use]
+    #[inline]
+    pub const fn kind(&self) -> NearMissKind {
+        self.kind
+    }
+
+    fn new(chars: &[u8], kind: NearMissKind) -> Self {
+        let mut buf = [0; 18];
+        buf[..chars.len()].copy_from_slice(chars);
+
+        Self {
+            buf,
+            len: chars.len(),
+            kind,
+        }
+    }
+
+    fn remove(&mut self, idx: usize) {
+        self.buf.copy_within(idx + 1..self.len, idx);
+        self.len -= 1;
+    }
+
+    fn insert(&mut self, idx: usize, c: u8) {
+        self.buf.copy_within(idx..self.len, idx + 1);
+        self.buf[idx] = c;
+        self.len += 1;
+    }
+}
+
+impl Debug for NearMiss {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("NearMiss")
+            .field("str", &self.as_str())
+            .field("kind", &self.kind)
+            .finish()
+    }
+}
+
+impl Display for NearMiss {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.pad(self.as_str())
+    }
+}
+
+/// A deterministic generator of strings that are almost valid ENIDs.
+///
+/// Each string is a valid ENID with a single mutation applied, as described by
+/// [`NearMissKind`]. Every string is rejected by the parsers in this crate, so
+/// the generator can be used to systematically test rejection paths.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::Enid80;
+/// # use enid::testing::NearMisses;
+/// for near_miss in NearMisses::enid80(42).take(100) {
+///     assert!(near_miss.as_str().parse::<Enid80>().is_err());
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct NearMisses {
+    state: u64,
+    is_enid80: bool,
+}
+
+impl NearMisses {
+    /// Creates a generator of near-miss 40-bit ENID strings from the given seed.
+    #[must_use]
+    #[inline]
+    pub const fn enid40(seed: u64) -> Self {
+        Self {
+            state: seed,
+            is_enid80: false,
+        }
+    }
+
+    /// Creates a generator of near-miss 80-bit ENID strings from the given seed.
+    #[must_use]
+    #[inline]
+    pub const fn enid80(seed: u64) -> Self {
+        Self {
+            state: seed,
+            is_enid80: true,
+        }
+    }
+
+    /// Generates a near-miss string of the given kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::testing::{NearMissKind, NearMisses};
+    /// let near_miss = NearMisses::enid40(42).next_of_kind(NearMissKind::TooShort);
+    ///
+    /// assert_eq!(near_miss.as_str().len(), 7);
+    /// ```
+    pub fn next_of_kind(&mut self, kind: NearMissKind) -> NearMiss {
+        let mut near_miss = if self.is_enid80 {
+            let enid = Enid80::from_bytes(self.next_bytes());
+            NearMiss::new(enid.write_ascii(&mut [0; 17]), kind)
+        } else {
+            let enid = Enid40::from_bytes(self.next_bytes());
+            NearMiss::new(enid.write_ascii(&mut [0; 8]), kind)
+        };
+
+        match kind {
+            NearMissKind::TooShort => {
+                let idx = self.next_char_idx();
+                near_miss.remove(idx);
+            }
+            NearMissKind::TooLong => {
+                let idx = self.next_below(near_miss.len + 1);
+                let c = ALPHABET[self.next_below(ALPHABET.len())];
+                near_miss.insert(idx, c);
+            }
+            NearMissKind::InvalidChar => {
+                let idx = self.next_char_idx();
+                near_miss.buf[idx] = INVALID_CHARS[self.next_below(INVALID_CHARS.len())];
+            }
+            NearMissKind::MisplacedSeparator => {
+                let idx = self.next_char_idx();
+                if self.is_enid80 {
+                    near_miss.buf.swap(8, idx);
+                } else {
+                    near_miss.buf[idx] = SEPARATOR;
+                }
+            }
+            NearMissKind::Uppercase => {
+                let idx = self.next_char_idx();
+                let c = ALPHABET[10 + self.next_below(ALPHABET.len() - 10)];
+                near_miss.buf[idx] = c.to_ascii_uppercase();
+            }
+        }
+
+        near_miss
+    }
+
+    // SplitMix64, which is simple and good enough for generating test inputs.
+    fn next_u64(&mut self) -> u64 {
+        hash::splitmix64(&mut self.state)
+    }
+
+    fn next_below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn next_bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut bytes = [0; N];
+
+        for chunk in bytes.chunks_mut(8) {
+            chunk.copy_from_slice(&self.next_u64().to_be_bytes()[..chunk.len()]);
+        }
+
+        bytes
+    }
+
+    /// Returns the index of a random character, excluding the separator.
+    fn next_char_idx(&mut self) -> usize {
+        if self.is_enid80 {
+            let idx = self.next_below(16);
+            if idx < 8 { idx } else { idx + 1 }
+        } else {
+            self.next_below(8)
+        }
+    }
+}
+
+impl Iterator for NearMisses {
+    type Item = NearMiss;
+
+    fn next(&mut self) -> Option<NearMiss> {
+        let kind = NearMissKind::ALL[self.next_below(NearMissKind::ALL.len())];
+        Some(self.next_of_kind(kind))
+    }
+}
+
+/// A generator that returns predetermined ENIDs, for asserting on exactly which
+/// IDs were assigned.
+///
+/// A generator either returns the ENIDs in a sequence, in order, or returns
+/// incrementing ENIDs starting from a base. The methods that return an ENID
+/// panic if the sequence is exhausted or the next ENID is the wrong size; the
+/// `try_` methods return `None` instead.
+///
+/// `FakeGenerator` implements [`EnidGenerator`], so it can be passed to code
+/// that is generic over how IDs are generated.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::{enid40, enid80, Enid};
+/// # use enid::testing::FakeGenerator;
+/// let ids = [Enid::from(enid40!("m6sc7n75")), Enid::from(enid80!("y3gx5gxm-kbhqr4fs"))];
+/// let mut generator = FakeGenerator::from_sequence(&ids);
+///
+/// assert_eq!(generator.next40(), enid40!("m6sc7n75"));
+/// assert_eq!(generator.next80(), enid80!("y3gx5gxm-kbhqr4fs"));
+/// assert_eq!(generator.try_next40(), None);
+/// assert_eq!(generator.issued(), 2);
+///
+/// let mut generator = FakeGenerator::incrementing(enid40!("m6sc7n75"));
+///
+/// assert_eq!(generator.next40(), enid40!("m6sc7n75"));
+/// assert_eq!(generator.next40(), enid40!("m6sc7n76"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FakeGenerator<'a> {
+    source: FakeSource<'a>,
+    issued: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum FakeSource<'a> {
+    Sequence(&'a [Enid]),
+    Incrementing(SequentialGenerator),
+}
+
+impl<'a> FakeGenerator<'a> {
+    /// Creates a generator that returns the given ENIDs, in order.
+    #[must_use]
+    #[inline]
+    pub const fn from_sequence(ids: &'a [Enid]) -> Self {
+        Self {
+            source: FakeSource::Sequence(ids),
+            issued: 0,
+        }
+    }
+
+    /// Creates a generator that returns incrementing ENIDs, starting from the
+    /// given ENID.
+    ///
+    /// The ENIDs are incremented as big-endian integers, wrapping on overflow,
+    /// as with [`SequentialGenerator`].
+    /// A 40-bit ENID is extended with leading zeros when an 80-bit ENID is
+    /// requested, and an 80-bit ENID is truncated to its last 40 bits when a
+    /// 40-bit ENID is requested.
+    #[must_use]
+    pub fn incrementing(base: impl Into<Enid>) -> Self {
+        let mut bytes = [0; 16];
+        let base = base.into();
+        let base = base.as_bytes();
+        bytes[16 - base.len()..].copy_from_slice(base);
+
+        Self {
+            source: FakeSource::Incrementing(SequentialGenerator::starting_at(
+                u128::from_be_bytes(bytes),
+            )),
+            issued: 0,
+        }
+    }
+
+    /// Returns the number of ENIDs returned so far.
+    #[must_use]
+    #[inline]
+    pub const fn issued(&self) -> usize {
+        self.issued
+    }
+
+    /// Returns the next 40-bit ENID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sequence is exhausted, or the next ENID is an [`Enid80`].
+    #[track_caller]
+    pub fn next40(&mut self) -> Enid40 {
+        self.try_next40()
+            .expect("FakeGenerator has no more 40-bit ENIDs")
+    }
+
+    /// Returns the next 80-bit ENID.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sequence is exhausted, or the next ENID is an [`Enid40`].
+    #[track_caller]
+    pub fn next80(&mut self) -> Enid80 {
+        self.try_next80()
+            .expect("FakeGenerator has no more 80-bit ENIDs")
+    }
+
+    /// Returns the next 40-bit ENID, or `None` if the sequence is exhausted or
+    /// the next ENID is an [`Enid80`].
+    pub fn try_next40(&mut self) -> Option<Enid40> {
+        let enid = match &mut self.source {
+            FakeSource::Sequence(ids) => match ids.split_first() {
+                Some((Enid::Enid40(enid), rest)) => {
+                    *ids = rest;
+                    *enid
+                }
+                _ => return None,
+            },
+            FakeSource::Incrementing(generator) => generator.next40(),
+        };
+
+        self.issued += 1;
+        Some(enid)
+    }
+
+    /// Returns the next 80-bit ENID, or `None` if the sequence is exhausted or
+    /// the next ENID is an [`Enid40`].
+    pub fn try_next80(&mut self) -> Option<Enid80> {
+        let enid = match &mut self.source {
+            FakeSource::Sequence(ids) => match ids.split_first() {
+                Some((Enid::Enid80(enid), rest)) => {
+                    *ids = rest;
+                    *enid
+                }
+                _ => return None,
+            },
+            FakeSource::Incrementing(generator) => generator.next80(),
+        };
+
+        self.issued += 1;
+        Some(enid)
+    }
+}
+
+impl EnidGenerator for FakeGenerator<'_> {
+    #[inline]
+    fn next40(&mut self) -> Enid40 {
+        self.next40()
+    }
+
+    #[inline]
+    fn next80(&mut self) -> Enid80 {
+        self.next80()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn near_misses_are_invalid() {
+        for near_miss in NearMisses::enid40(0).take(1000) {
+            assert!(
+                Enid40::parse_str(near_miss.as_str()).is_err(),
+                "{near_miss:?}"
+            );
+            assert!(
+                Enid::parse_str(near_miss.as_str()).is_err(),
+                "{near_miss:?}"
+            );
+        }
+
+        for near_miss in NearMisses::enid80(0).take(1000) {
+            assert!(
+                Enid80::parse_str(near_miss.as_str()).is_err(),
+                "{near_miss:?}"
+            );
+            assert!(
+                Enid::parse_str(near_miss.as_str()).is_err(),
+                "{near_miss:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,195 @@
+use crate::clock::EnidClock;
+use crate::enid::Enid80;
+
+/// A generator of 80-bit ENIDs that sort by the time at which they were
+/// created, like ULIDs.
+///
+/// The first 48 bits of each ENID hold the current time in milliseconds since
+/// the Unix epoch, as a big-endian integer, and the remaining 32 bits are
+/// random. Because ENIDs are compared and formatted most significant bit
+/// first, ENIDs created in different milliseconds sort in creation order
+/// whether they are compared directly or as strings. ENIDs created within the
+/// same millisecond are ordered randomly.
+///
+/// # Examples
+///
+/// ```
+/// # use enid::TimeOrdered;
+/// # use enid::clock::FixedClock;
+/// let earlier = TimeOrdered::new(FixedClock(1_700_000_000_000));
+/// let later = TimeOrdered::new(FixedClock(1_700_000_000_001));
+///
+/// let a = earlier.generate_with_random([0xff; 4]);
+/// let b = later.generate_with_random([0x00; 4]);
+///
+/// assert!(a < b);
+/// assert!(a.to_string() < b.to_string());
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Hash)]
+pub struct TimeOrdered<C> {
+    clock: C,
+}
+
+impl<C: EnidClock> TimeOrdered<C> {
+    /// Creates a generator that reads the time from the given clock.
+    #[must_use]
+    #[inline]
+    pub const fn new(clock: C) -> Self {
+        Self { clock }
+    }
+
+    /// Returns the clock used by the generator.
+    #[must_use]
+    #[inline]
+    pub const fn clock(&self) -> &C {
+        &self.clock
+    }
+
+    /// Generates an ENID with the current time and the given random bytes.
+    #[must_use]
+    #[inline]
+    pub fn generate_with_random(&self, random: [u8; 4]) -> Enid80 {
+        Enid80::from_timestamp_millis(self.clock.now_millis(), random)
+    }
+
+    /// Generates an ENID with the current time and random bytes from the
+    /// operating system's random number generator.
+    ///
+    /// This requires the `getrandom` feature.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number generator fails.
+    #[cfg(feature = "getrandom")]
+    #[must_use]
+    #[track_caller]
+    pub fn generate(&self) -> Enid80 {
+        let mut random = [0; 4];
+        getrandom::fill(&mut random).expect("failed to generate random ENID");
+
+        self.generate_with_random(random)
+    }
+
+    /// Generates an ENID with the current time and random bytes from the given
+    /// random number generator.
+    ///
+    /// This requires the `rand` feature.
+    #[cfg(feature = "rand")]
+    #[must_use]
+    #[inline]
+    pub fn generate_with_rng<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Enid80 {
+        let mut random = [0; 4];
+        rng.fill_bytes(&mut random);
+
+        self.generate_with_random(random)
+    }
+}
+
+impl Enid80 {
+    /// Creates a time-ordered ENID from a timestamp in milliseconds since the
+    /// Unix epoch and 32 random bits, using the layout described in
+    /// [`TimeOrdered`].
+    ///
+    /// Only the lowest 48 bits of the timestamp are used, which is enough for
+    /// dates until the year 10889.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let enid = Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid.as_bytes(), &[0x01, 0x8b, 0xcf, 0xe5, 0x68, 0x00, 0xa1, 0xb2, 0xc3, 0xd4]);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn from_timestamp_millis(millis: u64, random: [u8; 4]) -> Self {
+        let [_, _, t @ ..] = millis.to_be_bytes();
+        let r = random;
+
+        Self::from_bytes([t[0], t[1], t[2], t[3], t[4], t[5], r[0], r[1], r[2], r[3]])
+    }
+
+    /// Returns the timestamp in milliseconds since the Unix epoch stored in a
+    /// time-ordered ENID.
+    ///
+    /// This is the first 48 bits of the ENID, as a big-endian integer. ENIDs
+    /// that were not created by [`TimeOrdered`],
+    /// [`MonotonicGenerator`](crate::generator::MonotonicGenerator), or
+    /// [`from_timestamp_millis`](Self::from_timestamp_millis) do not contain a
+    /// meaningful timestamp.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let enid = Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid.timestamp_millis(), 1_700_000_000_000);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub const fn timestamp_millis(&self) -> u64 {
+        let b = self.as_bytes();
+
+        u64::from_be_bytes([0, 0, b[0], b[1], b[2], b[3], b[4], b[5]])
+    }
+
+    /// Returns the timestamp stored in a time-ordered ENID as a
+    /// [`SystemTime`](std::time::SystemTime).
+    ///
+    /// This requires the `std` feature. See
+    /// [`timestamp_millis`](Self::timestamp_millis) for details.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use std::time::{Duration, UNIX_EPOCH};
+    /// let enid = Enid80::from_timestamp_millis(1_700_000_000_000, [0xa1, 0xb2, 0xc3, 0xd4]);
+    ///
+    /// assert_eq!(enid.system_time(), UNIX_EPOCH + Duration::from_millis(1_700_000_000_000));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    #[inline]
+    pub fn system_time(&self) -> std::time::SystemTime {
+        std::time::UNIX_EPOCH + core::time::Duration::from_millis(self.timestamp_millis())
+    }
+
+    /// Generates a time-ordered ENID with the current system time and random
+    /// bytes from the operating system's random number generator.
+    ///
+    /// This requires the `std` and `getrandom` features.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// # use std::time::SystemTime;
+    /// let enid = Enid80::now();
+    ///
+    /// assert!(enid.system_time() <= SystemTime::now());
+    /// ```
+    ///
+    /// ENIDs from different milliseconds sort by time, whatever their random
+    /// bytes:
+    ///
+    /// ```
+    /// # use enid::Enid80;
+    /// let a = Enid80::from_timestamp_millis(1_700_000_000_000, [0xff; 4]);
+    /// let b = Enid80::from_timestamp_millis(1_700_000_000_001, [0x00; 4]);
+    ///
+    /// assert!(a < b);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the random number generator fails.
+    #[cfg(all(feature = "std", feature = "getrandom"))]
+    #[must_use]
+    #[track_caller]
+    pub fn now() -> Self {
+        TimeOrdered::new(crate::clock::SystemClock).generate()
+    }
+}
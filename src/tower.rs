@@ -0,0 +1,230 @@
+//! Middleware for assigning a request ID to each request, for use with
+//! [`tower`](https://docs.rs/tower) and frameworks built on it, such as axum.
+//!
+//! [`RequestIdLayer`] reads the request ID from a header (`x-request-id` by
+//! default). If the header is missing or does not contain a valid [`Enid80`], a
+//! new ID is created. The ID is then:
+//!
+//! * stored in the request's extensions as a [`RequestId`],
+//! * written to the request header, for services further down the stack, and
+//! * written to the response header.
+//!
+//! # Examples
+//!
+//! ```
+//! # use enid::Enid80;
+//! # use enid::tower::RequestIdLayer;
+//! # use std::sync::atomic::{AtomicU64, Ordering};
+//! static COUNTER: AtomicU64 = AtomicU64::new(0);
+//!
+//! let layer = RequestIdLayer::new(|| {
+//!     Enid80::from_words(0, COUNTER.fetch_add(1, Ordering::Relaxed))
+//! });
+//! ```
+
+use crate::enid::Enid80;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, ready};
+use http::header::{HeaderName, HeaderValue};
+use http::{Request, Response};
+use pin_project_lite::pin_project;
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// The default header used to propagate request IDs.
+pub const REQUEST_ID_HEADER: HeaderName = HeaderName::from_static("x-request-id");
+
+/// The ID of a request, stored in the request's extensions by
+/// [`RequestIdService`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct RequestId(pub Enid80);
+
+/// A [`Layer`] that wraps services with [`RequestIdService`].
+#[derive(Debug, Clone)]
+pub struct RequestIdLayer<F> {
+    header: HeaderName,
+    make_id: F,
+}
+
+impl<F: Fn() -> Enid80> RequestIdLayer<F> {
+    /// Creates a layer that uses the given function to create new request IDs.
+    #[must_use]
+    #[inline]
+    pub fn new(make_id: F) -> Self {
+        Self {
+            header: REQUEST_ID_HEADER,
+            make_id,
+        }
+    }
+
+    /// Sets the header used to propagate request IDs.
+    #[must_use]
+    #[inline]
+    pub fn header(mut self, header: HeaderName) -> Self {
+        self.header = header;
+        self
+    }
+}
+
+impl<S, F: Clone> Layer<S> for RequestIdLayer<F> {
+    type Service = RequestIdService<S, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RequestIdService {
+            inner,
+            header: self.header.clone(),
+            make_id: self.make_id.clone(),
+        }
+    }
+}
+
+/// A [`Service`] that assigns a request ID to each request.
+///
+/// See the [module-level documentation](self) for details.
+#[derive(Debug, Clone)]
+pub struct RequestIdService<S, F> {
+    inner: S,
+    header: HeaderName,
+    make_id: F,
+}
+
+impl<S, F, ReqBody, ResBody> Service<Request<ReqBody>> for RequestIdService<S, F>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    F: Fn() -> Enid80,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future>;
+
+    #[inline]
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let id = request
+            .headers()
+            .get(&self.header)
+            .and_then(|value| Enid80::parse_str_ascii(value.as_bytes()).ok())
+            .unwrap_or_else(&self.make_id);
+
+        let value = header_value(id);
+
+        request
+            .headers_mut()
+            .insert(self.header.clone(), value.clone());
+        request.extensions_mut().insert(RequestId(id));
+
+        ResponseFuture {
+            inner: self.inner.call(request),
+            header: Some((self.header.clone(), value)),
+        }
+    }
+}
+
+pin_project! {
+    /// The response future for [`RequestIdService`].
+    #[derive(Debug)]
+    pub struct ResponseFuture<F> {
+        #[pin]
+        inner: F,
+        header: Option<(HeaderName, HeaderValue)>,
+    }
+}
+
+impl<F, ResBody, E> Future for ResponseFuture<F>
+where
+    F: Future<Output = Result<Response<ResBody>, E>>,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut response = ready!(this.inner.poll(cx))?;
+
+        if let Some((name, value)) = this.header.take() {
+            response.headers_mut().insert(name, value);
+        }
+
+        Poll::Ready(Ok(response))
+    }
+}
+
+fn header_value(id: Enid80) -> HeaderValue {
+    let value = HeaderValue::from_str(id.write_to_buffer(&mut [0; 17]));
+
+    // ENID strings only contain visible ASCII characters.
+    value.unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+    use core::future::{self, Ready};
+    use core::task::Waker;
+
+    struct Echo;
+
+    impl Service<Request<()>> for Echo {
+        type Response = Response<Option<RequestId>>;
+        type Error = Infallible;
+        type Future = Ready<Result<Self::Response, Infallible>>;
+
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, request: Request<()>) -> Self::Future {
+            let id = request.extensions().get::<RequestId>().copied();
+            future::ready(Ok(Response::new(id)))
+        }
+    }
+
+    fn call(request: Request<()>) -> Response<Option<RequestId>> {
+        let mut service = RequestIdLayer::new(|| Enid80::from_words(0, 1)).layer(Echo);
+        let mut future = core::pin::pin!(service.call(request));
+
+        match future
+            .as_mut()
+            .poll(&mut Context::from_waker(Waker::noop()))
+        {
+            Poll::Ready(response) => response.unwrap(),
+            Poll::Pending => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn generates_id() {
+        let response = call(Request::new(()));
+
+        assert_eq!(response.body(), &Some(RequestId(Enid80::from_words(0, 1))));
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "00000000-00000001");
+    }
+
+    #[test]
+    fn propagates_id() {
+        let request = Request::builder()
+            .header(REQUEST_ID_HEADER, "y3gx5gxm-mpb8ey39")
+            .body(())
+            .unwrap();
+        let response = call(request);
+
+        let id = Enid80::parse_str("y3gx5gxm-mpb8ey39").unwrap();
+        assert_eq!(response.body(), &Some(RequestId(id)));
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "y3gx5gxm-mpb8ey39");
+    }
+
+    #[test]
+    fn replaces_invalid_id() {
+        let request = Request::builder()
+            .header(REQUEST_ID_HEADER, "not-an-enid")
+            .body(())
+            .unwrap();
+        let response = call(request);
+
+        assert_eq!(response.headers()[REQUEST_ID_HEADER], "00000000-00000001");
+    }
+}
@@ -0,0 +1,251 @@
+use crate::enid::{Enid, Enid40, Enid80, EnidParseError};
+
+impl Enid40 {
+    /// The maximum length of an ENID encoded with
+    /// [`write_varint`](Self::write_varint).
+    pub const MAX_VARINT_LEN: usize = 6;
+
+    /// Writes the ENID to the buffer as an unsigned LEB128 varint, and returns
+    /// the encoded bytes.
+    ///
+    /// The ENID is treated as a big-endian 40-bit integer, so IDs with many
+    /// leading zero bits take fewer bytes to encode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// let mut buf = [0; Enid40::MAX_VARINT_LEN];
+    ///
+    /// assert_eq!(enid40!("0000000z").write_varint(&mut buf), &[0x1f]);
+    /// assert_eq!(enid40!("00000100").write_varint(&mut buf), &[0x80, 0x08]);
+    /// ```
+    pub const fn write_varint<'a>(&self, buf: &'a mut [u8; 6]) -> &'a [u8] {
+        let mut value = self.to_u64();
+
+        let mut len = 0;
+        loop {
+            let byte = (value & 0x7f) as u8;
+            value >>= 7;
+
+            if value == 0 {
+                buf[len] = byte;
+                len += 1;
+                break;
+            }
+
+            buf[len] = byte | 0x80;
+            len += 1;
+        }
+
+        buf.split_at(len).0
+    }
+
+    /// Attempts to read an ENID encoded with [`write_varint`](Self::write_varint)
+    /// from the start of the given bytes.
+    ///
+    /// Returns the ENID and the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let (enid, len) = Enid40::read_varint(&[0x80, 0x08, 0xff])?;
+    ///
+    /// assert_eq!(enid, enid40!("00000100"));
+    /// assert_eq!(len, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the bytes end before the varint does, if
+    /// the varint does not use the shortest possible encoding, or if the value
+    /// does not fit in 40 bits.
+    pub const fn read_varint(bytes: &[u8]) -> Result<(Self, usize), EnidParseError> {
+        let mut value: u64 = 0;
+
+        let mut i = 0;
+        loop {
+            if i == bytes.len() || i == Self::MAX_VARINT_LEN {
+                return Err(EnidParseError);
+            }
+
+            let byte = bytes[i];
+            value |= ((byte & 0x7f) as u64) << (7 * i);
+            i += 1;
+
+            if byte & 0x80 == 0 {
+                if byte == 0 && i > 1 {
+                    return Err(EnidParseError);
+                }
+
+                break;
+            }
+        }
+
+        match Self::from_u64(value) {
+            Some(enid) => Ok((enid, i)),
+            None => Err(EnidParseError),
+        }
+    }
+}
+
+impl Enid {
+    /// The maximum length of an ENID encoded with
+    /// [`write_compact`](Self::write_compact).
+    pub const MAX_COMPACT_LEN: usize = 11;
+
+    /// Writes the ENID to the buffer in a compact length-prefixed form, and
+    /// returns the encoded bytes.
+    ///
+    /// The first byte holds the number of bytes that follow, with the high bit
+    /// set for an 80-bit ENID. The remaining bytes are the ENID with its leading
+    /// zero bytes removed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// let mut buf = [0; Enid::MAX_COMPACT_LEN];
+    ///
+    /// assert_eq!(enid!("00000100").write_compact(&mut buf), &[0x02, 0x04, 0x00]);
+    /// assert_eq!(enid!("00000000-0000000z").write_compact(&mut buf), &[0x81, 0x1f]);
+    /// ```
+    pub const fn write_compact<'a>(&self, buf: &'a mut [u8; 11]) -> &'a [u8] {
+        let (bytes, flag): (&[u8], u8) = match self {
+            Enid::Enid40(enid) => (enid.as_bytes(), 0x00),
+            Enid::Enid80(enid) => (enid.as_bytes(), 0x80),
+        };
+
+        let mut start = 0;
+        while start < bytes.len() && bytes[start] == 0 {
+            start += 1;
+        }
+
+        let len = bytes.len() - start;
+        buf[0] = flag | len as u8;
+
+        let mut i = 0;
+        while i < len {
+            buf[1 + i] = bytes[start + i];
+            i += 1;
+        }
+
+        buf.split_at(1 + len).0
+    }
+
+    /// Attempts to read an ENID encoded with
+    /// [`write_compact`](Self::write_compact) from the start of the given bytes.
+    ///
+    /// Returns the ENID and the number of bytes read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid, Enid};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let (enid, len) = Enid::read_compact(&[0x81, 0x1f, 0xff])?;
+    ///
+    /// assert_eq!(enid, enid!("00000000-0000000z"));
+    /// assert_eq!(len, 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the length prefix is invalid, if the
+    /// bytes end before the ENID does, or if the ENID has leading zero bytes.
+    pub const fn read_compact(bytes: &[u8]) -> Result<(Self, usize), EnidParseError> {
+        let Some((&prefix, rest)) = bytes.split_first() else {
+            return Err(EnidParseError);
+        };
+
+        let is_enid80 = prefix & 0x80 != 0;
+        let len = (prefix & 0x7f) as usize;
+        let max_len = if is_enid80 { 10 } else { 5 };
+
+        if len > max_len || len > rest.len() || (len > 0 && rest[0] == 0) {
+            return Err(EnidParseError);
+        }
+
+        let mut buf = [0; 10];
+
+        let mut i = 0;
+        while i < len {
+            buf[max_len - len + i] = rest[i];
+            i += 1;
+        }
+
+        let enid = if is_enid80 {
+            Enid::Enid80(Enid80::from_bytes(buf))
+        } else {
+            Enid::Enid40(Enid40::from_bytes(*buf.first_chunk().unwrap()))
+        };
+
+        Ok((enid, 1 + len))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn varint_round_trip() {
+        let mut buf = [0; Enid40::MAX_VARINT_LEN];
+
+        for bytes in [[0; 5], [0, 0, 0, 0, 0x7f], [0, 0, 0, 0, 0x80], [0xff; 5]] {
+            let enid = Enid40::from_bytes(bytes);
+            let encoded = enid.write_varint(&mut buf);
+            assert_eq!(Enid40::read_varint(encoded), Ok((enid, encoded.len())));
+        }
+
+        assert_eq!(
+            Enid40::from_bytes([0xff; 5]).write_varint(&mut buf).len(),
+            6
+        );
+    }
+
+    #[test]
+    fn varint_invalid() {
+        assert_eq!(Enid40::read_varint(&[]), Err(EnidParseError));
+        assert_eq!(Enid40::read_varint(&[0x80]), Err(EnidParseError));
+        assert_eq!(Enid40::read_varint(&[0x80, 0x00]), Err(EnidParseError));
+        assert_eq!(Enid40::read_varint(&[0xff; 7]), Err(EnidParseError));
+        assert_eq!(
+            Enid40::read_varint(&[0xff, 0xff, 0xff, 0xff, 0xff, 0x20]),
+            Err(EnidParseError)
+        );
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let mut buf = [0; Enid::MAX_COMPACT_LEN];
+
+        for enid in [
+            Enid::from([0; 5]),
+            Enid::from([0xff; 5]),
+            Enid::from([0; 10]),
+            Enid::from([0, 0, 0, 0, 0, 0, 0, 0, 1, 0]),
+            Enid::from([0xff; 10]),
+        ] {
+            let encoded = enid.write_compact(&mut buf);
+            assert_eq!(Enid::read_compact(encoded), Ok((enid, encoded.len())));
+        }
+    }
+
+    #[test]
+    fn compact_invalid() {
+        assert_eq!(Enid::read_compact(&[]), Err(EnidParseError));
+        assert_eq!(
+            Enid::read_compact(&[0x06, 1, 1, 1, 1, 1, 1]),
+            Err(EnidParseError)
+        );
+        assert_eq!(Enid::read_compact(&[0x02, 1]), Err(EnidParseError));
+        assert_eq!(Enid::read_compact(&[0x82, 0, 1]), Err(EnidParseError));
+    }
+}
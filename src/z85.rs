@@ -0,0 +1,239 @@
+use crate::ascii;
+use crate::enid::{Enid40, Enid80, EnidParseError};
+
+const CHARS: [u8; 85] =
+    *b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+const VALUES: [u8; 256] = {
+    let mut values = [0xff; 256];
+    let mut i = 0;
+
+    while i < CHARS.len() {
+        let idx = CHARS[i] as usize;
+
+        assert!(values[idx] == 0xff);
+        values[idx] = i as u8;
+
+        i += 1;
+    }
+
+    values
+};
+
+/// Encodes bytes as Z85. The length of `bytes` must be a multiple of 4, and the
+/// length of `chars` must be 5/4 of that.
+const fn encode(bytes: &[u8], chars: &mut [u8]) {
+    let mut i = 0;
+    while i < bytes.len() / 4 {
+        let mut value = u32::from_be_bytes([
+            bytes[4 * i],
+            bytes[4 * i + 1],
+            bytes[4 * i + 2],
+            bytes[4 * i + 3],
+        ]);
+
+        let mut j = 5;
+        while j > 0 {
+            j -= 1;
+            chars[5 * i + j] = CHARS[(value % 85) as usize];
+            value /= 85;
+        }
+
+        i += 1;
+    }
+}
+
+/// Decodes Z85 characters. The length of `chars` must be a multiple of 5, and
+/// the length of `bytes` must be 4/5 of that.
+const fn decode(chars: &[u8], bytes: &mut [u8]) -> Result<(), EnidParseError> {
+    let mut i = 0;
+    while i < chars.len() / 5 {
+        let mut value: u64 = 0;
+
+        let mut j = 0;
+        while j < 5 {
+            let v = VALUES[chars[5 * i + j] as usize];
+
+            if v == 0xff {
+                return Err(EnidParseError);
+            }
+
+            value = value * 85 + v as u64;
+            j += 1;
+        }
+
+        if value > u32::MAX as u64 {
+            return Err(EnidParseError);
+        }
+
+        let chunk = (value as u32).to_be_bytes();
+        bytes[4 * i] = chunk[0];
+        bytes[4 * i + 1] = chunk[1];
+        bytes[4 * i + 2] = chunk[2];
+        bytes[4 * i + 3] = chunk[3];
+
+        i += 1;
+    }
+
+    Ok(())
+}
+
+impl Enid40 {
+    /// Writes the ENID to the buffer in Z85 (ZeroMQ Base85) encoding, and
+    /// returns the encoded string.
+    ///
+    /// Z85 encodes groups of 4 bytes, so the ENID is padded with 3 leading zero
+    /// bytes before it is encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid40;
+    /// let enid = enid40!("m6sc7n75");
+    ///
+    /// assert_eq!(enid.write_z85(&mut [0; 10]), "0001)VCUhN");
+    /// ```
+    pub const fn write_z85<'a>(&self, buf: &'a mut [u8; 10]) -> &'a str {
+        let b = self.as_bytes();
+        encode(&[0, 0, 0, b[0], b[1], b[2], b[3], b[4]], buf);
+
+        ascii::to_str(buf)
+    }
+
+    /// Attempts to create an ENID from a Z85 string written by
+    /// [`write_z85`](Self::write_z85).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid40, Enid40};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = Enid40::parse_z85("0001)VCUhN")?;
+    ///
+    /// assert_eq!(enid, enid40!("m6sc7n75"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not valid Z85, or if the
+    /// padding bytes are not zero.
+    pub const fn parse_z85(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.as_bytes();
+
+        if s.len() != 10 {
+            return Err(EnidParseError);
+        }
+
+        let mut b = [0; 8];
+
+        if let Err(e) = decode(s, &mut b) {
+            return Err(e);
+        }
+
+        if b[0] != 0 || b[1] != 0 || b[2] != 0 {
+            return Err(EnidParseError);
+        }
+
+        Ok(Self::from_bytes([b[3], b[4], b[5], b[6], b[7]]))
+    }
+}
+
+impl Enid80 {
+    /// Writes the ENID to the buffer in Z85 (ZeroMQ Base85) encoding, and
+    /// returns the encoded string.
+    ///
+    /// Z85 encodes groups of 4 bytes, so the ENID is padded with 2 leading zero
+    /// bytes before it is encoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::enid80;
+    /// let enid = enid80!("y3gx5gxm-mpb8ey39");
+    ///
+    /// assert_eq!(enid.write_z85(&mut [0; 15]), "008JE^.&C*MwoZ0");
+    /// ```
+    pub const fn write_z85<'a>(&self, buf: &'a mut [u8; 15]) -> &'a str {
+        let b = self.as_bytes();
+        encode(
+            &[
+                0, 0, b[0], b[1], b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9],
+            ],
+            buf,
+        );
+
+        ascii::to_str(buf)
+    }
+
+    /// Attempts to create an ENID from a Z85 string written by
+    /// [`write_z85`](Self::write_z85).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use enid::{enid80, Enid80};
+    /// # fn main() -> Result<(), enid::EnidParseError> {
+    /// let enid = Enid80::parse_z85("008JE^.&C*MwoZ0")?;
+    ///
+    /// assert_eq!(enid, enid80!("y3gx5gxm-mpb8ey39"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EnidParseError`] if the string is not valid Z85, or if the
+    /// padding bytes are not zero.
+    pub const fn parse_z85(s: &str) -> Result<Self, EnidParseError> {
+        let s = s.as_bytes();
+
+        if s.len() != 15 {
+            return Err(EnidParseError);
+        }
+
+        let mut b = [0; 12];
+
+        if let Err(e) = decode(s, &mut b) {
+            return Err(e);
+        }
+
+        if b[0] != 0 || b[1] != 0 {
+            return Err(EnidParseError);
+        }
+
+        Ok(Self::from_bytes([
+            b[2], b[3], b[4], b[5], b[6], b[7], b[8], b[9], b[10], b[11],
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn z85_spec() {
+        let bytes = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+        let mut chars = [0; 10];
+
+        encode(&bytes, &mut chars);
+        assert_eq!(&chars, b"HelloWorld");
+
+        let mut decoded = [0; 8];
+        assert_eq!(decode(&chars, &mut decoded), Ok(()));
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn z85_invalid() {
+        assert_eq!(Enid40::parse_z85(""), Err(EnidParseError));
+        assert_eq!(Enid40::parse_z85("0001)VCUh"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_z85("0001)VCUh~"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_z85("1001)VCUhN"), Err(EnidParseError));
+        assert_eq!(Enid40::parse_z85("#####VCUhN"), Err(EnidParseError));
+        assert_eq!(Enid80::parse_z85("108JE^.&C*MwoZ0"), Err(EnidParseError));
+        assert_eq!(Enid80::parse_z85("008JE^.&C*MwoZ"), Err(EnidParseError));
+    }
+}